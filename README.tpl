@@ -25,6 +25,11 @@ This project is licensed under either of
 
 at your option.
 
+The collation table `src/sort/collate_table.txt` and the case folding table
+`src/sort/case_fold_table.rs.txt` are generated from the Unicode data 13.0.0,
+DUCET and `CaseFolding.txt`, Copyright © Unicode, Inc., under the Unicode
+license ([LICENSE-UNICODE](LICENSE-UNICODE)).
+
 [//]: # (badges)
 
 [crate-image]: https://img.shields.io/crates/v/{{crate}}.svg
@@ -1,12 +1,20 @@
 //
+use crate::sort::list::ListOrder;
 use crate::util::OptAccordingToWord;
+use crate::util::OptKeyDef;
 use crate::util::OptMaxBufferSize;
+use crate::util::OptOnBadKey;
+use crate::util::OptOnUnknown;
 use flood_tide::parse_simple_gnu_style;
 use flood_tide::HelpVersion;
 use flood_tide::{Arg, NameVal, Opt, OptNum};
 use flood_tide::{OptParseError, OptParseErrors};
 
+use crate::util::OptCollateLocale;
 use crate::util::OptColorWhen;
+use crate::util::OptFirstWeekday;
+use crate::util::OptMonthLocale;
+use crate::util::OptNormalizeForm;
 use crate::util::OptUcXParam;
 
 //----------------------------------------------------------------------
@@ -17,13 +25,48 @@ const DESCRIPTIONS_TEXT: &str = r#"
 sort lines of text.
 "#;
 const PARAMS_TEXT: &str = r#"Option Parameters:
-  <word>    'month', 'numeric', 'string', 'time', 'version'
+  <form>    'nfc', 'nfd', 'nfkc', or 'nfkd'. the keys are compared after normalized
+            by the Unicode normalization form, and the output lines are not changed.
+  <word>    'collate', 'datetime', 'deb-version', 'duration', 'general-numeric',
+            'human-size', 'ip', 'lenient-version', 'list', 'month', 'natural',
+            'numeric', 'pep440', 'rpm-version', 'string', 'time', 'version', 'weekday'
+  <c>       a character. the default of --decimal-char is '.', and the default of
+            --grouping-char is ',', or none if --decimal-char is ','.
+            an empty --grouping-char means no grouping.
+  <fmt>     strftime-like format, ex) '%Y/%m/%d %H:%M'. the time zone and the time
+            can be omitted, then they are UTC and 00:00. without --date-format,
+            ISO 8601, RFC 2822, syslog and common log format are parsed.
+  <day>     'sunday' or 'monday'. the default is 'monday'.
+  <loc>     'en', 'ja', 'de', or 'fr'. the default is 'en'.
+  <file>    a file of the month names or the order. a line has the names of
+            a month, from January to December, or the values of a position of
+            the order, separated by ','. the lines of '#' are comments.
+  <list>    the comma separated values, ex) 'low,medium,high,critical'.
+            the values are compared case-insensitively.
+  <unknown> 'error', 'first', or 'last'. the default is 'error'. an unknown
+            value is a bad key by 'error'.
+  <lang>    'root', 'da', 'es', 'fi', 'nb', or 'sv'. the default is 'root', the order
+            of the Unicode Collation Algorithm without a tailoring.
+  <policy>  'error', 'first', 'last', 'skip', or 'keep-in-place'. a bad key can not
+            be parsed, or is not found except 'error'. the line number of a bad key
+            is displayed by 'error' and 'skip'.
   <when>    'always', 'never', or 'auto'
   <exp>     regular expression, sort by the entires match.
+  <keydef>  F[.C][,F[.C]][:<word>][:r]: F is a field number and C is a character
+            position in the field, origin 1. ex) '3,3' is the 3rd field.
+            <word> is the according-to of the key, and 'r' reverses the key.
+            the keys are compared in order of the options.
+  <sep>     a field separator string. the default is the blanks.
   <size>    if a reading size is more than <size>, then it is not output,
-            quit and display error message.
+            quit and display error message. (--max-buffer)
+            if a chunk of lines is more than <size>, then it is sorted and
+            written to a temporary file, and they are merged. (--buffer-size)
+  <dir>     the default is the system temporary directory.
+"#;
+const ARGUMENTS_TEXT: &str = r#"Arguments:
+  <file>    utf-8 encoded plain text file. '-' means the standard input.
+            if no <file> is given, read the standard input.
 "#;
-//const ARGUMENTS_TEXT: &str = r#""#;
 const ENV_TEXT: &str = r#"Environments:
   AKI_RESORT_COLOR_SEQ_ST   color start sequence specified by ansi
   AKI_RESORT_COLOR_SEQ_ED   color end sequence specified by ansi
@@ -39,6 +82,18 @@ const EXAMPLES_TEXT: &str = r#"Examples:
     cat file1.txt | aki-resort -e "[^:]+:[^:]+:([0-9.]+):" --according-to version
   This sort via 1st chunk of numeric time character according to time:
     cat file1.txt | aki-resort -e "([0-9]+:([0-9]+:)?[0-9]+(.[0-9]+)?)" --according-to time
+  This sort via 3rd field of ':' separated according to version:
+    cat file1.txt | aki-resort -k 3,3 --field-separator ":" --according-to version
+  This sort via 5th field according to month, then 2nd field according to numeric in reverse:
+    cat file1.txt | aki-resort --field-separator ":" -k 5,5:month -k 2,2:numeric:r
+  This sort the concatenation of files and the standard input:
+    cat file3.txt | aki-resort file1.txt file2.txt -
+  This sort a large file with temporary files by chunks of 64MiB:
+    aki-resort -S 64M large.txt
+  This check whether a file is sorted according to numeric:
+    aki-resort -c --according-to numeric file1.txt
+  This merge the already sorted files according to numeric:
+    aki-resort -m --according-to numeric sorted1.txt sorted2.txt
 "#;
 //}}} TEXT
 
@@ -51,14 +106,14 @@ fn version_message(_program: &str) -> String {
 
 #[rustfmt::skip]
 fn usage_message(program: &str) -> String {
-    format!("Usage:\n  {} {}", program, "[options]")
+    format!("Usage:\n  {} {}", program, "[options] [<file>...]")
 }
 
 #[rustfmt::skip]
 fn help_message(program: &str) -> String {
     let ver = version_message(program);
     let usa = usage_message(env!("CARGO_PKG_NAME"));
-    [ &ver, "", &usa, DESCRIPTIONS_TEXT, OPTIONS_TEXT, PARAMS_TEXT, ENV_TEXT, EXAMPLES_TEXT].join("\n")
+    [ &ver, "", &usa, DESCRIPTIONS_TEXT, OPTIONS_TEXT, ARGUMENTS_TEXT, PARAMS_TEXT, ENV_TEXT, EXAMPLES_TEXT].join("\n")
 }
 
 #[rustfmt::skip]
@@ -96,7 +151,22 @@ fn opt_uc_x_package_version_info(_program: &str) -> String {
 }
 
 //----------------------------------------------------------------------
+// flood-tide treats a lone '-' as an empty short option and drops it.
+// it is replaced with this marker before parsing and restored after.
+// a NUL character can not appear in the command line arguments.
+const STDIN_MARK: &str = "\u{0}-";
+
 fn parse_match(conf: &mut CmdOptConf, nv: &NameVal<'_>) -> Result<(), OptParseError> {
+    let nv_restored;
+    let nv = if nv.val == Some(STDIN_MARK) {
+        nv_restored = NameVal {
+            val: Some("-"),
+            ..*nv
+        };
+        &nv_restored
+    } else {
+        nv
+    };
     include!("cmd.match.rs.txt");
     Ok(())
 }
@@ -108,8 +178,12 @@ pub fn parse_cmdopts(a_prog_name: &str, args: &[&str]) -> Result<CmdOptConf, Opt
         opt_color: OptColorWhen::Never,
         ..Default::default()
     };
+    let args: Vec<&str> = args
+        .iter()
+        .map(|&a| if a == "-" { STDIN_MARK } else { a })
+        .collect();
     let (opt_free, r_errs) =
-        parse_simple_gnu_style(&mut conf, &OPT_ARY, &OPT_ARY_SHO_IDX, args, parse_match);
+        parse_simple_gnu_style(&mut conf, &OPT_ARY, &OPT_ARY_SHO_IDX, &args, parse_match);
     //
     if conf.is_help() {
         let mut errs = OptParseErrors::new();
@@ -155,6 +229,81 @@ pub fn parse_cmdopts(a_prog_name: &str, args: &[&str]) -> Result<CmdOptConf, Opt
             errs.push(OptParseError::missing_option("e or f"));
         }
         */
+        if !conf.opt_key.is_empty() && !conf.opt_exp.is_empty() {
+            errs.push(OptParseError::unexpected_option_argument(
+                "key",
+                "can not be used with --exp",
+            ));
+        }
+        if let Some(s) = &conf.opt_grouping_char {
+            if s.chars().count() > 1 {
+                errs.push(OptParseError::invalid_option_argument(
+                    "grouping-char",
+                    &format!("can not parse '{s}': not a character"),
+                ));
+            }
+        }
+        if let Some(s) = &conf.opt_decimal_char {
+            if s.chars().count() != 1 {
+                errs.push(OptParseError::invalid_option_argument(
+                    "decimal-char",
+                    &format!("can not parse '{s}': not a character"),
+                ));
+            } else if conf.opt_grouping_char.as_ref() == Some(s) {
+                errs.push(OptParseError::unexpected_option_argument(
+                    "decimal-char",
+                    "can not be the same as --grouping-char",
+                ));
+            }
+        }
+        if conf.opt_month_locale.is_some() && !conf.opt_month_names.is_empty() {
+            errs.push(OptParseError::unexpected_option_argument(
+                "month-names",
+                "can not be used with --month-locale",
+            ));
+        }
+        if !conf.opt_order.is_empty() && !conf.opt_order_file.is_empty() {
+            errs.push(OptParseError::unexpected_option_argument(
+                "order-file",
+                "can not be used with --order",
+            ));
+        }
+        if !conf.opt_order.is_empty() {
+            if let Err(err) = ListOrder::from_list(&conf.opt_order, conf.opt_on_unknown) {
+                errs.push(OptParseError::invalid_option_argument(
+                    "order",
+                    &format!("can not parse '{}': {err}", conf.opt_order),
+                ));
+            }
+        }
+        let is_list = conf.opt_according_to == OptAccordingToWord::List
+            || conf
+                .opt_key
+                .iter()
+                .any(|a| a.according_to() == Some(OptAccordingToWord::List));
+        if is_list && conf.opt_order.is_empty() && conf.opt_order_file.is_empty() {
+            errs.push(OptParseError::missing_option("order"));
+        }
+        if conf.flg_merge {
+            if conf.opt_head.is_some() || conf.opt_tail.is_some() {
+                errs.push(OptParseError::unexpected_option_argument(
+                    "merge",
+                    "can not be used with --head or --tail",
+                ));
+            }
+            if conf.flg_check || conf.flg_check_quiet {
+                errs.push(OptParseError::unexpected_option_argument(
+                    "merge",
+                    "can not be used with --check",
+                ));
+            }
+            if !matches!(conf.opt_on_bad_key, OptOnBadKey::Error | OptOnBadKey::Skip) {
+                errs.push(OptParseError::unexpected_option_argument(
+                    "merge",
+                    "can be used with only --on-bad-key error or skip",
+                ));
+            }
+        }
         if conf.opt_color == OptColorWhen::Auto {
             if atty::is(atty::Stream::Stdout) {
                 conf.opt_color = OptColorWhen::Always;
@@ -164,10 +313,24 @@ pub fn parse_cmdopts(a_prog_name: &str, args: &[&str]) -> Result<CmdOptConf, Opt
         }
         //
         if let Some(free) = opt_free {
-            if !free.is_empty() {
-                errs.push(OptParseError::unexpected_argument(&free[0]));
-            }
+            conf.arg_params = free
+                .into_iter()
+                .map(|a| if a == STDIN_MARK { "-".to_string() } else { a })
+                .collect();
         };
+        if conf.flg_merge && conf.arg_params.iter().filter(|a| *a == "-").count() > 1 {
+            errs.push(OptParseError::unexpected_option_argument(
+                "merge",
+                "can not read the standard input more than once",
+            ));
+        }
+        // the line numbers of the messages are of the one input, like `sort -c`.
+        if (conf.flg_check || conf.flg_check_quiet) && conf.arg_params.len() > 1 {
+            errs.push(OptParseError::unexpected_option_argument(
+                "check",
+                "can not be used with more than one input",
+            ));
+        }
         if !errs.is_empty() {
             return Err(errs);
         }
@@ -1,4 +1,4 @@
-use libaki_resort::execute;
+use libaki_resort::{execute, SilentError};
 use runnel::RunnelIoeBuilder;
 
 fn main() {
@@ -10,7 +10,9 @@ fn main() {
     let sioe = RunnelIoeBuilder::new().build();
     //
     if let Err(err) = execute(&sioe, program, env_args) {
-        let _ = sioe.pg_err().write_line(format!("{program}: {err:#}"));
+        if err.downcast_ref::<SilentError>().is_none() {
+            let _ = sioe.pg_err().write_line(format!("{program}: {err:#}"));
+        }
         std::process::exit(1);
     };
 }
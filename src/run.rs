@@ -1,37 +1,57 @@
 use crate::conf::{CmdOptConf, EnvConf};
-use crate::sort::{KeyColumns, KeyLine};
+use crate::sort::external::{ExternalSorter, HeapItem, KeyLines};
+use crate::sort::extractor::KeyExtractor;
+use crate::sort::general_numeric::NumFormat;
+use crate::sort::multi::SortKey;
 use crate::sort::{
-    SortLinesBufferMonth, SortLinesBufferNumeric, SortLinesBufferString, SortLinesBufferTime,
-    SortLinesBufferVersion,
+    BadKeyLine, BufLine, Collator, KeyColumns, KeyLine, ListOrder, MonthNames, SortLinesBuffer,
+    SortOptions,
 };
-use crate::util::err::BrokenPipeError;
+use crate::sort::{
+    SortLinesBufferCollate, SortLinesBufferDatetime, SortLinesBufferDebVersion,
+    SortLinesBufferDuration, SortLinesBufferGeneralNumeric, SortLinesBufferHumanSize,
+    SortLinesBufferIp, SortLinesBufferLenientVersion, SortLinesBufferList,
+    SortLinesBufferMappedString, SortLinesBufferMonth, SortLinesBufferMulti,
+    SortLinesBufferNatural, SortLinesBufferNumeric, SortLinesBufferPep440,
+    SortLinesBufferRpmVersion, SortLinesBufferString, SortLinesBufferTime, SortLinesBufferVersion,
+    SortLinesBufferWeekday,
+};
+use crate::util::err::{BrokenPipeError, SilentError};
 use crate::util::OptAccordingToWord;
 use crate::util::OptColorWhen;
+use crate::util::OptOnBadKey;
+use anyhow::Context;
 use regex::Regex;
 use runnel::RunnelIoe;
+use std::collections::BinaryHeap;
+use std::io::BufRead;
 
 pub fn run(sioe: &RunnelIoe, conf: &CmdOptConf, env: &EnvConf) -> anyhow::Result<()> {
-    let re = if !conf.opt_exp.is_empty() {
+    let ext = if conf.opt_key.len() > 1 {
+        KeyExtractor::Fields(conf.opt_key.clone(), conf.opt_field_separator.clone())
+    } else if let Some(key_def) = conf.opt_key.first() {
+        KeyExtractor::Field(*key_def, conf.opt_field_separator.clone())
+    } else if !conf.opt_exp.is_empty() {
         let re = Regex::new(conf.opt_exp.as_str())?;
-        Some(re)
+        KeyExtractor::Regex(re)
     } else {
-        None
+        KeyExtractor::Line
     };
-    let r = run_0(sioe, conf, env, re);
+    let r = run_0(sioe, conf, env, &ext);
     if r.is_broken_pipe() {
         return Ok(());
     }
     r
 }
 
-fn lines_loop<T>(
-    sioe: &RunnelIoe,
-    conf: &CmdOptConf,
-    re: Option<Regex>,
+fn lines_loop<'a, T>(
+    sioe: &'a RunnelIoe,
+    conf: &'a CmdOptConf,
+    ext: &KeyExtractor,
     mut sort_buf_lines: T,
-) -> anyhow::Result<Vec<KeyLine>>
+) -> anyhow::Result<KeyLines<'a>>
 where
-    T: crate::sort::SortLinesBuffer,
+    T: SortLinesBuffer,
 {
     let mut curr_sz: usize = 0;
     let mut result_buf_lines = Vec::new();
@@ -39,7 +59,7 @@ where
     let mut match_count: usize = 0;
     //
     // read all lines
-    for line in sioe.pg_in().lines() {
+    for line in input_lines(sioe, conf) {
         let line_s = line?;
         let line_ss = line_s.as_str();
         let line_len: usize = line_ss.len();
@@ -57,102 +77,734 @@ where
             }
         }
         //
-        let key = if let Some(ref re) = re {
-            if let Some(caps) = re.captures(line_ss) {
-                match_count += 1;
-                if let Some(mat) = caps.get(1) {
-                    KeyColumns::new(mat.start(), mat.end())
-                } else if let Some(mat) = caps.get(0) {
-                    KeyColumns::new(mat.start(), mat.end())
-                } else {
-                    unreachable!();
-                }
-            } else {
-                KeyColumns::new(0, line_len)
-            }
-        } else {
-            KeyColumns::new(0, line_len)
-        };
-        buf_lines.push(KeyLine::new(key, line_s));
+        let key = ext.key_columns(line_ss);
+        if key.is_some() {
+            match_count += 1;
+        }
+        buf_lines.push((key, line_s));
     }
     // remove footer
-    let mut footer = if let Some(n) = conf.opt_tail {
+    let footer = if let Some(n) = conf.opt_tail {
         if n > 0 {
             let at = buf_lines.len() - n;
-            let mut buf = buf_lines.split_off(at);
-            for v in buf.iter_mut() {
-                v.key = KeyColumns::new(0, 0);
-            }
-            buf
+            let buf = buf_lines.split_off(at);
+            buf.into_iter()
+                .map(|(_, line)| KeyLine::new(KeyColumns::new(0, 0), line))
+                .collect()
         } else {
             Vec::new()
         }
     } else {
         Vec::new()
     };
-    let mut body = if re.is_none() || match_count > 0 {
+    let mut bad_keys = BadKeys::new(sioe, conf);
+    let body: KeyLines<'a> = if ext.is_line() || match_count > 0 || bad_keys.is_sorted_always() {
         // sort body
-        for key_line in buf_lines {
-            sort_buf_lines.push_line(key_line.key, key_line.line)?;
+        for (num, (key, line)) in buf_lines.into_iter().enumerate() {
+            let r = match key {
+                Some(key) => sort_buf_lines.push_line(key, line),
+                None if bad_keys.is_error() => {
+                    let key = KeyColumns::new(0, line.len());
+                    sort_buf_lines.push_line(key, line)
+                }
+                None => {
+                    bad_keys.push_not_found(num, line)?;
+                    continue;
+                }
+            };
+            if let Err(bad) = r {
+                bad_keys.push(num, bad)?;
+            }
         }
         // append all lines
-        sort_buf_lines.into_sorted_vec()
+        let v = sort_buf_lines.into_sorted_vec(conf.flg_unique);
+        bad_keys.into_body(Box::new(v.into_iter().map(Ok)))
     } else {
-        buf_lines
+        Box::new(buf_lines.into_iter().map(|(_, line)| {
+            let key = KeyColumns::new(0, line.len());
+            Ok(KeyLine::new(key, line))
+        }))
     };
-    result_buf_lines.append(&mut body);
-    result_buf_lines.append(&mut footer);
+    Ok(Box::new(
+        result_buf_lines
+            .into_iter()
+            .map(Ok)
+            .chain(body)
+            .chain(footer.into_iter().map(Ok)),
+    ))
+}
+
+/// sort with temporary files. see `ExternalSorter`.
+fn lines_loop_external<'a, T>(
+    sioe: &'a RunnelIoe,
+    conf: &'a CmdOptConf,
+    ext: &KeyExtractor,
+    sort_buf_lines: &'a T,
+) -> anyhow::Result<KeyLines<'a>>
+where
+    T: SortLinesBuffer + Sync,
+{
+    let mut sorter = ExternalSorter::new(conf.opt_temp_dir.as_str(), conf.flg_unique);
+    let mut bad_keys = BadKeys::new(sioe, conf);
+    let mut curr_sz: usize = 0;
+    let mut head_lines = Vec::new();
+    let mut tail_lines = std::collections::VecDeque::new();
+    let mut chunk = Vec::new();
+    let mut chunk_sz: usize = 0;
+    let mut body_num: usize = 0;
+    let mut match_count: usize = 0;
+    let chunk_max = conf.opt_buffer_size.unwrap_or_default();
     //
-    Ok(result_buf_lines)
+    for line in input_lines(sioe, conf) {
+        let line_s = line?;
+        let line_len: usize = line_s.len();
+        //
+        curr_sz += line_len;
+        if !conf.opt_max_buffer.is_ok(curr_sz) {
+            return Err(anyhow!("over max buffer size: {}", conf.opt_max_buffer));
+        }
+        //
+        if let Some(n) = conf.opt_head {
+            if head_lines.len() < n {
+                let key = KeyColumns::new(0, 0);
+                head_lines.push(KeyLine::new(key, line_s));
+                continue;
+            }
+        }
+        //
+        let key = ext.key_columns(line_s.as_str());
+        if key.is_some() {
+            match_count += 1;
+        }
+        let mut key_line = (key, line_s);
+        // the last lines are held back as the footer.
+        if let Some(n) = conf.opt_tail {
+            if n > 0 {
+                tail_lines.push_back(key_line);
+                if tail_lines.len() <= n {
+                    continue;
+                }
+                key_line = tail_lines.pop_front().unwrap();
+            }
+        }
+        let num = body_num;
+        body_num += 1;
+        let key_line = match key_line {
+            (Some(key), line) => KeyLine::new(key, line),
+            (None, line) if bad_keys.is_error() => {
+                KeyLine::new(KeyColumns::new(0, line.len()), line)
+            }
+            (None, line) => {
+                bad_keys.push_not_found(num, line)?;
+                continue;
+            }
+        };
+        chunk_sz += key_line.line.len();
+        chunk.push((num, key_line));
+        if !chunk_max.is_ok(chunk_sz) {
+            let sorted = ext.is_line() || match_count > 0 || bad_keys.is_sorted_always();
+            let chunk = std::mem::take(&mut chunk);
+            sorter.spill(sort_buf_lines, chunk, sorted, |num, bad| {
+                bad_keys.push(num, bad)
+            })?;
+            chunk_sz = 0;
+        }
+    }
+    // remove footer
+    let footer: Vec<KeyLine> = tail_lines
+        .into_iter()
+        .map(|(_, line)| KeyLine::new(KeyColumns::new(0, 0), line))
+        .collect();
+    let sorted = ext.is_line() || match_count > 0 || bad_keys.is_sorted_always();
+    let body: KeyLines<'a> = if sorter.is_empty() {
+        if sorted {
+            let lines = crate::sort::external::sort_chunk(
+                sort_buf_lines,
+                chunk,
+                conf.flg_unique,
+                |num, bad| bad_keys.push(num, bad),
+            )?;
+            Box::new(lines.into_iter().map(|a| Ok(a.into_key_line())))
+        } else {
+            Box::new(chunk.into_iter().map(|(_, a)| Ok(a)))
+        }
+    } else {
+        if !chunk.is_empty() {
+            sorter.spill(sort_buf_lines, chunk, sorted, |num, bad| {
+                bad_keys.push(num, bad)
+            })?;
+        }
+        sorter.into_merged(sort_buf_lines, sorted, |num, bad| bad_keys.push(num, bad))?
+    };
+    let body = bad_keys.into_body(body);
+    Ok(Box::new(
+        head_lines
+            .into_iter()
+            .map(Ok)
+            .chain(body)
+            .chain(footer.into_iter().map(Ok)),
+    ))
+}
+
+/// check that the body lines are already sorted, with the same key extraction
+/// and comparator as the sorting. it stops at the first disorder.
+fn check_lines<T>(
+    sioe: &RunnelIoe,
+    conf: &CmdOptConf,
+    ext: &KeyExtractor,
+    sort_buf_lines: &T,
+) -> anyhow::Result<()>
+where
+    T: SortLinesBuffer,
+{
+    let mut bad_keys = BadKeys::new(sioe, conf);
+    let mut head_count: usize = 0;
+    let mut tail_lines = std::collections::VecDeque::new();
+    let mut body_num: usize = 0;
+    let mut prev_line: Option<T::Line> = None;
+    //
+    for line in input_lines(sioe, conf) {
+        let line_s = line?;
+        //
+        if let Some(n) = conf.opt_head {
+            if head_count < n {
+                head_count += 1;
+                continue;
+            }
+        }
+        //
+        let mut key_line = (ext.key_columns(line_s.as_str()), line_s);
+        // the last lines are held back as the footer.
+        if let Some(n) = conf.opt_tail {
+            if n > 0 {
+                tail_lines.push_back(key_line);
+                if tail_lines.len() <= n {
+                    continue;
+                }
+                key_line = tail_lines.pop_front().unwrap();
+            }
+        }
+        let num = body_num;
+        body_num += 1;
+        let (key, line) = match key_line {
+            (Some(key), line) => (key, line),
+            (None, line) if bad_keys.is_error() => (KeyColumns::new(0, line.len()), line),
+            (None, line) => {
+                bad_keys.push_not_found(num, line)?;
+                continue;
+            }
+        };
+        let curr = match sort_buf_lines.make_line(num, key, line) {
+            Ok(curr) => curr,
+            Err(bad) => {
+                bad_keys.push(num, bad)?;
+                continue;
+            }
+        };
+        if let Some(prev) = prev_line.as_ref() {
+            let is_eq = sort_buf_lines.eq_line(prev, &curr);
+            let msg = if is_eq {
+                conf.flg_unique.then_some("duplicate")
+            } else {
+                let r = sort_buf_lines.cmp_line(prev, &curr);
+                (r == std::cmp::Ordering::Greater).then_some("disorder")
+            };
+            if let Some(msg) = msg {
+                if conf.flg_check_quiet {
+                    return Err(SilentError.into());
+                }
+                return Err(anyhow!(
+                    "line {}: {}: '{}' after '{}'",
+                    bad_keys.line_num(num),
+                    msg,
+                    key_str(curr.key_line()),
+                    key_str(prev.key_line()),
+                ));
+            }
+        }
+        prev_line = Some(curr);
+    }
+    Ok(())
+}
+
+#[inline]
+fn key_str(key_line: &KeyLine) -> &str {
+    &key_line.line[key_line.key.st..key_line.key.ed]
+}
+
+/// merge the already sorted inputs with k-way merge, without buffering
+/// all lines. the equal lines are in the order of the inputs.
+fn merge_lines<'a, T>(
+    sioe: &'a RunnelIoe,
+    conf: &'a CmdOptConf,
+    ext: &'a KeyExtractor,
+    sort_buf_lines: &'a T,
+) -> anyhow::Result<KeyLines<'a>>
+where
+    T: SortLinesBuffer,
+{
+    let mut merged = MergedInputs {
+        conf,
+        ext,
+        sort_buf: sort_buf_lines,
+        inputs: Vec::new(),
+        heap: BinaryHeap::new(),
+        bad_keys: BadKeys::new(sioe, conf),
+    };
+    for path in input_paths(conf) {
+        let lines = open_input(sioe, path)?;
+        merged.inputs.push(MergeInput {
+            name: path.to_string(),
+            lines,
+            line_count: 0,
+        });
+    }
+    for idx in 0..merged.inputs.len() {
+        if let Some(line) = merged.read_line(idx)? {
+            let item = HeapItem::new(sort_buf_lines, line, idx);
+            merged.heap.push(item);
+        }
+    }
+    Ok(Box::new(merged))
+}
+
+/// an input of the `--merge`.
+struct MergeInput<'a> {
+    name: String,
+    lines: InputLines<'a>,
+    line_count: usize,
+}
+
+/// k-way merge of the sorted inputs.
+struct MergedInputs<'a, T: SortLinesBuffer> {
+    conf: &'a CmdOptConf,
+    ext: &'a KeyExtractor,
+    sort_buf: &'a T,
+    inputs: Vec<MergeInput<'a>>,
+    heap: BinaryHeap<HeapItem<'a, T>>,
+    bad_keys: BadKeys<'a>,
+}
+
+impl<'a, T: SortLinesBuffer> MergedInputs<'a, T> {
+    /// the next line of the input `idx`. the lines with a bad key are
+    /// passed to the `--on-bad-key`.
+    fn read_line(&mut self, idx: usize) -> anyhow::Result<Option<T::Line>> {
+        loop {
+            let input = &mut self.inputs[idx];
+            let line_s = match input.lines.next() {
+                Some(line) => line?,
+                None => return Ok(None),
+            };
+            let num = input.line_count;
+            input.line_count += 1;
+            let key = match self.ext.key_columns(line_s.as_str()) {
+                Some(key) => key,
+                None if self.bad_keys.is_error() => KeyColumns::new(0, line_s.len()),
+                None => {
+                    self.bad_keys.input_name = Some(input.name.clone());
+                    self.bad_keys.push_not_found(num, line_s)?;
+                    continue;
+                }
+            };
+            // the equal lines are ordered by the `idx` of the `HeapItem`.
+            match self.sort_buf.make_line(0, key, line_s) {
+                Ok(line) => return Ok(Some(line)),
+                Err(bad) => {
+                    self.bad_keys.input_name = Some(input.name.clone());
+                    self.bad_keys.push(num, bad)?;
+                }
+            }
+        }
+    }
+    /// push the next line of the input of the `prev` into the heap.
+    /// it is an error if the input is not sorted.
+    fn advance(&mut self, prev: &HeapItem<'a, T>) -> anyhow::Result<()> {
+        if let Some(line) = self.read_line(prev.idx)? {
+            let is_eq = self.sort_buf.eq_line(&prev.line, &line);
+            if !is_eq && self.sort_buf.cmp_line(&prev.line, &line) == std::cmp::Ordering::Greater {
+                let input = &self.inputs[prev.idx];
+                return Err(anyhow!(
+                    "{}: line {}: disorder: '{}' after '{}'",
+                    input.name,
+                    input.line_count,
+                    key_str(line.key_line()),
+                    key_str(prev.line.key_line()),
+                ));
+            }
+            self.heap.push(HeapItem::new(self.sort_buf, line, prev.idx));
+        }
+        Ok(())
+    }
+}
+
+impl<T: SortLinesBuffer> Iterator for MergedInputs<'_, T> {
+    type Item = anyhow::Result<KeyLine>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.heap.pop()?;
+        if let Err(err) = self.advance(&item) {
+            return Some(Err(err));
+        }
+        // the equal lines of the other inputs are next to it in the heap.
+        while self.conf.flg_unique {
+            match self.heap.peek() {
+                Some(top) if self.sort_buf.eq_line(&item.line, &top.line) => {
+                    let top = self.heap.pop().unwrap();
+                    if let Err(err) = self.advance(&top) {
+                        return Some(Err(err));
+                    }
+                }
+                _ => break,
+            }
+        }
+        Some(Ok(item.line.into_key_line()))
+    }
+}
+
+/// the lines with a bad key, by the `--on-bad-key`.
+struct BadKeys<'a> {
+    sioe: &'a RunnelIoe,
+    conf: &'a CmdOptConf,
+    lines: Vec<(usize, KeyLine)>,
+    /// the name of the input in the messages, for the `--merge`.
+    input_name: Option<String>,
+}
+
+impl<'a> BadKeys<'a> {
+    fn new(sioe: &'a RunnelIoe, conf: &'a CmdOptConf) -> Self {
+        Self {
+            sioe,
+            conf,
+            lines: Vec::new(),
+            input_name: None,
+        }
+    }
+    /// true if the policy is `error`. then a not found key is the whole line.
+    fn is_error(&self) -> bool {
+        matches!(self.conf.opt_on_bad_key, OptOnBadKey::Error)
+    }
+    /// true if the body is sorted even if no key is found.
+    fn is_sorted_always(&self) -> bool {
+        !self.is_error()
+    }
+    /// the input line number of the body line `num`, origin 1.
+    fn line_num(&self, num: usize) -> usize {
+        self.conf.opt_head.unwrap_or(0) + num + 1
+    }
+    /// the position of the body line `num` in the messages.
+    fn position(&self, num: usize) -> String {
+        match self.input_name.as_ref() {
+            Some(name) => format!("{}: line {}", name, self.line_num(num)),
+            None => format!("line {}", self.line_num(num)),
+        }
+    }
+    /// the key of the body line `num` is not found.
+    fn push_not_found(&mut self, num: usize, line: String) -> anyhow::Result<()> {
+        let err = anyhow!("'{}': the key is not found", line);
+        let key_line = KeyLine::new(KeyColumns::new(0, 0), line);
+        self.push(num, BadKeyLine { err, key_line })
+    }
+    /// the key of the body line `num` can not be parsed.
+    fn push(&mut self, num: usize, bad: BadKeyLine) -> anyhow::Result<()> {
+        let pos = self.position(num);
+        match self.conf.opt_on_bad_key {
+            OptOnBadKey::Error => Err(bad.err.context(pos)),
+            OptOnBadKey::Skip => {
+                let s = format!("{}: {}: skipped: {:#}", self.conf.prog_name, pos, bad.err);
+                self.sioe.pg_err().write_line(s)?;
+                Ok(())
+            }
+            OptOnBadKey::First | OptOnBadKey::Last | OptOnBadKey::KeepInPlace => {
+                self.lines.push((num, bad.key_line));
+                Ok(())
+            }
+        }
+    }
+    /// put the lines with a bad key into the sorted `body`.
+    fn into_body<'b>(self, body: KeyLines<'b>) -> KeyLines<'b> {
+        let mut lines = self.lines;
+        if lines.is_empty() {
+            return body;
+        }
+        lines.sort_unstable_by_key(|a| a.0);
+        match self.conf.opt_on_bad_key {
+            OptOnBadKey::First => Box::new(lines.into_iter().map(|a| Ok(a.1)).chain(body)),
+            OptOnBadKey::Last => Box::new(body.chain(lines.into_iter().map(|a| Ok(a.1)))),
+            OptOnBadKey::KeepInPlace => Box::new(KeepInPlaceLines {
+                body,
+                bad_lines: lines.into(),
+                pos: 0,
+            }),
+            OptOnBadKey::Error | OptOnBadKey::Skip => body,
+        }
+    }
+}
+
+/// the sorted lines, and the lines with a bad key at the original position.
+struct KeepInPlaceLines<'b> {
+    body: KeyLines<'b>,
+    bad_lines: std::collections::VecDeque<(usize, KeyLine)>,
+    pos: usize,
+}
+
+impl Iterator for KeepInPlaceLines<'_> {
+    type Item = anyhow::Result<KeyLine>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let pos = self.pos;
+        self.pos += 1;
+        match self.bad_lines.front() {
+            Some((num, _)) if *num == pos => Some(Ok(self.bad_lines.pop_front()?.1)),
+            _ => match self.body.next() {
+                Some(r) => Some(r),
+                None => Some(Ok(self.bad_lines.pop_front()?.1)),
+            },
+        }
+    }
+}
+
+type InputLines<'a> = Box<dyn Iterator<Item = anyhow::Result<String>> + 'a>;
+
+/// the names of the input files. '-' means the standard input.
+fn input_paths(conf: &CmdOptConf) -> Vec<&str> {
+    if conf.arg_params.is_empty() {
+        vec!["-"]
+    } else {
+        conf.arg_params.iter().map(|s| s.as_str()).collect()
+    }
+}
+
+/// open the one input and return an iterator over the lines of it.
+/// errors of a file are prefixed by the file name.
+fn open_input<'a>(sioe: &'a RunnelIoe, path: &str) -> anyhow::Result<InputLines<'a>> {
+    if path == "-" {
+        Ok(Box::new(sioe.pg_in().lines().map(|r| Ok(r?))))
+    } else {
+        let file = std::fs::File::open(path).with_context(|| path.to_string())?;
+        let path = path.to_string();
+        let lines = std::io::BufReader::new(file).lines();
+        Ok(Box::new(
+            lines.map(move |r| r.with_context(|| path.clone())),
+        ))
+    }
+}
+
+/// an iterator over the lines of all inputs, concatenated in order.
+/// each file is opened when the previous one is exhausted.
+fn input_lines<'a>(sioe: &'a RunnelIoe, conf: &'a CmdOptConf) -> InputLines<'a> {
+    Box::new(
+        input_paths(conf)
+            .into_iter()
+            .flat_map(move |path| match open_input(sioe, path) {
+                Ok(lines) => lines,
+                Err(err) => Box::new(std::iter::once(Err(err))),
+            }),
+    )
+}
+
+/// the options of the according-to modes from the command line.
+fn sort_options(conf: &CmdOptConf) -> anyhow::Result<SortOptions> {
+    let decimal = match &conf.opt_decimal_char {
+        Some(s) => s.chars().next().unwrap_or('.'),
+        None => '.',
+    };
+    let grouping = match &conf.opt_grouping_char {
+        Some(s) => s.chars().next(),
+        None if decimal == ',' => None,
+        None => Some(','),
+    };
+    Ok(SortOptions {
+        num_fmt: NumFormat::new(grouping, decimal),
+        date_fmt: conf.opt_date_format.clone(),
+        ipv6_first: conf.flg_ipv6_first,
+        first_weekday: conf.opt_first_weekday,
+        month_names: month_names(conf)?,
+        list_order: list_order(conf)?,
+        ignore_case: conf.flg_ignore_case,
+        normalize: conf.opt_normalize,
+        collator: Collator::new(conf.opt_collate_locale),
+    })
+}
+
+/// the order of `--order <list>` or `--order-file <file>`.
+/// without them, the order is empty, and it is not used.
+fn list_order(conf: &CmdOptConf) -> anyhow::Result<ListOrder> {
+    if conf.opt_order.is_empty() && conf.opt_order_file.is_empty() {
+        return Ok(ListOrder::default());
+    }
+    if conf.opt_order_file.is_empty() {
+        return ListOrder::from_list(&conf.opt_order, conf.opt_on_unknown);
+    }
+    let path = conf.opt_order_file.as_str();
+    let text = std::fs::read_to_string(path).with_context(|| path.to_string())?;
+    ListOrder::from_text(&text, conf.opt_on_unknown).with_context(|| path.to_string())
+}
+
+/// the month names of `--month-names <file>` or `--month-locale <loc>`.
+fn month_names(conf: &CmdOptConf) -> anyhow::Result<MonthNames> {
+    if conf.opt_month_names.is_empty() {
+        return Ok(MonthNames::new(conf.opt_month_locale.unwrap_or_default()));
+    }
+    let path = conf.opt_month_names.as_str();
+    let text = std::fs::read_to_string(path).with_context(|| path.to_string())?;
+    MonthNames::from_text(&text).with_context(|| path.to_string())
 }
 
 fn run_0(
     sioe: &RunnelIoe,
     conf: &CmdOptConf,
     env: &EnvConf,
-    re: Option<Regex>,
+    ext: &KeyExtractor,
 ) -> anyhow::Result<()> {
-    let color_start_s = env.color_seq_start.as_str();
-    let color_end_s = env.color_seq_end.as_str();
-    let color_is_alyways = matches!(conf.opt_color, OptColorWhen::Always);
     let flg_r = conf.flg_reverse;
-    let v = match conf.opt_according_to {
+    if conf.opt_key.len() > 1 || conf.opt_key.iter().any(|a| a.has_modifiers()) {
+        let keys = conf
+            .opt_key
+            .iter()
+            .map(|key_def| {
+                let ext = KeyExtractor::Field(*key_def, conf.opt_field_separator.clone());
+                let according_to = key_def.according_to().unwrap_or(conf.opt_according_to);
+                SortKey::new(ext, according_to, key_def.is_reverse())
+            })
+            .collect();
+        let sort_buf_lines = SortLinesBufferMulti::new(keys, flg_r, sort_options(conf)?);
+        return run_1(sioe, conf, env, ext, sort_buf_lines);
+    }
+    match conf.opt_according_to {
+        OptAccordingToWord::Collate => {
+            let collator = Collator::new(conf.opt_collate_locale);
+            let sort_buf_lines = SortLinesBufferCollate::new(flg_r, collator, conf.opt_normalize);
+            run_1(sioe, conf, env, ext, sort_buf_lines)
+        }
         OptAccordingToWord::Numeric => {
-            lines_loop(sioe, conf, re, SortLinesBufferNumeric::new(flg_r))?
+            run_1(sioe, conf, env, ext, SortLinesBufferNumeric::new(flg_r))
+        }
+        OptAccordingToWord::Datetime => {
+            let date_fmt = conf.opt_date_format.clone();
+            let sort_buf_lines = SortLinesBufferDatetime::new(flg_r, date_fmt);
+            run_1(sioe, conf, env, ext, sort_buf_lines)
+        }
+        OptAccordingToWord::DebVersion => {
+            run_1(sioe, conf, env, ext, SortLinesBufferDebVersion::new(flg_r))
+        }
+        OptAccordingToWord::Duration => {
+            run_1(sioe, conf, env, ext, SortLinesBufferDuration::new(flg_r))
+        }
+        OptAccordingToWord::GeneralNumeric => {
+            let num_fmt = sort_options(conf)?.num_fmt;
+            let sort_buf_lines = SortLinesBufferGeneralNumeric::new(flg_r, num_fmt);
+            run_1(sioe, conf, env, ext, sort_buf_lines)
+        }
+        OptAccordingToWord::HumanSize => {
+            run_1(sioe, conf, env, ext, SortLinesBufferHumanSize::new(flg_r))
+        }
+        OptAccordingToWord::Ip => {
+            let sort_buf_lines = SortLinesBufferIp::new(flg_r, conf.flg_ipv6_first);
+            run_1(sioe, conf, env, ext, sort_buf_lines)
+        }
+        OptAccordingToWord::LenientVersion => run_1(
+            sioe,
+            conf,
+            env,
+            ext,
+            SortLinesBufferLenientVersion::new(flg_r),
+        ),
+        OptAccordingToWord::List => {
+            let sort_buf_lines = SortLinesBufferList::new(flg_r, list_order(conf)?);
+            run_1(sioe, conf, env, ext, sort_buf_lines)
+        }
+        OptAccordingToWord::Month => {
+            let sort_buf_lines = SortLinesBufferMonth::new(flg_r, month_names(conf)?);
+            run_1(sioe, conf, env, ext, sort_buf_lines)
+        }
+        OptAccordingToWord::Natural => {
+            run_1(sioe, conf, env, ext, SortLinesBufferNatural::new(flg_r))
+        }
+        OptAccordingToWord::Pep440 => {
+            run_1(sioe, conf, env, ext, SortLinesBufferPep440::new(flg_r))
+        }
+        OptAccordingToWord::RpmVersion => {
+            run_1(sioe, conf, env, ext, SortLinesBufferRpmVersion::new(flg_r))
         }
-        OptAccordingToWord::Month => lines_loop(sioe, conf, re, SortLinesBufferMonth::new(flg_r))?,
         OptAccordingToWord::String => {
-            lines_loop(sioe, conf, re, SortLinesBufferString::new(flg_r))?
+            if conf.flg_ignore_case || conf.opt_normalize.is_some() {
+                let sort_buf_lines = SortLinesBufferMappedString::new(
+                    flg_r,
+                    conf.flg_ignore_case,
+                    conf.opt_normalize,
+                );
+                run_1(sioe, conf, env, ext, sort_buf_lines)
+            } else {
+                run_1(sioe, conf, env, ext, SortLinesBufferString::new(flg_r))
+            }
         }
-        OptAccordingToWord::Time => lines_loop(sioe, conf, re, SortLinesBufferTime::new(flg_r))?,
+        OptAccordingToWord::Time => run_1(sioe, conf, env, ext, SortLinesBufferTime::new(flg_r)),
         OptAccordingToWord::Version => {
-            lines_loop(sioe, conf, re, SortLinesBufferVersion::new(flg_r))?
+            run_1(sioe, conf, env, ext, SortLinesBufferVersion::new(flg_r))
         }
-    };
+        OptAccordingToWord::Weekday => {
+            let sort_buf_lines = SortLinesBufferWeekday::new(flg_r, conf.opt_first_weekday);
+            run_1(sioe, conf, env, ext, sort_buf_lines)
+        }
+    }
+}
+
+fn run_1<T>(
+    sioe: &RunnelIoe,
+    conf: &CmdOptConf,
+    env: &EnvConf,
+    ext: &KeyExtractor,
+    sort_buf_lines: T,
+) -> anyhow::Result<()>
+where
+    T: SortLinesBuffer + Sync,
+{
+    if conf.flg_check || conf.flg_check_quiet {
+        check_lines(sioe, conf, ext, &sort_buf_lines)
+    } else if conf.flg_merge {
+        let v = merge_lines(sioe, conf, ext, &sort_buf_lines)?;
+        output_lines(sioe, conf, env, v)
+    } else if conf.opt_buffer_size.is_some() {
+        let v = lines_loop_external(sioe, conf, ext, &sort_buf_lines)?;
+        output_lines(sioe, conf, env, v)
+    } else {
+        let v = lines_loop(sioe, conf, ext, sort_buf_lines)?;
+        output_lines(sioe, conf, env, v)
+    }
+}
+
+fn output_lines<I>(sioe: &RunnelIoe, conf: &CmdOptConf, env: &EnvConf, v: I) -> anyhow::Result<()>
+where
+    I: Iterator<Item = anyhow::Result<KeyLine>>,
+{
+    let color_start_s = env.color_seq_start.as_str();
+    let color_end_s = env.color_seq_end.as_str();
+    let color_is_alyways = matches!(conf.opt_color, OptColorWhen::Always);
     //
+    // the `--unique` is done by the sorting, this is the `--unique-line`.
     #[allow(clippy::collapsible_if)]
     if !color_is_alyways {
-        if !conf.flg_unique {
+        if !conf.flg_unique_line {
             for key_line in v {
-                sioe.pg_out().write_line(key_line.line)?;
+                sioe.pg_out().write_line(key_line?.line)?;
             }
         } else {
             let mut pre_line = String::new();
             for key_line in v {
+                let key_line = key_line?;
                 if pre_line != key_line.line {
                     sioe.pg_out().write_line(key_line.line.clone())?;
                     pre_line = key_line.line;
                 }
             }
         }
-    } else if !conf.flg_unique {
+    } else if !conf.flg_unique_line {
         for key_line in v {
-            let out_s = make_out_s(color_start_s, color_end_s, &key_line)?;
+            let out_s = make_out_s(color_start_s, color_end_s, &key_line?)?;
             sioe.pg_out().write_line(out_s)?;
         }
     } else {
         let mut pre_line = String::new();
         for key_line in v {
+            let key_line = key_line?;
             if pre_line != key_line.line {
                 let out_s = make_out_s(color_start_s, color_end_s, &key_line)?;
                 sioe.pg_out().write_line(out_s)?;
@@ -0,0 +1,42 @@
+/// the case folded string of `s`, by the full case folding of Unicode.
+/// ex) `Straße` and `STRASSE` are `strasse`.
+pub(crate) fn case_fold(s: &str) -> String {
+    let mut folded = String::with_capacity(s.len());
+    for c in s.chars() {
+        if c.is_ascii() {
+            folded.push(c.to_ascii_lowercase());
+            continue;
+        }
+        match CASE_FOLDING.binary_search_by_key(&c, |&(k, _)| k) {
+            Ok(idx) => folded.push_str(CASE_FOLDING[idx].1),
+            Err(_) => folded.push(c),
+        }
+    }
+    folded
+}
+
+include!("case_fold_table.rs.txt");
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_unicode_version() {
+        // `--normalize` with `-f` needs the same Unicode version.
+        assert_eq!(CASE_FOLDING_VERSION, unicode_normalization::UNICODE_VERSION);
+    }
+    #[test]
+    fn test_case_fold() {
+        assert_eq!(case_fold("Apple"), "apple");
+        assert_eq!(case_fold("Straße"), "strasse");
+        assert_eq!(case_fold("STRASSE"), "strasse");
+        assert_eq!(case_fold("ΣΊΣΥΦΟΣ"), case_fold("σίσυφος"));
+        assert_eq!(case_fold("ﬁle"), "file");
+        assert_eq!(case_fold("ÀÉÎ"), "àéî");
+        assert_eq!(case_fold("日本語"), "日本語");
+    }
+    #[test]
+    fn test_case_folding_sorted() {
+        assert!(CASE_FOLDING.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+}
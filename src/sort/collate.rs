@@ -0,0 +1,567 @@
+use super::string::make_normalized;
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
+use crate::util::{OptCollateLocale, OptNormalizeForm};
+use std::cmp::Ordering;
+use std::collections::HashMap;
+use unicode_normalization::UnicodeNormalization;
+
+#[derive(Debug)]
+pub struct SortLinesBufferCollate {
+    buf_lines: Vec<SortLine>,
+    reverse: bool,
+    collator: Collator,
+    normalize: Option<OptNormalizeForm>,
+}
+impl SortLinesBufferCollate {
+    pub fn new(
+        a_reverse: bool,
+        a_collator: Collator,
+        a_normalize: Option<OptNormalizeForm>,
+    ) -> Self {
+        Self {
+            buf_lines: Vec::new(),
+            reverse: a_reverse,
+            collator: a_collator,
+            normalize: a_normalize,
+        }
+    }
+}
+impl SortLinesBuffer for SortLinesBufferCollate {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
+        let num = self.buf_lines.len();
+        let sort_line = SortLine::new(num, key, line, &self.collator, self.normalize);
+        self.buf_lines.push(sort_line);
+        Ok(())
+    }
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
+        use rayon::slice::ParallelSliceMut;
+        if !self.reverse {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
+        } else {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
+        }
+        let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
+        for sort_line in self.buf_lines.into_iter() {
+            ret_vec.push(sort_line.key_line);
+        }
+        ret_vec
+    }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        Ok(SortLine::new(
+            num,
+            key,
+            line,
+            &self.collator,
+            self.normalize,
+        ))
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SortLine {
+    num: usize,
+    key: Box<[u32]>,
+    key_line: KeyLine,
+}
+
+impl SortLine {
+    fn new(
+        a_num: usize,
+        a_key: KeyColumns,
+        a_line: String,
+        a_collator: &Collator,
+        a_normalize: Option<OptNormalizeForm>,
+    ) -> Self {
+        let key = make_collate(&a_line[a_key.st..a_key.ed], a_collator, a_normalize);
+        Self {
+            num: a_num,
+            key,
+            key_line: KeyLine::new(a_key, a_line),
+        }
+    }
+}
+
+/// the sort key of `s` by `collator`, after normalized by `normalize`.
+pub(crate) fn make_collate(
+    s: &str,
+    collator: &Collator,
+    normalize: Option<OptNormalizeForm>,
+) -> Box<[u32]> {
+    match normalize {
+        Some(form) => collator.sort_key(&make_normalized(s, form)),
+        None => collator.sort_key(s),
+    }
+}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
+impl PartialOrd for SortLine {
+    #[inline]
+    fn partial_cmp(&self, other: &SortLine) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortLine {
+    #[inline]
+    fn cmp(&self, other: &SortLine) -> Ordering {
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl PartialEq for SortLine {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for SortLine {}
+
+/// the collation of the Unicode Collation Algorithm, by the table of
+/// `collate_table.txt` and the tailoring of a locale.
+#[derive(Debug, Default, Clone)]
+pub struct Collator {
+    /// the tailored letters in NFD by the first character, the longest first.
+    tailoring: HashMap<char, Vec<Tailored>>,
+}
+
+/// the rest characters of a tailored letter, and the weights of it.
+type Tailored = (Box<[char]>, [u32; 3]);
+
+impl Collator {
+    pub fn new(locale: OptCollateLocale) -> Self {
+        Self {
+            tailoring: tailor(tailoring_rules(locale)),
+        }
+    }
+    /// the sort key of `s`. it has the weights of the level 1, 2 and 3
+    /// separated by 0, and the code points of NFD as the last level, so
+    /// the canonical equivalent strings have the same key.
+    pub(crate) fn sort_key(&self, s: &str) -> Box<[u32]> {
+        let chars: Vec<char> = s.nfd().collect();
+        let mut levels: [Vec<u32>; 3] = Default::default();
+        let mut i = 0;
+        while i < chars.len() {
+            if let Some((len, ws)) = self.lookup(&chars[i..]) {
+                for lv in 0..3 {
+                    levels[lv].push(ws[lv]);
+                }
+                i += len;
+                continue;
+            }
+            match TABLE.lookup(&chars[i..]) {
+                Some((len, ws)) => {
+                    for lv in 0..3 {
+                        levels[lv].extend_from_slice(&ws[lv]);
+                    }
+                    i += len;
+                }
+                None => {
+                    levels[0].extend_from_slice(&TABLE.implicit(chars[i] as u32));
+                    levels[1].push(COMMON_L2);
+                    levels[2].push(COMMON_L3);
+                    i += 1;
+                }
+            }
+        }
+        let mut key =
+            Vec::with_capacity(levels.iter().map(|v| v.len() + 1).sum::<usize>() + chars.len());
+        for lv in levels {
+            key.extend(lv);
+            key.push(0);
+        }
+        key.extend(chars.iter().map(|&c| c as u32));
+        key.into_boxed_slice()
+    }
+    /// the length of the tailored letter at the head of `chars`, and the weights.
+    fn lookup(&self, chars: &[char]) -> Option<(usize, &[u32; 3])> {
+        let (c, rest) = chars.split_first()?;
+        let v = self.tailoring.get(c)?;
+        v.iter()
+            .find(|(r, _)| rest.starts_with(r))
+            .map(|(r, ws)| (1 + r.len(), ws))
+    }
+}
+
+/// the rules of the tailoring like the rules of CLDR. `&x` resets to `x`,
+/// and `<y`, `<<y` and `<<<y` put `y` after the last character with
+/// a difference of the level 1, 2 and 3.
+fn tailoring_rules(locale: OptCollateLocale) -> &'static str {
+    match locale {
+        OptCollateLocale::Root => "",
+        OptCollateLocale::Da | OptCollateLocale::Nb => "&z<æ<<<Æ<<ä<<<Ä<ø<<<Ø<<ö<<<Ö<å<<<Å",
+        OptCollateLocale::Es => "&n<ñ<<<Ñ",
+        OptCollateLocale::Fi | OptCollateLocale::Sv => "&z<å<<<Å<ä<<<Ä<<æ<<<Æ<ö<<<Ö<<ø<<<Ø",
+    }
+}
+
+/// the tailored letters of `rules`. a letter is in NFD, like the keys, so
+/// `å` is the contraction of `a` and the combining ring above.
+fn tailor(rules: &str) -> HashMap<char, Vec<Tailored>> {
+    let mut tailoring: HashMap<char, Vec<Tailored>> = HashMap::new();
+    let mut last = [0; 3];
+    let mut chars = rules.chars().peekable();
+    while let Some(c) = chars.next() {
+        let level = match c {
+            '&' => {
+                if let Some((_, ws)) = chars.next().and_then(|c| TABLE.lookup(&[c])) {
+                    last = [ws[0][0], ws[1][0], ws[2][0]];
+                }
+                continue;
+            }
+            '<' => {
+                let mut level = 0;
+                while chars.next_if_eq(&'<').is_some() {
+                    level += 1;
+                }
+                level
+            }
+            _ => continue,
+        };
+        let Some(c) = chars.next() else {
+            break;
+        };
+        let mut ws = last;
+        ws[level] += 1;
+        if level < 1 {
+            ws[1] = COMMON_L2;
+        }
+        if level < 2 {
+            ws[2] = COMMON_L3;
+        }
+        let nfd: Vec<char> = c.to_string().nfd().collect();
+        if let Some((first, rest)) = nfd.split_first() {
+            let v = tailoring.entry(*first).or_default();
+            v.push((rest.into(), ws));
+            v.sort_by_key(|(rest, _)| std::cmp::Reverse(rest.len()));
+        }
+        last = ws;
+    }
+    tailoring
+}
+
+// the weights of the table are shifted to leave room for the tailoring.
+const WEIGHT_SHIFT: u32 = 8;
+
+/// the level 2 and 3 weights of the implicit weighting of DUCET.
+const COMMON_L2: u32 = 0x20 << WEIGHT_SHIFT;
+const COMMON_L3: u32 = 0x02 << WEIGHT_SHIFT;
+
+const TABLE_TEXT: &str = include_str!("collate_table.txt");
+
+lazy_static! {
+    static ref TABLE: CollateTable = CollateTable::parse(TABLE_TEXT);
+}
+
+/// the weights of the level 1, 2 and 3 of a collation entry.
+type Weights = [Box<[u32]>; 3];
+
+/// the rest characters of a contraction, and the weights of it.
+type Contraction = (Box<[char]>, Weights);
+
+#[derive(Debug, Default)]
+struct CollateTable {
+    singles: HashMap<char, Weights>,
+    /// the contractions by the first character, the longest first.
+    contractions: HashMap<char, Vec<Contraction>>,
+    /// the ranges of the implicit weights, `(first, last, base, start)`.
+    /// `start` is the first code point of the ranges of the same `base`.
+    implicit: Vec<(u32, u32, u32, u32)>,
+}
+
+impl CollateTable {
+    fn parse(text: &str) -> Self {
+        let mut table = Self::default();
+        for line in text.lines() {
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.split(';');
+            let name = fields.next().unwrap_or_default();
+            let fields: Vec<Vec<u32>> = fields
+                .map(|s| s.split_whitespace().map(hex).collect())
+                .collect();
+            if let Some(s) = name.strip_prefix("@implicit ") {
+                let range: Vec<u32> = s.split_whitespace().map(hex).collect();
+                let base = fields.first().and_then(|v| v.first()).copied();
+                if let ([first, last], Some(base)) = (range.as_slice(), base) {
+                    table.implicit.push((*first, *last, base, *first));
+                }
+                continue;
+            }
+            if name.starts_with('@') || fields.len() != 3 {
+                continue;
+            }
+            let ws = [0, 1, 2].map(|lv| {
+                let v: Vec<u32> = fields[lv].iter().map(|w| w << WEIGHT_SHIFT).collect();
+                v.into_boxed_slice()
+            });
+            let cs: Vec<char> = name
+                .split_whitespace()
+                .filter_map(|s| char::from_u32(hex(s)))
+                .collect();
+            match cs.as_slice() {
+                [] => (),
+                [c] => {
+                    table.singles.insert(*c, ws);
+                }
+                [c, rest @ ..] => {
+                    let v = table.contractions.entry(*c).or_default();
+                    v.push((rest.into(), ws));
+                    v.sort_by_key(|(rest, _)| std::cmp::Reverse(rest.len()));
+                }
+            }
+        }
+        let starts = table.implicit.clone();
+        for (_, _, base, start) in table.implicit.iter_mut() {
+            for (first, _, b, _) in starts.iter() {
+                if b == base && first < start {
+                    *start = *first;
+                }
+            }
+        }
+        table
+    }
+    /// the length of the longest match at the head of `chars`, and the weights.
+    fn lookup(&self, chars: &[char]) -> Option<(usize, &Weights)> {
+        let (c, rest) = chars.split_first()?;
+        if let Some(v) = self.contractions.get(c) {
+            for (r, ws) in v {
+                if rest.starts_with(r) {
+                    return Some((1 + r.len(), ws));
+                }
+            }
+        }
+        self.singles.get(c).map(|ws| (1, ws))
+    }
+    /// the level 1 weights of the code point that is not in the table.
+    fn implicit(&self, cp: u32) -> [u32; 2] {
+        let range = self
+            .implicit
+            .iter()
+            .find(|(first, last, _, _)| (*first..=*last).contains(&cp));
+        let (base1, base2) = match range {
+            Some((_, _, base, start)) => (*base, (cp - start) | 0x8000),
+            None => {
+                let base = if is_han_core(cp) {
+                    0xFB40
+                } else if is_han_ext(cp) {
+                    0xFB80
+                } else {
+                    0xFBC0
+                };
+                (base + (cp >> 15), (cp & 0x7FFF) | 0x8000)
+            }
+        };
+        [base1 << WEIGHT_SHIFT, base2 << WEIGHT_SHIFT]
+    }
+}
+
+fn hex(s: &str) -> u32 {
+    u32::from_str_radix(s, 16).unwrap_or_default()
+}
+
+/// the unified ideographs of the blocks of CJK Unified Ideographs and
+/// CJK Compatibility Ideographs, of Unicode 13.0.0.
+fn is_han_core(cp: u32) -> bool {
+    const COMPAT: [u32; 12] = [
+        0xFA0E, 0xFA0F, 0xFA11, 0xFA13, 0xFA14, 0xFA1F, 0xFA21, 0xFA23, 0xFA24, 0xFA27, 0xFA28,
+        0xFA29,
+    ];
+    (0x4E00..=0x9FFC).contains(&cp) || COMPAT.contains(&cp)
+}
+
+/// the other unified ideographs of Unicode 13.0.0, the extension A to G.
+fn is_han_ext(cp: u32) -> bool {
+    (0x3400..=0x4DBF).contains(&cp)
+        || (0x20000..=0x2A6DD).contains(&cp)
+        || (0x2A700..=0x2B734).contains(&cp)
+        || (0x2B740..=0x2B81D).contains(&cp)
+        || (0x2B820..=0x2CEA1).contains(&cp)
+        || (0x2CEB0..=0x2EBE0).contains(&cp)
+        || (0x30000..=0x3134A).contains(&cp)
+}
+
+#[cfg(test)]
+mod debug {
+    use super::*;
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferCollate>(), 80);
+        assert_eq!(std::mem::size_of::<SortLine>(), 64);
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferCollate>(), 48);
+        assert_eq!(std::mem::size_of::<SortLine>(), 32);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn sorted(collator: &Collator, v: &[&str]) -> Vec<String> {
+        let mut v: Vec<String> = v.iter().map(|s| s.to_string()).collect();
+        v.sort_by_key(|s| collator.sort_key(s));
+        v
+    }
+    #[test]
+    fn test_sort_key_latin() {
+        let collator = Collator::new(OptCollateLocale::Root);
+        assert_eq!(
+            sorted(&collator, &["zebra", "éclair", "Eclair", "eclair", "apple"]),
+            vec!["apple", "eclair", "Eclair", "éclair", "zebra"]
+        );
+        assert_eq!(
+            sorted(&collator, &["straße", "strasse", "strasbourg", "strata"]),
+            vec!["strasbourg", "strasse", "straße", "strata"]
+        );
+    }
+    #[test]
+    fn test_sort_key_ignorable() {
+        let collator = Collator::new(OptCollateLocale::Root);
+        assert_eq!(
+            sorted(&collator, &["coop", "co-op", "co op", "cop"]),
+            vec!["co op", "co-op", "coop", "cop"]
+        );
+    }
+    #[test]
+    fn test_sort_key_kana() {
+        let collator = Collator::new(OptCollateLocale::Root);
+        assert_eq!(
+            sorted(&collator, &["カ", "か", "い", "ア", "あ"]),
+            vec!["あ", "ア", "い", "か", "カ"]
+        );
+    }
+    #[test]
+    fn test_sort_key_implicit() {
+        let collator = Collator::new(OptCollateLocale::Root);
+        // hangul, han, and the han extension b.
+        assert_eq!(
+            sorted(&collator, &["\u{20000}", "一", "丁", "가", "z"]),
+            vec!["z", "가", "一", "丁", "\u{20000}"]
+        );
+        assert_eq!(
+            sorted(&collator, &["각", "가", "나"]),
+            vec!["가", "각", "나"]
+        );
+    }
+    #[test]
+    fn test_sort_key_tailoring() {
+        let v = ["öl", "zoo", "ål", "äpple", "apple"];
+        let collator = Collator::new(OptCollateLocale::Root);
+        assert_eq!(
+            sorted(&collator, &v),
+            vec!["ål", "apple", "äpple", "öl", "zoo"]
+        );
+        let collator = Collator::new(OptCollateLocale::Sv);
+        assert_eq!(
+            sorted(&collator, &v),
+            vec!["apple", "zoo", "ål", "äpple", "öl"]
+        );
+        let collator = Collator::new(OptCollateLocale::Da);
+        assert_eq!(
+            sorted(&collator, &v),
+            vec!["apple", "zoo", "äpple", "öl", "ål"]
+        );
+        let collator = Collator::new(OptCollateLocale::Es);
+        assert_eq!(
+            sorted(&collator, &["nube", "ñu", "oso", "Ñandú"]),
+            vec!["nube", "Ñandú", "ñu", "oso"]
+        );
+    }
+    #[test]
+    fn test_sort_key_canonical_equivalence() {
+        let collator = Collator::new(OptCollateLocale::Root);
+        assert_eq!(
+            collator.sort_key("\u{e9}clair"),
+            collator.sort_key("e\u{301}clair")
+        );
+        // the angstrom sign, and a with ring above.
+        assert_eq!(collator.sort_key("\u{212b}"), collator.sort_key("\u{c5}"));
+        assert_ne!(
+            collator.sort_key("\u{e9}clair"),
+            collator.sort_key("eclair")
+        );
+    }
+    #[test]
+    fn test_sort_key_tailoring_nfd() {
+        let collator = Collator::new(OptCollateLocale::Sv);
+        assert_eq!(
+            sorted(&collator, &["a\u{30a}l", "zoo", "\u{e5}m", "apple"]),
+            vec!["apple", "zoo", "a\u{30a}l", "\u{e5}m"]
+        );
+        assert_eq!(collator.sort_key("A\u{30a}l"), collator.sort_key("\u{c5}l"));
+    }
+    #[test]
+    fn test_table_unicode_version() {
+        let version = TABLE_TEXT
+            .lines()
+            .find_map(|line| line.strip_prefix("@version "))
+            .unwrap();
+        let (major, minor, update) = unicode_normalization::UNICODE_VERSION;
+        assert_eq!(version, format!("{major}.{minor}.{update}"));
+    }
+    #[test]
+    fn test_table() {
+        assert!(TABLE.singles.len() > 20000);
+        assert!(!TABLE.contractions.is_empty());
+        assert_eq!(TABLE.implicit.len(), 4);
+        // the tangut supplement follows the tangut.
+        assert_eq!(TABLE.implicit(0x18D00), [0xFB00 << 8, 0x9D00 << 8]);
+        assert_eq!(TABLE.implicit(0x4E00), [0xFB40 << 8, 0xCE00 << 8]);
+    }
+}
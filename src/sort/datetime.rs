@@ -0,0 +1,298 @@
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+use std::cmp::Ordering;
+
+#[derive(Debug)]
+pub struct SortLinesBufferDatetime {
+    buf_lines: Vec<SortLine>,
+    reverse: bool,
+    date_fmt: Option<String>,
+}
+impl SortLinesBufferDatetime {
+    pub fn new(a_reverse: bool, a_date_fmt: Option<String>) -> Self {
+        Self {
+            buf_lines: Vec::new(),
+            reverse: a_reverse,
+            date_fmt: a_date_fmt,
+        }
+    }
+}
+impl SortLinesBuffer for SortLinesBufferDatetime {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
+        let sort_line = SortLine::new(self.buf_lines.len(), key, line, self.date_fmt.as_deref())?;
+        self.buf_lines.push(sort_line);
+        Ok(())
+    }
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
+        use rayon::slice::ParallelSliceMut;
+        if !self.reverse {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
+        } else {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
+        }
+        let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
+        for sort_line in self.buf_lines.into_iter() {
+            ret_vec.push(sort_line.key_line);
+        }
+        ret_vec
+    }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        SortLine::new(num, key, line, self.date_fmt.as_deref())
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SortLine {
+    num: usize,
+    key: NaiveDateTime,
+    key_line: KeyLine,
+}
+
+impl SortLine {
+    fn new(
+        a_num: usize,
+        a_key: KeyColumns,
+        a_line: String,
+        a_date_fmt: Option<&str>,
+    ) -> Result<Self, BadKeyLine> {
+        let key_num = match make_datetime(&a_line[a_key.st..a_key.ed], a_date_fmt) {
+            Ok(key_num) => key_num,
+            Err(err) => return Err(BadKeyLine::new(err, a_key, a_line)),
+        };
+        Ok(Self {
+            num: a_num,
+            key: key_num,
+            key_line: KeyLine::new(a_key, a_line),
+        })
+    }
+}
+
+/// the formats with a time zone, ISO 8601, common log format.
+const FORMATS_TZ: [&str; 5] = [
+    "%Y-%m-%dT%H:%M:%S%.f%:z",
+    "%Y-%m-%d %H:%M:%S%.f%:z",
+    "%Y-%m-%dT%H:%M:%S%.f%z",
+    "%Y-%m-%d %H:%M:%S%.f%z",
+    "%d/%b/%Y:%H:%M:%S%.f %z",
+];
+
+/// the formats without a time zone, ISO 8601.
+const FORMATS_NAIVE: [&str; 4] = [
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%d %H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M",
+];
+
+/// the date time of `s` in UTC.
+///
+/// if `fmt` is `None`, `s` is ISO 8601, RFC 2822, syslog or common log format.
+/// the date time without a time zone is UTC, and syslog without a year is
+/// the year 0.
+pub(crate) fn make_datetime(s: &str, fmt: Option<&str>) -> anyhow::Result<NaiveDateTime> {
+    let s = s.trim();
+    if let Some(fmt) = fmt {
+        return parse_with_format(s, fmt);
+    }
+    let naive_s = s.strip_suffix('Z').unwrap_or(s);
+    let dt = FORMATS_TZ
+        .iter()
+        .find_map(|f| DateTime::parse_from_str(s, f).ok())
+        .or_else(|| DateTime::parse_from_rfc2822(s).ok())
+        .map(|dt| dt.naive_utc())
+        .or_else(|| {
+            FORMATS_NAIVE
+                .iter()
+                .find_map(|f| NaiveDateTime::parse_from_str(naive_s, f).ok())
+        })
+        .or_else(|| {
+            NaiveDate::parse_from_str(s, "%Y-%m-%d")
+                .ok()
+                .and_then(|d| d.and_hms_opt(0, 0, 0))
+        })
+        .or_else(|| {
+            let s = format!("0000 {s}");
+            NaiveDateTime::parse_from_str(&s, "%Y %b %e %H:%M:%S%.f").ok()
+        });
+    match dt {
+        Some(dt) => Ok(dt),
+        None => Err(anyhow!("unknown format while parsing datetime")),
+    }
+}
+
+/// the date time of `s` by the strftime-like `fmt`. the time zone and
+/// the time can be omitted, then they are UTC and 00:00.
+fn parse_with_format(s: &str, fmt: &str) -> anyhow::Result<NaiveDateTime> {
+    use chrono::format::ParseErrorKind;
+    let err = match DateTime::parse_from_str(s, fmt) {
+        Ok(dt) => return Ok(dt.naive_utc()),
+        Err(err) => err,
+    };
+    if err.kind() == ParseErrorKind::NotEnough {
+        if let Ok(dt) = NaiveDateTime::parse_from_str(s, fmt) {
+            return Ok(dt);
+        }
+        if let Some(dt) = NaiveDate::parse_from_str(s, fmt)
+            .ok()
+            .and_then(|d| d.and_hms_opt(0, 0, 0))
+        {
+            return Ok(dt);
+        }
+    }
+    Err(anyhow!("{err} while parsing datetime by '{fmt}'"))
+}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
+impl PartialOrd for SortLine {
+    #[inline]
+    fn partial_cmp(&self, other: &SortLine) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortLine {
+    #[inline]
+    fn cmp(&self, other: &SortLine) -> Ordering {
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl PartialEq for SortLine {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for SortLine {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn utc(s: &str) -> NaiveDateTime {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S%.f").unwrap()
+    }
+    #[test]
+    fn test_make_datetime() {
+        let dt = utc("2024-03-01 03:00:00");
+        assert_eq!(
+            make_datetime("2024-03-01T12:00:00+09:00", None).unwrap(),
+            dt
+        );
+        assert_eq!(make_datetime("2024-03-01 03:00:00Z", None).unwrap(), dt);
+        assert_eq!(make_datetime("2024-03-01T03:00:00", None).unwrap(), dt);
+        assert_eq!(make_datetime("2024-03-01T03:00", None).unwrap(), dt);
+        assert_eq!(make_datetime("2024-02-29T19:00:00-0800", None).unwrap(), dt);
+        assert_eq!(
+            make_datetime("Fri, 01 Mar 2024 03:00:00 +0000", None).unwrap(),
+            dt
+        );
+        assert_eq!(
+            make_datetime("01/Mar/2024:12:00:00 +0900", None).unwrap(),
+            dt
+        );
+        assert_eq!(
+            make_datetime("2024-03-01T03:00:00.250Z", None).unwrap(),
+            utc("2024-03-01 03:00:00.25")
+        );
+        assert_eq!(
+            make_datetime("2024-03-01", None).unwrap(),
+            utc("2024-03-01 00:00:00")
+        );
+        assert_eq!(
+            make_datetime("Mar  1 12:00:00", None).unwrap(),
+            utc("0000-03-01 12:00:00")
+        );
+    }
+    #[test]
+    fn test_make_datetime_format() {
+        let dt = utc("2024-03-01 12:30:00");
+        let fmt = Some("%Y/%m/%d %H:%M");
+        assert_eq!(make_datetime("2024/03/01 12:30", fmt).unwrap(), dt);
+        let fmt = Some("%d.%m.%Y %H:%M %z");
+        assert_eq!(make_datetime("01.03.2024 13:30 +0100", fmt).unwrap(), dt);
+        let fmt = Some("%d.%m.%Y");
+        assert_eq!(
+            make_datetime("01.03.2024", fmt).unwrap(),
+            utc("2024-03-01 00:00:00")
+        );
+    }
+    #[test]
+    fn test_make_datetime_invalid() {
+        let e = make_datetime("2024-13-01", None).unwrap_err();
+        assert_eq!(e.to_string(), "unknown format while parsing datetime");
+        let e = make_datetime("2024-03-01", Some("%d.%m.%Y")).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "input contains invalid characters while parsing datetime by '%d.%m.%Y'"
+        );
+    }
+}
+
+#[cfg(test)]
+mod debug {
+    use super::*;
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferDatetime>(), 56);
+        assert_eq!(std::mem::size_of::<SortLine>(), 64);
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferDatetime>(), 28);
+        assert_eq!(std::mem::size_of::<SortLine>(), 36);
+    }
+}
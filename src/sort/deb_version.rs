@@ -0,0 +1,327 @@
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
+use std::cmp::Ordering;
+
+#[derive(Debug)]
+pub struct SortLinesBufferDebVersion {
+    buf_lines: Vec<SortLine>,
+    reverse: bool,
+}
+impl SortLinesBufferDebVersion {
+    pub fn new(a_reverse: bool) -> Self {
+        Self {
+            buf_lines: Vec::new(),
+            reverse: a_reverse,
+        }
+    }
+}
+impl SortLinesBuffer for SortLinesBufferDebVersion {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
+        let sort_line = SortLine::new(self.buf_lines.len(), key, line)?;
+        self.buf_lines.push(sort_line);
+        Ok(())
+    }
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
+        use rayon::slice::ParallelSliceMut;
+        if !self.reverse {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
+        } else {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
+        }
+        let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
+        for sort_line in self.buf_lines.into_iter() {
+            ret_vec.push(sort_line.key_line);
+        }
+        ret_vec
+    }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        SortLine::new(num, key, line)
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SortLine {
+    num: usize,
+    key: DebVersion,
+    key_line: KeyLine,
+}
+
+impl SortLine {
+    fn new(a_num: usize, a_key: KeyColumns, a_line: String) -> Result<Self, BadKeyLine> {
+        let key_num = match make_deb_version(&a_line[a_key.st..a_key.ed]) {
+            Ok(key_num) => key_num,
+            Err(err) => return Err(BadKeyLine::new(err, a_key, a_line)),
+        };
+        Ok(Self {
+            num: a_num,
+            key: key_num,
+            key_line: KeyLine::new(a_key, a_line),
+        })
+    }
+}
+
+/// the debian package version, `[epoch:]upstream[-revision]`.
+#[derive(Debug)]
+pub(crate) struct DebVersion {
+    epoch: u64,
+    upstream: Box<str>,
+    revision: Box<str>,
+}
+
+/// the debian package version of `s`, like `dpkg --compare-versions`.
+pub(crate) fn make_deb_version(s: &str) -> anyhow::Result<DebVersion> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(anyhow!("empty string while parsing deb-version"));
+    }
+    let (epoch, rest) = match s.split_once(':') {
+        Some((epoch_s, rest)) => {
+            let epoch = epoch_s.parse::<u64>().map_err(|_| {
+                anyhow!("epoch '{epoch_s}' is not a number while parsing deb-version")
+            })?;
+            (epoch, rest)
+        }
+        None => (0, s),
+    };
+    let (upstream, revision) = match rest.rsplit_once('-') {
+        Some((upstream, revision)) => {
+            if revision.is_empty() {
+                return Err(anyhow!("empty revision while parsing deb-version"));
+            }
+            (upstream, revision)
+        }
+        None => (rest, ""),
+    };
+    if upstream.is_empty() {
+        return Err(anyhow!("empty upstream version while parsing deb-version"));
+    }
+    let is_valid =
+        |c: char, extra: &str| c.is_ascii_alphanumeric() || ".+~".contains(c) || extra.contains(c);
+    let invalid = upstream
+        .chars()
+        .find(|&c| !is_valid(c, "-:"))
+        .or_else(|| revision.chars().find(|&c| !is_valid(c, "")));
+    if let Some(c) = invalid {
+        return Err(anyhow!(
+            "unexpected character '{c}' while parsing deb-version"
+        ));
+    }
+    Ok(DebVersion {
+        epoch,
+        upstream: upstream.into(),
+        revision: revision.into(),
+    })
+}
+
+/// the order of a character of the non-digit part. `~` is the smallest,
+/// even than the end, and the letters are smaller than the others.
+#[inline]
+fn char_order(c: Option<u8>) -> i32 {
+    match c {
+        None => 0,
+        Some(c) if c.is_ascii_digit() => 0,
+        Some(c) if c.is_ascii_alphabetic() => c as i32,
+        Some(b'~') => -1,
+        Some(c) => c as i32 + 256,
+    }
+}
+
+/// compare the parts of the debian version, the `verrevcmp()` of dpkg.
+pub(crate) fn verrevcmp(a: &str, b: &str) -> Ordering {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let is_digit = |s: &[u8], i: usize| s.get(i).map_or(false, |c| c.is_ascii_digit());
+    let (mut i, mut k) = (0, 0);
+    while i < a.len() || k < b.len() {
+        while (i < a.len() && !is_digit(a, i)) || (k < b.len() && !is_digit(b, k)) {
+            let ac = char_order(a.get(i).copied());
+            let bc = char_order(b.get(k).copied());
+            if ac != bc {
+                return ac.cmp(&bc);
+            }
+            i += 1;
+            k += 1;
+        }
+        while a.get(i) == Some(&b'0') {
+            i += 1;
+        }
+        while b.get(k) == Some(&b'0') {
+            k += 1;
+        }
+        let mut first_diff = Ordering::Equal;
+        while is_digit(a, i) && is_digit(b, k) {
+            if first_diff == Ordering::Equal {
+                first_diff = a[i].cmp(&b[k]);
+            }
+            i += 1;
+            k += 1;
+        }
+        if is_digit(a, i) {
+            return Ordering::Greater;
+        }
+        if is_digit(b, k) {
+            return Ordering::Less;
+        }
+        if first_diff != Ordering::Equal {
+            return first_diff;
+        }
+    }
+    Ordering::Equal
+}
+
+impl Ord for DebVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.epoch
+            .cmp(&other.epoch)
+            .then_with(|| verrevcmp(&self.upstream, &other.upstream))
+            .then_with(|| verrevcmp(&self.revision, &other.revision))
+    }
+}
+
+impl PartialOrd for DebVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for DebVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for DebVersion {}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
+impl PartialOrd for SortLine {
+    #[inline]
+    fn partial_cmp(&self, other: &SortLine) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortLine {
+    #[inline]
+    fn cmp(&self, other: &SortLine) -> Ordering {
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl PartialEq for SortLine {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for SortLine {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn cmp(a: &str, b: &str) -> Ordering {
+        make_deb_version(a)
+            .unwrap()
+            .cmp(&make_deb_version(b).unwrap())
+    }
+    #[test]
+    fn test_cmp_deb_version() {
+        assert_eq!(cmp("1.0~rc1", "1.0"), Ordering::Less);
+        assert_eq!(cmp("1.0~rc1", "1.0~rc2"), Ordering::Less);
+        assert_eq!(cmp("1.0~~", "1.0~"), Ordering::Less);
+        assert_eq!(cmp("1:2.3-4ubuntu1", "2.4.1.7"), Ordering::Greater);
+        assert_eq!(cmp("2.3-4ubuntu1", "2.3-4"), Ordering::Greater);
+        assert_eq!(cmp("2.3-4ubuntu1", "2.3-10"), Ordering::Less);
+        assert_eq!(cmp("1.0a", "1.0+"), Ordering::Less);
+        assert_eq!(cmp("1.01", "1.1"), Ordering::Equal);
+        assert_eq!(cmp("1.0", "1.0-0"), Ordering::Equal);
+        assert_eq!(cmp("1.2-3-4", "1.2-3-10"), Ordering::Less);
+        assert_eq!(cmp("v1.2", "v1.10"), Ordering::Less);
+    }
+    #[test]
+    fn test_make_deb_version_invalid() {
+        let e = make_deb_version("a:1.0").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "epoch 'a' is not a number while parsing deb-version"
+        );
+        let e = make_deb_version("1.0-").unwrap_err();
+        assert_eq!(e.to_string(), "empty revision while parsing deb-version");
+        let e = make_deb_version("1:-1").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "empty upstream version while parsing deb-version"
+        );
+        let e = make_deb_version("1.0_1").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "unexpected character '_' while parsing deb-version"
+        );
+    }
+}
+
+#[cfg(test)]
+mod debug {
+    use super::*;
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferDebVersion>(), 32);
+        assert_eq!(std::mem::size_of::<SortLine>(), 88);
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferDebVersion>(), 16);
+        assert_eq!(std::mem::size_of::<SortLine>(), 48);
+    }
+}
@@ -0,0 +1,285 @@
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
+use std::cmp::Ordering;
+use std::time::Duration;
+
+#[derive(Debug)]
+pub struct SortLinesBufferDuration {
+    buf_lines: Vec<SortLine>,
+    reverse: bool,
+}
+impl SortLinesBufferDuration {
+    pub fn new(a_reverse: bool) -> Self {
+        Self {
+            buf_lines: Vec::new(),
+            reverse: a_reverse,
+        }
+    }
+}
+impl SortLinesBuffer for SortLinesBufferDuration {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
+        let sort_line = SortLine::new(self.buf_lines.len(), key, line)?;
+        self.buf_lines.push(sort_line);
+        Ok(())
+    }
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
+        use rayon::slice::ParallelSliceMut;
+        if !self.reverse {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
+        } else {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
+        }
+        let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
+        for sort_line in self.buf_lines.into_iter() {
+            ret_vec.push(sort_line.key_line);
+        }
+        ret_vec
+    }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        SortLine::new(num, key, line)
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SortLine {
+    num: usize,
+    key: Duration,
+    key_line: KeyLine,
+}
+
+impl SortLine {
+    fn new(a_num: usize, a_key: KeyColumns, a_line: String) -> Result<Self, BadKeyLine> {
+        let key_num = match make_duration(&a_line[a_key.st..a_key.ed]) {
+            Ok(key_num) => key_num,
+            Err(err) => return Err(BadKeyLine::new(err, a_key, a_line)),
+        };
+        Ok(Self {
+            num: a_num,
+            key: key_num,
+            key_line: KeyLine::new(a_key, a_line),
+        })
+    }
+}
+
+/// the units of the duration in nanoseconds.
+const UNITS: [(&str, u128); 10] = [
+    ("ns", 1),
+    ("us", 1_000),
+    ("\u{b5}s", 1_000),
+    ("\u{3bc}s", 1_000),
+    ("ms", 1_000_000),
+    ("s", 1_000_000_000),
+    ("m", 60 * 1_000_000_000),
+    ("h", 60 * 60 * 1_000_000_000),
+    ("d", 24 * 60 * 60 * 1_000_000_000),
+    ("w", 7 * 24 * 60 * 60 * 1_000_000_000),
+];
+
+/// the duration of the sequence of the numbers and the units, like `1h30m`,
+/// `1m2.5s` and `350\u{b5}s`. the units are `ns`, `us` or `\u{b5}s`, `ms`, `s`,
+/// `m`, `h`, `d` and `w`, and they can be separated by the blanks.
+/// `0` without a unit is zero.
+pub(crate) fn make_duration(s: &str) -> anyhow::Result<Duration> {
+    let s = s.trim();
+    if s == "0" {
+        return Ok(Duration::ZERO);
+    }
+    if s.is_empty() {
+        return Err(anyhow!("empty string while parsing duration"));
+    }
+    let overflow = || anyhow!("overflow while parsing duration");
+    let mut total: u128 = 0;
+    let mut rest = s;
+    while !rest.is_empty() {
+        let num_len = rest
+            .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+            .unwrap_or(rest.len());
+        let unit_len = rest[num_len..]
+            .find(|c: char| c.is_ascii_digit() || c == '.' || c.is_whitespace())
+            .unwrap_or(rest.len() - num_len);
+        let (num_s, unit_s) = (&rest[..num_len], &rest[num_len..(num_len + unit_len)]);
+        let part = &rest[..(num_len + unit_len)];
+        rest = rest[part.len()..].trim_start();
+        //
+        let (int_s, frac_s) = match num_s.find('.') {
+            Some(idx) => (&num_s[..idx], &num_s[(idx + 1)..]),
+            None => (num_s, ""),
+        };
+        if (int_s.is_empty() && frac_s.is_empty()) || frac_s.contains('.') {
+            return Err(anyhow!("invalid number '{part}' while parsing duration"));
+        }
+        let unit = match UNITS.iter().find(|(name, _)| *name == unit_s) {
+            Some((_, unit)) => *unit,
+            None if unit_s.is_empty() => {
+                return Err(anyhow!("missing unit '{part}' while parsing duration"));
+            }
+            None => {
+                return Err(anyhow!(
+                    "unknown unit '{unit_s}' of '{part}' while parsing duration"
+                ));
+            }
+        };
+        let int = if int_s.is_empty() {
+            0
+        } else {
+            int_s.parse::<u128>().map_err(|_| overflow())?
+        };
+        // the digits under nanoseconds are truncated.
+        let frac_s = &frac_s[..frac_s.len().min(18)];
+        let frac = if frac_s.is_empty() {
+            0
+        } else {
+            frac_s.parse::<u128>()? * unit / 10u128.pow(frac_s.len() as u32)
+        };
+        total = int
+            .checked_mul(unit)
+            .and_then(|a| a.checked_add(frac))
+            .and_then(|a| a.checked_add(total))
+            .ok_or_else(overflow)?;
+    }
+    let secs = u64::try_from(total / 1_000_000_000).map_err(|_| overflow())?;
+    Ok(Duration::new(secs, (total % 1_000_000_000) as u32))
+}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
+impl PartialOrd for SortLine {
+    #[inline]
+    fn partial_cmp(&self, other: &SortLine) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortLine {
+    #[inline]
+    fn cmp(&self, other: &SortLine) -> Ordering {
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl PartialEq for SortLine {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for SortLine {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_make_duration() {
+        assert_eq!(make_duration("0").unwrap(), Duration::ZERO);
+        assert_eq!(make_duration("1h30m").unwrap(), Duration::from_secs(5400));
+        assert_eq!(make_duration("1h 30m").unwrap(), Duration::from_secs(5400));
+        assert_eq!(
+            make_duration("1m2.5s").unwrap(),
+            Duration::from_millis(62_500)
+        );
+        assert_eq!(make_duration("250ms").unwrap(), Duration::from_millis(250));
+        assert_eq!(
+            make_duration("350\u{b5}s").unwrap(),
+            Duration::from_micros(350)
+        );
+        assert_eq!(
+            make_duration("350\u{3bc}s").unwrap(),
+            Duration::from_micros(350)
+        );
+        assert_eq!(make_duration("350us").unwrap(), Duration::from_micros(350));
+        assert_eq!(make_duration("2d").unwrap(), Duration::from_secs(2 * 86400));
+        assert_eq!(make_duration(".5w").unwrap(), Duration::from_secs(302_400));
+        assert_eq!(make_duration("1.5ns").unwrap(), Duration::from_nanos(1));
+    }
+    #[test]
+    fn test_make_duration_invalid() {
+        let e = make_duration("").unwrap_err();
+        assert_eq!(e.to_string(), "empty string while parsing duration");
+        let e = make_duration("1h5q").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "unknown unit 'q' of '5q' while parsing duration"
+        );
+        let e = make_duration("1h 30").unwrap_err();
+        assert_eq!(e.to_string(), "missing unit '30' while parsing duration");
+        let e = make_duration("1.2.3s").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "invalid number '1.2.3s' while parsing duration"
+        );
+        let e = make_duration("ms").unwrap_err();
+        assert_eq!(e.to_string(), "invalid number 'ms' while parsing duration");
+        let e = make_duration("99999999999999999999w").unwrap_err();
+        assert_eq!(e.to_string(), "overflow while parsing duration");
+    }
+}
+
+#[cfg(test)]
+mod debug {
+    use super::*;
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferDuration>(), 32);
+        assert_eq!(std::mem::size_of::<SortLine>(), 64);
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferDuration>(), 16);
+        #[cfg(not(any(target_arch = "arm", target_arch = "mips")))]
+        assert_eq!(std::mem::size_of::<SortLine>(), 36);
+        #[cfg(any(target_arch = "arm", target_arch = "mips"))]
+        assert_eq!(std::mem::size_of::<SortLine>(), 40);
+    }
+}
@@ -0,0 +1,351 @@
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
+use anyhow::Context;
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use std::fs::{File, OpenOptions};
+use std::io::{BufRead, BufReader, BufWriter, Write};
+use std::path::PathBuf;
+
+pub(crate) type KeyLines<'a> = Box<dyn Iterator<Item = anyhow::Result<KeyLine>> + 'a>;
+
+/// external merge sort.
+///
+/// the chunks of lines are sorted in memory and spilled to temporary files
+/// as the sorted runs, and then the runs are merged with k-way merge.
+/// the lines keep the original line number, so the tie-break is the same
+/// as the in-memory sort.
+#[derive(Debug)]
+pub(crate) struct ExternalSorter {
+    tmp_dir: PathBuf,
+    runs: Vec<RunFile>,
+    unique: bool,
+}
+
+impl ExternalSorter {
+    /// if `unique` is true, only the first line of an equal key is kept.
+    pub fn new(a_tmp_dir: &str, a_unique: bool) -> Self {
+        let tmp_dir = if a_tmp_dir.is_empty() {
+            std::env::temp_dir()
+        } else {
+            PathBuf::from(a_tmp_dir)
+        };
+        Self {
+            tmp_dir,
+            runs: Vec::new(),
+            unique: a_unique,
+        }
+    }
+    pub fn is_empty(&self) -> bool {
+        self.runs.is_empty()
+    }
+    /// spill the chunk of the original line numbers and the lines to
+    /// a temporary file. if `sorted` is false, the chunk is written in
+    /// the input order. the lines with a bad key are passed to `on_bad`.
+    pub fn spill<T, F>(
+        &mut self,
+        sort_buf: &T,
+        chunk: Vec<(usize, KeyLine)>,
+        sorted: bool,
+        on_bad: F,
+    ) -> anyhow::Result<()>
+    where
+        T: SortLinesBuffer + Sync,
+        F: FnMut(usize, BadKeyLine) -> anyhow::Result<()>,
+    {
+        let idx = self.runs.len();
+        let run = if sorted {
+            let lines = sort_chunk(sort_buf, chunk, self.unique, on_bad)?;
+            let lines = lines.iter().map(|a| (a.num(), a.key_line()));
+            RunFile::create(&self.tmp_dir, idx, true, lines)?
+        } else {
+            let lines = chunk.iter().map(|(num, a)| (*num, a));
+            RunFile::create(&self.tmp_dir, idx, false, lines)?
+        };
+        self.runs.push(run);
+        Ok(())
+    }
+    /// merge all runs. if `sorted` is false, the runs are concatenated.
+    /// the lines with a bad key are passed to `on_bad`.
+    pub fn into_merged<'a, T, F>(
+        mut self,
+        sort_buf: &'a T,
+        sorted: bool,
+        mut on_bad: F,
+    ) -> anyhow::Result<KeyLines<'a>>
+    where
+        T: SortLinesBuffer + Sync,
+        F: FnMut(usize, BadKeyLine) -> anyhow::Result<()>,
+    {
+        if !sorted {
+            let mut readers = Vec::with_capacity(self.runs.len());
+            for run in self.runs.drain(..) {
+                readers.push(RunReader::open(run)?);
+            }
+            return Ok(Box::new(readers.into_iter().flatten().map(|r| {
+                let (_num, key_line) = r?;
+                Ok(key_line)
+            })));
+        }
+        // the runs that were spilled before the first match of the regex
+        // are not sorted yet.
+        let mut runs = Vec::with_capacity(self.runs.len());
+        for (idx, run) in self.runs.drain(..).enumerate() {
+            if run.sorted {
+                runs.push(run);
+                continue;
+            }
+            let mut chunk = Vec::new();
+            for r in RunReader::open(run)? {
+                chunk.push(r?);
+            }
+            let lines = sort_chunk(sort_buf, chunk, self.unique, &mut on_bad)?;
+            let lines = lines.iter().map(|a| (a.num(), a.key_line()));
+            runs.push(RunFile::create(&self.tmp_dir, idx, true, lines)?);
+        }
+        let merged = MergedLines::new(sort_buf, runs, self.unique)?;
+        Ok(Box::new(merged))
+    }
+}
+
+/// sort the chunk of the original line numbers and the lines in memory
+/// with the comparator of the `sort_buf`. if `unique` is true, only
+/// the first line of an equal key is kept. the lines with a bad key are
+/// passed to `on_bad`.
+pub(crate) fn sort_chunk<T, F>(
+    sort_buf: &T,
+    chunk: Vec<(usize, KeyLine)>,
+    unique: bool,
+    mut on_bad: F,
+) -> anyhow::Result<Vec<T::Line>>
+where
+    T: SortLinesBuffer + Sync,
+    F: FnMut(usize, BadKeyLine) -> anyhow::Result<()>,
+{
+    use rayon::slice::ParallelSliceMut;
+    let mut lines = Vec::with_capacity(chunk.len());
+    for (num, key_line) in chunk.into_iter() {
+        match sort_buf.make_line(num, key_line.key, key_line.line) {
+            Ok(line) => lines.push(line),
+            Err(bad) => on_bad(num, bad)?,
+        }
+    }
+    lines.par_sort_unstable_by(|a, b| sort_buf.cmp_line(a, b));
+    if unique {
+        lines.dedup_by(|a, b| sort_buf.eq_line(a, b));
+    }
+    Ok(lines)
+}
+
+/// the temporary file of a run. it is removed when dropped.
+#[derive(Debug)]
+struct RunFile {
+    path: PathBuf,
+    sorted: bool,
+}
+
+impl RunFile {
+    /// create the temporary file and write the lines into it.
+    /// a record is `num \t key.st \t key.ed \t line`.
+    fn create<'a, I>(
+        tmp_dir: &std::path::Path,
+        idx: usize,
+        sorted: bool,
+        lines: I,
+    ) -> anyhow::Result<Self>
+    where
+        I: Iterator<Item = (usize, &'a KeyLine)>,
+    {
+        let pid = std::process::id();
+        let mut n = 0;
+        let (path, file) = loop {
+            let path = tmp_dir.join(format!("aki-resort.{pid}.{idx}.{n}.tmp"));
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(file) => break (path, file),
+                Err(err) if err.kind() == std::io::ErrorKind::AlreadyExists => n += 1,
+                Err(err) => {
+                    return Err(err).with_context(|| format!("{}", path.display()));
+                }
+            }
+        };
+        // it is removed by the drop, even if the writing fails.
+        let run = Self { path, sorted };
+        let mut writer = BufWriter::new(file);
+        for (num, key_line) in lines {
+            writeln!(
+                writer,
+                "{}\t{}\t{}\t{}",
+                num, key_line.key.st, key_line.key.ed, key_line.line
+            )
+            .with_context(|| format!("{}", run.path.display()))?;
+        }
+        writer
+            .flush()
+            .with_context(|| format!("{}", run.path.display()))?;
+        Ok(run)
+    }
+}
+
+impl Drop for RunFile {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+#[derive(Debug)]
+struct RunReader {
+    reader: BufReader<File>,
+    // this is dropped after the `reader`, so the file is closed before removed.
+    run: RunFile,
+}
+
+impl RunReader {
+    fn open(run: RunFile) -> anyhow::Result<Self> {
+        let file = File::open(&run.path).with_context(|| format!("{}", run.path.display()))?;
+        Ok(Self {
+            reader: BufReader::new(file),
+            run,
+        })
+    }
+    fn parse_record(&self, s: String) -> anyhow::Result<(usize, KeyLine)> {
+        let mut it = s.splitn(4, '\t');
+        let mut next_num = || -> anyhow::Result<usize> {
+            match it.next().map(|a| a.parse::<usize>()) {
+                Some(Ok(n)) => Ok(n),
+                _ => Err(anyhow!("{}: broken record", self.run.path.display())),
+            }
+        };
+        let num = next_num()?;
+        let st = next_num()?;
+        let ed = next_num()?;
+        let line = match it.next() {
+            Some(line) => line.to_string(),
+            None => return Err(anyhow!("{}: broken record", self.run.path.display())),
+        };
+        Ok((num, KeyLine::new(KeyColumns::new(st, ed), line)))
+    }
+}
+
+impl Iterator for RunReader {
+    type Item = anyhow::Result<(usize, KeyLine)>;
+    fn next(&mut self) -> Option<Self::Item> {
+        // not `lines()`, it strips a '\r' of the end of the line too.
+        let mut s = String::new();
+        match self.reader.read_line(&mut s) {
+            Ok(0) => None,
+            Ok(_) => {
+                if s.ends_with('\n') {
+                    s.pop();
+                }
+                Some(self.parse_record(s))
+            }
+            Err(err) => Some(Err(err.into())),
+        }
+    }
+}
+
+/// the head line of a run in the merge heap.
+pub(crate) struct HeapItem<'a, T: SortLinesBuffer> {
+    sort_buf: &'a T,
+    pub line: T::Line,
+    pub idx: usize,
+}
+
+impl<'a, T: SortLinesBuffer> HeapItem<'a, T> {
+    pub fn new(sort_buf: &'a T, line: T::Line, idx: usize) -> Self {
+        Self {
+            sort_buf,
+            line,
+            idx,
+        }
+    }
+}
+
+impl<T: SortLinesBuffer> PartialOrd for HeapItem<'_, T> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: SortLinesBuffer> Ord for HeapItem<'_, T> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        // `BinaryHeap` is a max-heap, so this is reversed.
+        // the equal lines are in the order of the runs.
+        self.sort_buf
+            .cmp_line(&other.line, &self.line)
+            .then_with(|| other.idx.cmp(&self.idx))
+    }
+}
+
+impl<T: SortLinesBuffer> PartialEq for HeapItem<'_, T> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl<T: SortLinesBuffer> Eq for HeapItem<'_, T> {}
+
+/// k-way merge of the sorted runs.
+struct MergedLines<'a, T: SortLinesBuffer> {
+    sort_buf: &'a T,
+    readers: Vec<RunReader>,
+    heap: BinaryHeap<HeapItem<'a, T>>,
+    unique: bool,
+}
+
+impl<'a, T: SortLinesBuffer> MergedLines<'a, T> {
+    fn new(sort_buf: &'a T, runs: Vec<RunFile>, unique: bool) -> anyhow::Result<Self> {
+        let mut readers = Vec::with_capacity(runs.len());
+        for run in runs {
+            readers.push(RunReader::open(run)?);
+        }
+        let mut merged = Self {
+            sort_buf,
+            readers,
+            heap: BinaryHeap::new(),
+            unique,
+        };
+        for idx in 0..merged.readers.len() {
+            merged.fill(idx)?;
+        }
+        Ok(merged)
+    }
+    /// push the next line of the run `idx` into the heap.
+    fn fill(&mut self, idx: usize) -> anyhow::Result<()> {
+        if let Some(r) = self.readers[idx].next() {
+            let (num, key_line) = r?;
+            let line = self.sort_buf.make_line(num, key_line.key, key_line.line)?;
+            self.heap.push(HeapItem {
+                sort_buf: self.sort_buf,
+                line,
+                idx,
+            });
+        }
+        Ok(())
+    }
+}
+
+impl<T: SortLinesBuffer> Iterator for MergedLines<'_, T> {
+    type Item = anyhow::Result<KeyLine>;
+    fn next(&mut self) -> Option<Self::Item> {
+        let item = self.heap.pop()?;
+        if let Err(err) = self.fill(item.idx) {
+            return Some(Err(err));
+        }
+        // the equal lines of the other runs are next to it in the heap.
+        while self.unique {
+            match self.heap.peek() {
+                Some(top) if self.sort_buf.eq_line(&item.line, &top.line) => {
+                    let idx = self.heap.pop().unwrap().idx;
+                    if let Err(err) = self.fill(idx) {
+                        return Some(Err(err));
+                    }
+                }
+                _ => break,
+            }
+        }
+        Some(Ok(item.line.into_key_line()))
+    }
+}
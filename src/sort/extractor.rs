@@ -0,0 +1,52 @@
+use super::KeyColumns;
+use crate::util::OptKeyDef;
+use regex::Regex;
+
+/// how to extract the key from the line.
+#[derive(Debug)]
+pub(crate) enum KeyExtractor {
+    /// the whole line
+    Line,
+    /// the 1st capture of the regex, or the entire match
+    Regex(Regex),
+    /// the fields and the field separator
+    Field(OptKeyDef, String),
+    /// the fields of the multiple keys and the field separator. the key is
+    /// found if all the keys are found, and the columns are of the first key.
+    Fields(Vec<OptKeyDef>, String),
+}
+
+impl KeyExtractor {
+    /// true if the key is always the whole line.
+    pub fn is_line(&self) -> bool {
+        matches!(self, KeyExtractor::Line)
+    }
+    /// the key columns of the line. `None` if the key is not found.
+    pub fn key_columns(&self, line_ss: &str) -> Option<KeyColumns> {
+        match self {
+            KeyExtractor::Line => Some(KeyColumns::new(0, line_ss.len())),
+            KeyExtractor::Regex(re) => {
+                let caps = re.captures(line_ss)?;
+                if let Some(mat) = caps.get(1) {
+                    Some(KeyColumns::new(mat.start(), mat.end()))
+                } else if let Some(mat) = caps.get(0) {
+                    Some(KeyColumns::new(mat.start(), mat.end()))
+                } else {
+                    unreachable!();
+                }
+            }
+            KeyExtractor::Field(key_def, sep) => {
+                let (st, ed) = key_def.key_range(line_ss, sep)?;
+                Some(KeyColumns::new(st, ed))
+            }
+            KeyExtractor::Fields(key_defs, sep) => {
+                let mut ranges = key_defs.iter().map(|a| a.key_range(line_ss, sep));
+                let (st, ed) = ranges.next()??;
+                for range in ranges {
+                    range?;
+                }
+                Some(KeyColumns::new(st, ed))
+            }
+        }
+    }
+}
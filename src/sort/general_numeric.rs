@@ -0,0 +1,334 @@
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
+use std::cmp::Ordering;
+
+#[derive(Debug)]
+pub struct SortLinesBufferGeneralNumeric {
+    buf_lines: Vec<SortLine>,
+    reverse: bool,
+    fmt: NumFormat,
+}
+impl SortLinesBufferGeneralNumeric {
+    pub(crate) fn new(a_reverse: bool, a_fmt: NumFormat) -> Self {
+        Self {
+            buf_lines: Vec::new(),
+            reverse: a_reverse,
+            fmt: a_fmt,
+        }
+    }
+}
+impl SortLinesBuffer for SortLinesBufferGeneralNumeric {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
+        let sort_line = SortLine::new(self.buf_lines.len(), key, line, &self.fmt)?;
+        self.buf_lines.push(sort_line);
+        Ok(())
+    }
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
+        use rayon::slice::ParallelSliceMut;
+        if !self.reverse {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
+        } else {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
+        }
+        let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
+        for sort_line in self.buf_lines.into_iter() {
+            ret_vec.push(sort_line.key_line);
+        }
+        ret_vec
+    }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        SortLine::new(num, key, line, &self.fmt)
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SortLine {
+    num: usize,
+    key: f64,
+    key_line: KeyLine,
+}
+
+impl SortLine {
+    fn new(
+        a_num: usize,
+        a_key: KeyColumns,
+        a_line: String,
+        a_fmt: &NumFormat,
+    ) -> Result<Self, BadKeyLine> {
+        let key_num = match make_general_numeric(&a_line[a_key.st..a_key.ed], a_fmt) {
+            Ok(key_num) => key_num,
+            Err(err) => return Err(BadKeyLine::new(err, a_key, a_line)),
+        };
+        Ok(Self {
+            num: a_num,
+            key: key_num,
+            key_line: KeyLine::new(a_key, a_line),
+        })
+    }
+}
+
+/// the grouping and the decimal characters of the general numeric.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct NumFormat {
+    grouping: Option<char>,
+    decimal: char,
+}
+impl NumFormat {
+    pub fn new(a_grouping: Option<char>, a_decimal: char) -> Self {
+        Self {
+            grouping: a_grouping,
+            decimal: a_decimal,
+        }
+    }
+}
+impl Default for NumFormat {
+    fn default() -> Self {
+        Self::new(Some(','), '.')
+    }
+}
+
+/// the number at the start of `s`, like `sort -g`. the leading blanks and
+/// the trailing text are ignored.
+///
+/// it is a decimal number with the grouping and the decimal characters of
+/// `fmt` and an optional exponent, a hexadecimal number with `0x`, an octal
+/// number with `0o`, `inf` or `nan`.
+pub(crate) fn make_general_numeric(s: &str, fmt: &NumFormat) -> anyhow::Result<f64> {
+    let s = s.trim_start();
+    let (neg, s) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let chars: Vec<char> = s.chars().collect();
+    let head = s.get(..3).map(|a| a.to_ascii_lowercase());
+    let v = match head.as_deref() {
+        Some("inf") => f64::INFINITY,
+        Some("nan") => f64::NAN,
+        _ => match radix_number(&chars) {
+            Some(v) => v,
+            None => decimal_number(&chars, fmt)?,
+        },
+    };
+    Ok(if neg { -v } else { v })
+}
+
+/// the number with the prefix `0x` or `0o`.
+fn radix_number(chars: &[char]) -> Option<f64> {
+    let radix = match chars {
+        ['0', 'x' | 'X', ..] => 16,
+        ['0', 'o' | 'O', ..] => 8,
+        _ => return None,
+    };
+    let digits = chars[2..].iter().map_while(|c| c.to_digit(radix));
+    let mut v = None;
+    for d in digits {
+        v = Some(v.unwrap_or(0.0) * radix as f64 + d as f64);
+    }
+    v
+}
+
+fn decimal_number(chars: &[char], fmt: &NumFormat) -> anyhow::Result<f64> {
+    let is_digit = |i: usize| chars.get(i).map_or(false, |c| c.is_ascii_digit());
+    let mut buf = String::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_ascii_digit() {
+            buf.push(c);
+        } else if Some(c) == fmt.grouping && i > 0 && is_digit(i - 1) && is_digit(i + 1) {
+            // the grouping is only between the digits of the integer part.
+        } else {
+            break;
+        }
+        i += 1;
+    }
+    if chars.get(i) == Some(&fmt.decimal) {
+        buf.push('.');
+        i += 1;
+        while is_digit(i) {
+            buf.push(chars[i]);
+            i += 1;
+        }
+    }
+    if !buf.bytes().any(|c| c.is_ascii_digit()) {
+        return match chars.first() {
+            Some(c) => Err(anyhow!(
+                "unexpected character '{c}' while parsing general numeric"
+            )),
+            None => Err(anyhow!("empty string while parsing general numeric")),
+        };
+    }
+    if matches!(chars.get(i), Some('e' | 'E')) {
+        let mut k = i + 1;
+        let sign = match chars.get(k) {
+            Some(&c) if c == '-' || c == '+' => {
+                k += 1;
+                Some(c)
+            }
+            _ => None,
+        };
+        if is_digit(k) {
+            buf.push('e');
+            if let Some(c) = sign {
+                buf.push(c);
+            }
+            while is_digit(k) {
+                buf.push(chars[k]);
+                k += 1;
+            }
+        }
+    }
+    Ok(buf.parse::<f64>()?)
+}
+
+/// compare the general numerics. `nan` is the smallest, and `-0` equals `0`.
+#[inline]
+pub(crate) fn cmp_general_numeric(a: f64, b: f64) -> Ordering {
+    match a.partial_cmp(&b) {
+        Some(r) => r,
+        None => b.is_nan().cmp(&a.is_nan()),
+    }
+}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
+impl PartialOrd for SortLine {
+    #[inline]
+    fn partial_cmp(&self, other: &SortLine) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortLine {
+    #[inline]
+    fn cmp(&self, other: &SortLine) -> Ordering {
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
+        cmp_general_numeric(self.key, other.key)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl PartialEq for SortLine {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        cmp_general_numeric(self.key, other.key) == Ordering::Equal
+    }
+}
+
+impl Eq for SortLine {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_make_general_numeric() {
+        let fmt = NumFormat::default();
+        assert_eq!(make_general_numeric("1,234,567", &fmt).unwrap(), 1234567.0);
+        assert_eq!(make_general_numeric("  +42 items", &fmt).unwrap(), 42.0);
+        assert_eq!(make_general_numeric("1.5e+03ms", &fmt).unwrap(), 1500.0);
+        assert_eq!(make_general_numeric("2e", &fmt).unwrap(), 2.0);
+        assert_eq!(make_general_numeric("-.5", &fmt).unwrap(), -0.5);
+        assert_eq!(make_general_numeric("1,,2", &fmt).unwrap(), 1.0);
+        assert_eq!(make_general_numeric("0x1F", &fmt).unwrap(), 31.0);
+        assert_eq!(make_general_numeric("-0o17", &fmt).unwrap(), -15.0);
+        assert_eq!(make_general_numeric("0xZ", &fmt).unwrap(), 0.0);
+        assert_eq!(
+            make_general_numeric("Infinity", &fmt).unwrap(),
+            f64::INFINITY
+        );
+        assert!(make_general_numeric("NaN", &fmt).unwrap().is_nan());
+    }
+    #[test]
+    fn test_make_general_numeric_fmt() {
+        let fmt = NumFormat::new(Some('.'), ',');
+        assert_eq!(make_general_numeric("1.234,5", &fmt).unwrap(), 1234.5);
+        let fmt = NumFormat::new(None, '.');
+        assert_eq!(make_general_numeric("1,234", &fmt).unwrap(), 1.0);
+    }
+    #[test]
+    fn test_make_general_numeric_invalid() {
+        let fmt = NumFormat::default();
+        let e = make_general_numeric("abc", &fmt).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "unexpected character 'a' while parsing general numeric"
+        );
+        let e = make_general_numeric("  ", &fmt).unwrap_err();
+        assert_eq!(e.to_string(), "empty string while parsing general numeric");
+    }
+    #[test]
+    fn test_cmp_general_numeric() {
+        assert_eq!(cmp_general_numeric(-0.0, 0.0), Ordering::Equal);
+        assert_eq!(
+            cmp_general_numeric(f64::NAN, f64::NEG_INFINITY),
+            Ordering::Less
+        );
+        assert_eq!(cmp_general_numeric(1.0, f64::NAN), Ordering::Greater);
+        assert_eq!(cmp_general_numeric(f64::NAN, f64::NAN), Ordering::Equal);
+    }
+}
+
+#[cfg(test)]
+mod debug {
+    use super::*;
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferGeneralNumeric>(), 40);
+        assert_eq!(std::mem::size_of::<SortLine>(), 56);
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferGeneralNumeric>(), 24);
+        assert_eq!(std::mem::size_of::<SortLine>(), 32);
+    }
+}
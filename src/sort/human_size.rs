@@ -0,0 +1,229 @@
+use super::general_numeric::cmp_general_numeric;
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
+use crate::util::size_prefix_exp;
+use std::cmp::Ordering;
+
+#[derive(Debug)]
+pub struct SortLinesBufferHumanSize {
+    buf_lines: Vec<SortLine>,
+    reverse: bool,
+}
+impl SortLinesBufferHumanSize {
+    pub fn new(a_reverse: bool) -> Self {
+        Self {
+            buf_lines: Vec::new(),
+            reverse: a_reverse,
+        }
+    }
+}
+impl SortLinesBuffer for SortLinesBufferHumanSize {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
+        let sort_line = SortLine::new(self.buf_lines.len(), key, line)?;
+        self.buf_lines.push(sort_line);
+        Ok(())
+    }
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
+        use rayon::slice::ParallelSliceMut;
+        if !self.reverse {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
+        } else {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
+        }
+        let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
+        for sort_line in self.buf_lines.into_iter() {
+            ret_vec.push(sort_line.key_line);
+        }
+        ret_vec
+    }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        SortLine::new(num, key, line)
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SortLine {
+    num: usize,
+    key: f64,
+    key_line: KeyLine,
+}
+
+impl SortLine {
+    fn new(a_num: usize, a_key: KeyColumns, a_line: String) -> Result<Self, BadKeyLine> {
+        let key_num = match make_human_size(&a_line[a_key.st..a_key.ed]) {
+            Ok(key_num) => key_num,
+            Err(err) => return Err(BadKeyLine::new(err, a_key, a_line)),
+        };
+        Ok(Self {
+            num: a_num,
+            key: key_num,
+            key_line: KeyLine::new(a_key, a_line),
+        })
+    }
+}
+
+/// the number of bytes of the human readable size, like `du -h`.
+///
+/// the unit is `K`, `M`, `G`, `T`, `P`, `E`, `Z` or `Y`, and the optional `B`.
+/// `KB` is 1000 of SI, but `K` alone and `Ki`, `KiB` are 1024 of IEC,
+/// like the output of `du -h` and `ls -lh`. `k` is the same as `K`.
+pub(crate) fn make_human_size(s: &str) -> anyhow::Result<f64> {
+    let s = s.trim();
+    let idx = s
+        .char_indices()
+        .find(|&(i, c)| !(c.is_ascii_digit() || c == '.' || (i == 0 && (c == '-' || c == '+'))))
+        .map(|(i, _)| i)
+        .unwrap_or(s.len());
+    let (num_s, unit_s) = (&s[..idx], s[idx..].trim_start());
+    if !num_s.bytes().any(|c| c.is_ascii_digit()) {
+        return Err(anyhow!("invalid number '{num_s}' while parsing size"));
+    }
+    let num = num_s
+        .parse::<f64>()
+        .map_err(|_| anyhow!("invalid number '{num_s}' while parsing size"))?;
+    let unit = match parse_unit(unit_s) {
+        Some(unit) => unit,
+        None => return Err(anyhow!("unexpected unit '{unit_s}' while parsing size")),
+    };
+    Ok(num * unit)
+}
+
+/// the multiplier of the unit, `None` if it is not a unit.
+fn parse_unit(s: &str) -> Option<f64> {
+    let (c, rest) = match s.as_bytes() {
+        [] | [b'B'] => return Some(1.0),
+        [b'k', rest @ ..] => (b'K', rest),
+        [c, rest @ ..] => (*c, rest),
+    };
+    let exp = size_prefix_exp(c)? as i32;
+    match rest {
+        b"" | b"i" | b"iB" => Some(1024f64.powi(exp)),
+        b"B" => Some(1000f64.powi(exp)),
+        _ => None,
+    }
+}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
+impl PartialOrd for SortLine {
+    #[inline]
+    fn partial_cmp(&self, other: &SortLine) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortLine {
+    #[inline]
+    fn cmp(&self, other: &SortLine) -> Ordering {
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
+        cmp_general_numeric(self.key, other.key)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl PartialEq for SortLine {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp_key(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SortLine {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_make_human_size() {
+        assert_eq!(make_human_size("0").unwrap(), 0.0);
+        assert_eq!(make_human_size("512").unwrap(), 512.0);
+        assert_eq!(make_human_size("512B").unwrap(), 512.0);
+        assert_eq!(make_human_size("1K").unwrap(), 1024.0);
+        assert_eq!(make_human_size("1k").unwrap(), 1024.0);
+        assert_eq!(make_human_size("1KiB").unwrap(), 1024.0);
+        assert_eq!(make_human_size("1Ki").unwrap(), 1024.0);
+        assert_eq!(make_human_size("1kB").unwrap(), 1000.0);
+        assert_eq!(make_human_size("2.5M").unwrap(), 2.5 * 1024.0 * 1024.0);
+        assert_eq!(make_human_size("3 GiB").unwrap(), 3.0 * 1024f64.powi(3));
+        assert_eq!(make_human_size("3GB").unwrap(), 3.0e9);
+        assert_eq!(make_human_size(" .5T ").unwrap(), 0.5 * 1024f64.powi(4));
+        assert_eq!(make_human_size("1Y").unwrap(), 1024f64.powi(8));
+    }
+    #[test]
+    fn test_make_human_size_invalid() {
+        let e = make_human_size("").unwrap_err();
+        assert_eq!(e.to_string(), "invalid number '' while parsing size");
+        let e = make_human_size("1.2.3K").unwrap_err();
+        assert_eq!(e.to_string(), "invalid number '1.2.3' while parsing size");
+        let e = make_human_size("12X").unwrap_err();
+        assert_eq!(e.to_string(), "unexpected unit 'X' while parsing size");
+        let e = make_human_size("12KBi").unwrap_err();
+        assert_eq!(e.to_string(), "unexpected unit 'KBi' while parsing size");
+        let e = make_human_size("K").unwrap_err();
+        assert_eq!(e.to_string(), "invalid number '' while parsing size");
+    }
+}
+
+#[cfg(test)]
+mod debug {
+    use super::*;
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferHumanSize>(), 32);
+        assert_eq!(std::mem::size_of::<SortLine>(), 56);
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferHumanSize>(), 16);
+        assert_eq!(std::mem::size_of::<SortLine>(), 32);
+    }
+}
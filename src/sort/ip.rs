@@ -0,0 +1,251 @@
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
+use std::cmp::Ordering;
+use std::net::IpAddr;
+
+#[derive(Debug)]
+pub struct SortLinesBufferIp {
+    buf_lines: Vec<SortLine>,
+    reverse: bool,
+    ipv6_first: bool,
+}
+impl SortLinesBufferIp {
+    pub fn new(a_reverse: bool, a_ipv6_first: bool) -> Self {
+        Self {
+            buf_lines: Vec::new(),
+            reverse: a_reverse,
+            ipv6_first: a_ipv6_first,
+        }
+    }
+}
+impl SortLinesBuffer for SortLinesBufferIp {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
+        let sort_line = SortLine::new(self.buf_lines.len(), key, line, self.ipv6_first)?;
+        self.buf_lines.push(sort_line);
+        Ok(())
+    }
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
+        use rayon::slice::ParallelSliceMut;
+        if !self.reverse {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
+        } else {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
+        }
+        let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
+        for sort_line in self.buf_lines.into_iter() {
+            ret_vec.push(sort_line.key_line);
+        }
+        ret_vec
+    }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        SortLine::new(num, key, line, self.ipv6_first)
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SortLine {
+    num: usize,
+    key: IpKey,
+    key_line: KeyLine,
+}
+
+impl SortLine {
+    fn new(
+        a_num: usize,
+        a_key: KeyColumns,
+        a_line: String,
+        a_ipv6_first: bool,
+    ) -> Result<Self, BadKeyLine> {
+        let key_num = match make_ip(&a_line[a_key.st..a_key.ed], a_ipv6_first) {
+            Ok(key_num) => key_num,
+            Err(err) => return Err(BadKeyLine::new(err, a_key, a_line)),
+        };
+        Ok(Self {
+            num: a_num,
+            key: key_num,
+            key_line: KeyLine::new(a_key, a_line),
+        })
+    }
+}
+
+/// the ip address with the prefix length. the fields are in order of
+/// the comparison, and `family` is the order of IPv4 and IPv6.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct IpKey {
+    family: u8,
+    addr: u128,
+    prefix: u8,
+}
+
+/// the ip address of `s`, `addr[/prefix]`. the IPv4-mapped IPv6 address,
+/// like `::ffff:10.0.0.1` or `::ffff:10.0.0.0/104`, is the IPv4 address
+/// if the prefix is 96 or more. without the prefix, the prefix length is
+/// the full length, so `10.0.0.0/8` is before `10.0.0.0`.
+pub(crate) fn make_ip(s: &str, ipv6_first: bool) -> anyhow::Result<IpKey> {
+    let s = s.trim();
+    let (addr_s, prefix_s) = match s.split_once('/') {
+        Some((addr_s, prefix_s)) => (addr_s, Some(prefix_s)),
+        None => (s, None),
+    };
+    let addr: IpAddr = addr_s
+        .parse()
+        .map_err(|_| anyhow!("invalid address '{addr_s}' while parsing ip"))?;
+    let max_prefix = if addr.is_ipv4() { 32 } else { 128 };
+    let prefix = match prefix_s {
+        Some(prefix_s) => match prefix_s.parse::<u8>() {
+            Ok(n) if n <= max_prefix => n,
+            _ => return Err(anyhow!("invalid prefix '{prefix_s}' while parsing ip")),
+        },
+        None => max_prefix,
+    };
+    let (is_v6, addr, prefix) = match addr {
+        IpAddr::V4(v4) => (false, u32::from(v4) as u128, prefix),
+        IpAddr::V6(v6) => match v6.octets() {
+            // the prefix of the IPv4-mapped address is of IPv6, `/104` is `/8`.
+            [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0xff, 0xff, a, b, c, d] if prefix >= 96 => {
+                (false, u32::from_be_bytes([a, b, c, d]) as u128, prefix - 96)
+            }
+            _ => (true, u128::from(v6), prefix),
+        },
+    };
+    Ok(IpKey {
+        family: (is_v6 != ipv6_first) as u8,
+        addr,
+        prefix,
+    })
+}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
+impl PartialOrd for SortLine {
+    #[inline]
+    fn partial_cmp(&self, other: &SortLine) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortLine {
+    #[inline]
+    fn cmp(&self, other: &SortLine) -> Ordering {
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl PartialEq for SortLine {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for SortLine {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn cmp(a: &str, b: &str) -> Ordering {
+        make_ip(a, false).unwrap().cmp(&make_ip(b, false).unwrap())
+    }
+    #[test]
+    fn test_cmp_ip() {
+        assert_eq!(cmp("10.0.0.9", "10.0.0.10"), Ordering::Less);
+        assert_eq!(cmp("9.255.255.255", "10.0.0.0"), Ordering::Less);
+        assert_eq!(cmp("255.255.255.255", "::"), Ordering::Less);
+        assert_eq!(cmp("2001:db8::1", "2001:db8:0:0:0:0:0:1"), Ordering::Equal);
+        assert_eq!(cmp("2001:db8::9", "2001:db8::a"), Ordering::Less);
+        assert_eq!(cmp("::ffff:10.0.0.1", "10.0.0.1"), Ordering::Equal);
+        assert_eq!(cmp("::ffff:10.0.0.0/104", "10.0.0.0/8"), Ordering::Equal);
+        assert_eq!(cmp("::ffff:10.0.0.0/128", "10.0.0.0"), Ordering::Equal);
+        assert_eq!(cmp("::ffff:0.0.0.0/80", "0.0.0.0/0"), Ordering::Greater);
+        assert_eq!(cmp("10.0.0.0/8", "10.0.0.0/24"), Ordering::Less);
+        assert_eq!(cmp("10.0.0.0/24", "10.0.0.0"), Ordering::Less);
+        assert_eq!(cmp("10.0.0.0/32", "10.0.0.0"), Ordering::Equal);
+        assert_eq!(cmp("10.0.0.0/32", "10.0.0.1/8"), Ordering::Less);
+    }
+    #[test]
+    fn test_cmp_ip_ipv6_first() {
+        let a = make_ip("::1", true).unwrap();
+        let b = make_ip("0.0.0.0", true).unwrap();
+        assert_eq!(a.cmp(&b), Ordering::Less);
+    }
+    #[test]
+    fn test_make_ip_invalid() {
+        let e = make_ip("10.0.0.256", false).unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "invalid address '10.0.0.256' while parsing ip"
+        );
+        let e = make_ip("10.0.0.0/33", false).unwrap_err();
+        assert_eq!(e.to_string(), "invalid prefix '33' while parsing ip");
+        let e = make_ip("::ffff:10.0.0.0/129", false).unwrap_err();
+        assert_eq!(e.to_string(), "invalid prefix '129' while parsing ip");
+        let e = make_ip("::/x", false).unwrap_err();
+        assert_eq!(e.to_string(), "invalid prefix 'x' while parsing ip");
+    }
+}
+
+#[cfg(test)]
+mod debug {
+    use super::*;
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferIp>(), 32);
+        assert_eq!(std::mem::size_of::<SortLine>(), 80);
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferIp>(), 16);
+        assert_eq!(std::mem::size_of::<SortLine>(), 64);
+    }
+}
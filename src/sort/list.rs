@@ -0,0 +1,273 @@
+use super::{name_lines, split_names, BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
+use crate::util::OptOnUnknown;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+#[derive(Debug)]
+pub struct SortLinesBufferList {
+    buf_lines: Vec<SortLine>,
+    reverse: bool,
+    list_order: ListOrder,
+}
+impl SortLinesBufferList {
+    pub fn new(a_reverse: bool, a_list_order: ListOrder) -> Self {
+        Self {
+            buf_lines: Vec::new(),
+            reverse: a_reverse,
+            list_order: a_list_order,
+        }
+    }
+}
+impl SortLinesBuffer for SortLinesBufferList {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
+        let sort_line = SortLine::new(self.buf_lines.len(), key, line, &self.list_order)?;
+        self.buf_lines.push(sort_line);
+        Ok(())
+    }
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
+        use rayon::slice::ParallelSliceMut;
+        if !self.reverse {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
+        } else {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
+        }
+        let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
+        for sort_line in self.buf_lines.into_iter() {
+            ret_vec.push(sort_line.key_line);
+        }
+        ret_vec
+    }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        SortLine::new(num, key, line, &self.list_order)
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SortLine {
+    num: usize,
+    key: i64,
+    key_line: KeyLine,
+}
+
+/// the order of the values of the list mode, and the policy
+/// for the values that are not in it. the values are lowercase.
+#[derive(Debug, Default, Clone)]
+pub struct ListOrder {
+    names: HashMap<Box<str>, i64>,
+    len: i64,
+    on_unknown: OptOnUnknown,
+}
+
+impl ListOrder {
+    /// the order of the comma separated values, ex) `low,medium,high`.
+    pub fn from_list(list: &str, on_unknown: OptOnUnknown) -> anyhow::Result<Self> {
+        let mut list_order = Self {
+            on_unknown,
+            ..Default::default()
+        };
+        for name in split_names(list) {
+            list_order.insert(name, list_order.len)?;
+            list_order.len += 1;
+        }
+        list_order.check_len()?;
+        Ok(list_order)
+    }
+    /// the order of the text of an order file. a line has the values of
+    /// a position separated by `,`. the empty lines and the lines that
+    /// start with `#` are skipped.
+    pub fn from_text(text: &str, on_unknown: OptOnUnknown) -> anyhow::Result<Self> {
+        let mut list_order = Self {
+            on_unknown,
+            ..Default::default()
+        };
+        for names in name_lines(text) {
+            for name in names {
+                list_order.insert(name, list_order.len)?;
+            }
+            list_order.len += 1;
+        }
+        list_order.check_len()?;
+        Ok(list_order)
+    }
+    /// an error if `name` is already of the other position.
+    fn insert(&mut self, name: &str, idx: i64) -> anyhow::Result<()> {
+        let old = *self.names.entry(name.to_lowercase().into()).or_insert(idx);
+        if old != idx {
+            return Err(anyhow!("duplicate value '{name}' of the order"));
+        }
+        Ok(())
+    }
+    fn check_len(&self) -> anyhow::Result<()> {
+        if self.len == 0 {
+            return Err(anyhow!("no values of the order"));
+        }
+        Ok(())
+    }
+}
+
+/// the position of `s` in `list_order`, origin 0. the unknown value is
+/// before or after all the values, or an error, by the policy.
+pub(crate) fn make_list(s: &str, list_order: &ListOrder) -> anyhow::Result<i64> {
+    let s = s.trim();
+    match list_order.names.get(s.to_lowercase().as_str()) {
+        Some(&idx) => Ok(idx),
+        None => match list_order.on_unknown {
+            OptOnUnknown::First => Ok(-1),
+            OptOnUnknown::Last => Ok(list_order.len),
+            OptOnUnknown::Error => Err(anyhow!("unknown value '{s}' while parsing list")),
+        },
+    }
+}
+
+impl SortLine {
+    fn new(
+        a_num: usize,
+        a_key: KeyColumns,
+        a_line: String,
+        a_list_order: &ListOrder,
+    ) -> Result<Self, BadKeyLine> {
+        let idx = match make_list(&a_line[a_key.st..a_key.ed], a_list_order) {
+            Ok(idx) => idx,
+            Err(err) => return Err(BadKeyLine::new(err, a_key, a_line)),
+        };
+        Ok(Self {
+            num: a_num,
+            key: idx,
+            key_line: KeyLine::new(a_key, a_line),
+        })
+    }
+}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
+impl PartialOrd for SortLine {
+    #[inline]
+    fn partial_cmp(&self, other: &SortLine) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortLine {
+    #[inline]
+    fn cmp(&self, other: &SortLine) -> Ordering {
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl PartialEq for SortLine {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for SortLine {}
+
+#[cfg(test)]
+mod debug {
+    use super::*;
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferList>(), 96);
+        assert_eq!(std::mem::size_of::<SortLine>(), 56);
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferList>(), 64);
+        assert_eq!(std::mem::size_of::<SortLine>(), 32);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_make_list() {
+        let order =
+            ListOrder::from_list("low, medium,high,,critical", OptOnUnknown::Error).unwrap();
+        assert_eq!(make_list("low", &order).unwrap(), 0);
+        assert_eq!(make_list("HIGH", &order).unwrap(), 2);
+        assert_eq!(make_list("Critical", &order).unwrap(), 3);
+        let e = make_list("urgent", &order).unwrap_err();
+        assert_eq!(e.to_string(), "unknown value 'urgent' while parsing list");
+    }
+    #[test]
+    fn test_make_list_on_unknown() {
+        let order = ListOrder::from_list("low,high", OptOnUnknown::First).unwrap();
+        assert_eq!(make_list("urgent", &order).unwrap(), -1);
+        let order = ListOrder::from_list("low,high", OptOnUnknown::Last).unwrap();
+        assert_eq!(make_list("urgent", &order).unwrap(), 2);
+    }
+    #[test]
+    fn test_list_order_from_text() {
+        let text = "# levels\ntrace\ndebug\n\ninfo, information\nwarn, warning\nerror, err\n";
+        let order = ListOrder::from_text(text, OptOnUnknown::Error).unwrap();
+        assert_eq!(make_list("TRACE", &order).unwrap(), 0);
+        assert_eq!(make_list("information", &order).unwrap(), 2);
+        assert_eq!(make_list("Warning", &order).unwrap(), 3);
+        assert_eq!(make_list("err", &order).unwrap(), 4);
+        let e = ListOrder::from_text("# empty\n", OptOnUnknown::Error).unwrap_err();
+        assert_eq!(e.to_string(), "no values of the order");
+        let e = ListOrder::from_text("low\nhigh, Low\n", OptOnUnknown::Error).unwrap_err();
+        assert_eq!(e.to_string(), "duplicate value 'Low' of the order");
+    }
+    #[test]
+    fn test_list_order_from_list_invalid() {
+        let e = ListOrder::from_list(",", OptOnUnknown::Error).unwrap_err();
+        assert_eq!(e.to_string(), "no values of the order");
+        let e = ListOrder::from_list("high,low,high", OptOnUnknown::Error).unwrap_err();
+        assert_eq!(e.to_string(), "duplicate value 'high' of the order");
+    }
+}
@@ -0,0 +1,174 @@
+use super::string::make_mapped_key;
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
+use crate::util::OptNormalizeForm;
+use std::cmp::Ordering;
+
+/// the string sort with the key mapped by `--ignore-case` and `--normalize`.
+/// without them, `SortLinesBufferString` compares the key as is.
+#[derive(Debug)]
+pub struct SortLinesBufferMappedString {
+    buf_lines: Vec<SortLine>,
+    reverse: bool,
+    ignore_case: bool,
+    normalize: Option<OptNormalizeForm>,
+}
+impl SortLinesBufferMappedString {
+    pub fn new(
+        a_reverse: bool,
+        a_ignore_case: bool,
+        a_normalize: Option<OptNormalizeForm>,
+    ) -> Self {
+        Self {
+            buf_lines: Vec::new(),
+            reverse: a_reverse,
+            ignore_case: a_ignore_case,
+            normalize: a_normalize,
+        }
+    }
+}
+impl SortLinesBuffer for SortLinesBufferMappedString {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
+        let num = self.buf_lines.len();
+        let sort_line = SortLine::new(num, key, line, self.ignore_case, self.normalize);
+        self.buf_lines.push(sort_line);
+        Ok(())
+    }
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
+        use rayon::slice::ParallelSliceMut;
+        if !self.reverse {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
+        } else {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
+        }
+        let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
+        for sort_line in self.buf_lines.into_iter() {
+            ret_vec.push(sort_line.key_line);
+        }
+        ret_vec
+    }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        Ok(SortLine::new(
+            num,
+            key,
+            line,
+            self.ignore_case,
+            self.normalize,
+        ))
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SortLine {
+    num: usize,
+    key: Box<str>,
+    key_line: KeyLine,
+}
+
+impl SortLine {
+    fn new(
+        a_num: usize,
+        a_key: KeyColumns,
+        a_line: String,
+        a_ignore_case: bool,
+        a_normalize: Option<OptNormalizeForm>,
+    ) -> Self {
+        let key = make_mapped_key(&a_line[a_key.st..a_key.ed], a_ignore_case, a_normalize);
+        Self {
+            num: a_num,
+            key,
+            key_line: KeyLine::new(a_key, a_line),
+        }
+    }
+}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
+impl PartialOrd for SortLine {
+    #[inline]
+    fn partial_cmp(&self, other: &SortLine) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortLine {
+    #[inline]
+    fn cmp(&self, other: &SortLine) -> Ordering {
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl PartialEq for SortLine {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for SortLine {}
+
+#[cfg(test)]
+mod debug {
+    use super::*;
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferMappedString>(), 32);
+        assert_eq!(std::mem::size_of::<SortLine>(), 64);
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferMappedString>(), 16);
+        assert_eq!(std::mem::size_of::<SortLine>(), 32);
+    }
+}
@@ -1,14 +1,45 @@
+pub(crate) mod case_fold;
+pub mod collate;
+pub mod datetime;
+pub mod deb_version;
+pub mod duration;
+pub mod external;
+pub mod extractor;
+pub mod general_numeric;
+pub mod human_size;
+pub mod ip;
+pub mod list;
+pub mod mapped_string;
 pub mod month;
+pub mod multi;
+pub mod natural;
 pub mod numeric;
+pub mod pep440;
+pub mod rpm_version;
 pub mod string;
 pub mod time;
 pub mod version;
+pub mod weekday;
 
-pub use month::SortLinesBufferMonth;
+pub use collate::{Collator, SortLinesBufferCollate};
+pub use datetime::SortLinesBufferDatetime;
+pub use deb_version::SortLinesBufferDebVersion;
+pub use duration::SortLinesBufferDuration;
+pub use general_numeric::SortLinesBufferGeneralNumeric;
+pub use human_size::SortLinesBufferHumanSize;
+pub use ip::SortLinesBufferIp;
+pub use list::{ListOrder, SortLinesBufferList};
+pub use mapped_string::SortLinesBufferMappedString;
+pub use month::{MonthNames, SortLinesBufferMonth};
+pub use multi::SortLinesBufferMulti;
+pub use natural::SortLinesBufferNatural;
 pub use numeric::SortLinesBufferNumeric;
+pub use pep440::SortLinesBufferPep440;
+pub use rpm_version::SortLinesBufferRpmVersion;
 pub use string::SortLinesBufferString;
 pub use time::SortLinesBufferTime;
-pub use version::SortLinesBufferVersion;
+pub use version::{SortLinesBufferLenientVersion, SortLinesBufferVersion};
+pub use weekday::SortLinesBufferWeekday;
 
 #[derive(Debug)]
 pub(crate) struct KeyColumns {
@@ -35,9 +66,83 @@ impl KeyLine {
     }
 }
 
+/// the options of the according-to modes.
+#[derive(Debug, Default, Clone)]
+pub(crate) struct SortOptions {
+    pub num_fmt: general_numeric::NumFormat,
+    pub date_fmt: Option<String>,
+    pub ipv6_first: bool,
+    pub first_weekday: crate::util::OptFirstWeekday,
+    pub month_names: month::MonthNames,
+    pub list_order: list::ListOrder,
+    pub ignore_case: bool,
+    pub normalize: Option<crate::util::OptNormalizeForm>,
+    pub collator: collate::Collator,
+}
+
+/// the lines of the text of a names file, like the month names and the
+/// order of the list. a line has the names separated by `,`. the empty
+/// lines and the lines that start with `#` are skipped.
+pub(crate) fn name_lines(text: &str) -> impl Iterator<Item = Vec<&str>> {
+    text.lines()
+        .map(str::trim)
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .map(split_names)
+}
+
+/// the names separated by `,`, without the empty names.
+pub(crate) fn split_names(s: &str) -> Vec<&str> {
+    s.split(',')
+        .map(str::trim)
+        .filter(|s| !s.is_empty())
+        .collect()
+}
+
+/// the line whose key can not be parsed, and the error of it.
+#[derive(Debug)]
+pub(crate) struct BadKeyLine {
+    pub err: anyhow::Error,
+    pub key_line: KeyLine,
+}
+impl BadKeyLine {
+    pub fn new(a_err: anyhow::Error, a_key: KeyColumns, a_line: String) -> Self {
+        let err = a_err.context(format!("({},{}):'{}'", a_key.st, a_key.ed, a_line));
+        Self {
+            err,
+            key_line: KeyLine::new(a_key, a_line),
+        }
+    }
+}
+impl std::fmt::Display for BadKeyLine {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:#}", self.err)
+    }
+}
+impl std::error::Error for BadKeyLine {}
+
 pub(crate) trait SortLinesBuffer {
-    fn push_line(&mut self, key: KeyColumns, line: String) -> anyhow::Result<()>;
-    fn into_sorted_vec(self) -> Vec<KeyLine>;
+    type Line: BufLine + Send;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine>;
+    /// sort the lines. if `unique` is true, only the first line of an equal key is kept.
+    fn into_sorted_vec(self, unique: bool) -> Vec<KeyLine>;
+    /// make the line with the original line number `num`, without buffering it.
+    fn make_line(
+        &self,
+        num: usize,
+        key: KeyColumns,
+        line: String,
+    ) -> Result<Self::Line, BadKeyLine>;
+    /// compare the lines in the order of the `into_sorted_vec()`.
+    fn cmp_line(&self, a: &Self::Line, b: &Self::Line) -> std::cmp::Ordering;
+    /// true if the keys of the lines are equal, regardless of the line number.
+    fn eq_line(&self, a: &Self::Line, b: &Self::Line) -> bool;
+}
+
+/// the line made by `SortLinesBuffer::make_line()`
+pub(crate) trait BufLine {
+    fn num(&self) -> usize;
+    fn key_line(&self) -> &KeyLine;
+    fn into_key_line(self) -> KeyLine;
 }
 
 #[cfg(test)]
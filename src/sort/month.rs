@@ -1,31 +1,39 @@
-use super::{KeyColumns, KeyLine, SortLinesBuffer};
+use super::{name_lines, BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
+use crate::util::OptMonthLocale;
 use std::cmp::Ordering;
+use std::collections::HashMap;
 
 #[derive(Debug)]
 pub struct SortLinesBufferMonth {
     buf_lines: Vec<SortLine>,
     reverse: bool,
+    month_names: MonthNames,
 }
 impl SortLinesBufferMonth {
-    pub fn new(a_reverse: bool) -> Self {
+    pub fn new(a_reverse: bool, a_month_names: MonthNames) -> Self {
         Self {
             buf_lines: Vec::new(),
             reverse: a_reverse,
+            month_names: a_month_names,
         }
     }
 }
 impl SortLinesBuffer for SortLinesBufferMonth {
-    fn push_line(&mut self, key: KeyColumns, line: String) -> anyhow::Result<()> {
-        let sort_line = SortLine::new(self.buf_lines.len(), key, line)?;
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
+        let sort_line = SortLine::new(self.buf_lines.len(), key, line, &self.month_names)?;
         self.buf_lines.push(sort_line);
         Ok(())
     }
-    fn into_sorted_vec(mut self) -> Vec<KeyLine> {
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
         use rayon::slice::ParallelSliceMut;
         if !self.reverse {
             self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
         } else {
-            self.buf_lines.par_sort_unstable_by(|a, b| b.cmp(a));
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
         }
         let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
         for sort_line in self.buf_lines.into_iter() {
@@ -33,56 +41,203 @@ impl SortLinesBuffer for SortLinesBufferMonth {
         }
         ret_vec
     }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        SortLine::new(num, key, line, &self.month_names)
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
 }
 
 #[derive(Debug)]
-struct SortLine {
+pub(crate) struct SortLine {
     num: usize,
     key: i64,
     key_line: KeyLine,
 }
 
-const DICT_FULL: [(&str, &str, &str); 12] = [
-    ("jan", "jan", "january"),
-    ("feb", "feb", "february"),
-    ("mar", "mar", "march"),
-    ("apr", "apr", "april"),
-    ("may", "may", "may"),
-    ("jun", "june", "june"),
-    ("jul", "july", "july"),
-    ("aug", "aug", "august"),
-    ("sep", "sept", "september"),
-    ("oct", "oct", "october"),
-    ("nov", "nov", "november"),
-    ("dec", "dec", "december"),
+const DICT_FULL: [&[&str]; 12] = [
+    &["jan", "january"],
+    &["feb", "february"],
+    &["mar", "march"],
+    &["apr", "april"],
+    &["may"],
+    &["jun", "june"],
+    &["jul", "july"],
+    &["aug", "august"],
+    &["sep", "sept", "september"],
+    &["oct", "october"],
+    &["nov", "november"],
+    &["dec", "december"],
 ];
 
+const DICT_FULL_JA: [&[&str]; 12] = [
+    &["1月", "１月", "01月", "一月", "睦月"],
+    &["2月", "２月", "02月", "二月", "如月"],
+    &["3月", "３月", "03月", "三月", "弥生"],
+    &["4月", "４月", "04月", "四月", "卯月"],
+    &["5月", "５月", "05月", "五月", "皐月"],
+    &["6月", "６月", "06月", "六月", "水無月"],
+    &["7月", "７月", "07月", "七月", "文月"],
+    &["8月", "８月", "08月", "八月", "葉月"],
+    &["9月", "９月", "09月", "九月", "長月"],
+    &["10月", "１０月", "十月", "神無月"],
+    &["11月", "１１月", "十一月", "霜月"],
+    &["12月", "１２月", "十二月", "師走"],
+];
+
+const DICT_FULL_DE: [&[&str]; 12] = [
+    &["jan", "januar", "jän", "jänner"],
+    &["feb", "februar", "feber"],
+    &["mär", "mrz", "märz"],
+    &["apr", "april"],
+    &["mai"],
+    &["jun", "juni"],
+    &["jul", "juli"],
+    &["aug", "august"],
+    &["sep", "sept", "september"],
+    &["okt", "oktober"],
+    &["nov", "november"],
+    &["dez", "dezember"],
+];
+
+const DICT_FULL_FR: [&[&str]; 12] = [
+    &["janv", "janvier"],
+    &["févr", "fevr", "février", "fevrier"],
+    &["mars"],
+    &["avr", "avril"],
+    &["mai"],
+    &["juin"],
+    &["juil", "juillet"],
+    &["août", "aout"],
+    &["sept", "septembre"],
+    &["oct", "octobre"],
+    &["nov", "novembre"],
+    &["déc", "dec", "décembre", "decembre"],
+];
+
+/// the month names and their month indices, origin 0.
+/// the names are lowercase and without the trailing `.`.
+#[derive(Debug, Clone)]
+pub struct MonthNames {
+    names: HashMap<Box<str>, i64>,
+}
+
+impl MonthNames {
+    /// the built-in month names of `locale`.
+    pub fn new(locale: OptMonthLocale) -> Self {
+        let dict = match locale {
+            OptMonthLocale::En => &DICT_FULL,
+            OptMonthLocale::Ja => &DICT_FULL_JA,
+            OptMonthLocale::De => &DICT_FULL_DE,
+            OptMonthLocale::Fr => &DICT_FULL_FR,
+        };
+        let mut month_names = Self {
+            names: HashMap::new(),
+        };
+        for (idx, aliases) in dict.iter().enumerate() {
+            for name in aliases.iter() {
+                let key = normalize(name).into();
+                month_names.names.entry(key).or_insert(idx as i64);
+            }
+        }
+        month_names
+    }
+    /// the month names of the text of a names file. a line has the names of
+    /// a month separated by `,`, from January to December. the empty lines
+    /// and the lines that start with `#` are skipped.
+    pub fn from_text(text: &str) -> anyhow::Result<Self> {
+        let mut month_names = Self {
+            names: HashMap::new(),
+        };
+        let mut idx = 0;
+        for names in name_lines(text) {
+            if idx >= 12 {
+                return Err(anyhow!("too many months, expected 12 lines"));
+            }
+            for name in names {
+                month_names.insert(name, idx)?;
+            }
+            idx += 1;
+        }
+        if idx < 12 {
+            return Err(anyhow!("too few months, expected 12 lines: {idx}"));
+        }
+        Ok(month_names)
+    }
+    /// an error if `name` is already of the other month.
+    fn insert(&mut self, name: &str, idx: i64) -> anyhow::Result<()> {
+        let old = *self.names.entry(normalize(name).into()).or_insert(idx);
+        if old != idx {
+            return Err(anyhow!("duplicate month name '{name}'"));
+        }
+        Ok(())
+    }
+}
+
+impl Default for MonthNames {
+    fn default() -> Self {
+        Self::new(OptMonthLocale::En)
+    }
+}
+
+/// the name `s` to look up, trimmed, without the trailing `.` and lowercased.
+#[inline]
+pub(crate) fn normalize(s: &str) -> String {
+    s.trim().trim_end_matches('.').to_lowercase()
+}
+
 impl SortLine {
-    fn new(a_num: usize, a_key: KeyColumns, a_line: String) -> anyhow::Result<Self> {
-        let key = a_line[a_key.st..a_key.ed].to_ascii_lowercase();
-        let idx = match DICT_FULL
-            .iter()
-            .position(|item| item.0 == key || item.1 == key || item.2 == key)
-        {
+    fn new(
+        a_num: usize,
+        a_key: KeyColumns,
+        a_line: String,
+        a_month_names: &MonthNames,
+    ) -> Result<Self, BadKeyLine> {
+        let idx = match make_month(&a_line[a_key.st..a_key.ed], a_month_names) {
             Some(idx) => idx,
             None => {
-                return Err(anyhow!(
-                    "({},{}):'{}': {}",
-                    a_key.st,
-                    a_key.ed,
-                    a_line,
-                    "invalid month strings"
-                ));
+                let err = anyhow!("invalid month strings");
+                return Err(BadKeyLine::new(err, a_key, a_line));
             }
         };
         Ok(Self {
             num: a_num,
-            key: idx as i64,
+            key: idx,
             key_line: KeyLine::new(a_key, a_line),
         })
     }
 }
 
+/// the month index of `s`, origin 0. `None` if `s` is not a month name.
+pub(crate) fn make_month(s: &str, month_names: &MonthNames) -> Option<i64> {
+    month_names.names.get(normalize(s).as_str()).copied()
+}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
 impl PartialOrd for SortLine {
     #[inline]
     fn partial_cmp(&self, other: &SortLine) -> Option<Ordering> {
@@ -93,7 +248,24 @@ impl PartialOrd for SortLine {
 impl Ord for SortLine {
     #[inline]
     fn cmp(&self, other: &SortLine) -> Ordering {
-        let r = self.key.cmp(&other.key);
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
         match r {
             Ordering::Equal => self.num.cmp(&other.num),
             _ => r,
@@ -116,13 +288,64 @@ mod debug {
     #[cfg(target_pointer_width = "64")]
     #[test]
     fn size_of() {
-        assert_eq!(std::mem::size_of::<SortLinesBufferMonth>(), 32);
+        assert_eq!(std::mem::size_of::<SortLinesBufferMonth>(), 80);
         assert_eq!(std::mem::size_of::<SortLine>(), 56);
     }
     #[cfg(target_pointer_width = "32")]
     #[test]
     fn size_of() {
-        assert_eq!(std::mem::size_of::<SortLinesBufferMonth>(), 16);
+        assert_eq!(std::mem::size_of::<SortLinesBufferMonth>(), 48);
         assert_eq!(std::mem::size_of::<SortLine>(), 32);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_make_month() {
+        let names = MonthNames::default();
+        assert_eq!(make_month("Jan", &names), Some(0));
+        assert_eq!(make_month("SEPT.", &names), Some(8));
+        assert_eq!(make_month("december", &names), Some(11));
+        assert_eq!(make_month("März", &names), None);
+    }
+    #[test]
+    fn test_make_month_locale() {
+        let names = MonthNames::new(OptMonthLocale::Ja);
+        assert_eq!(make_month("1月", &names), Some(0));
+        assert_eq!(make_month("１２月", &names), Some(11));
+        let names = MonthNames::new(OptMonthLocale::De);
+        assert_eq!(make_month("März", &names), Some(2));
+        assert_eq!(make_month("MÄRZ", &names), Some(2));
+        assert_eq!(make_month("Okt", &names), Some(9));
+        let names = MonthNames::new(OptMonthLocale::Fr);
+        assert_eq!(make_month("févr.", &names), Some(1));
+        assert_eq!(make_month("Août", &names), Some(7));
+        assert_eq!(make_month("Jan", &names), None);
+    }
+    #[test]
+    fn test_month_names_from_text() {
+        let text = "# months\n\
+            Jan, Januar, 1月\n\
+            Feb\nMar\nApr\nMay\nJun\nJul\nAug\nSep\nOct\nNov\n\
+            \n\
+            Dec, Dezember\n";
+        let names = MonthNames::from_text(text).unwrap();
+        assert_eq!(make_month("januar", &names), Some(0));
+        assert_eq!(make_month("1月", &names), Some(0));
+        assert_eq!(make_month("Dezember", &names), Some(11));
+        assert_eq!(make_month("Sept", &names), None);
+    }
+    #[test]
+    fn test_month_names_from_text_invalid() {
+        let e = MonthNames::from_text("Jan\nFeb\n").unwrap_err();
+        assert_eq!(e.to_string(), "too few months, expected 12 lines: 2");
+        let text: String = (1..=13).map(|i| format!("m{i}\n")).collect();
+        let e = MonthNames::from_text(&text).unwrap_err();
+        assert_eq!(e.to_string(), "too many months, expected 12 lines");
+        let text = "Jan\nFeb, jan.\nMar\nApr\nMay\nJun\nJul\nAug\nSep\nOct\nNov\nDec\n";
+        let e = MonthNames::from_text(text).unwrap_err();
+        assert_eq!(e.to_string(), "duplicate month name 'jan.'");
+    }
+}
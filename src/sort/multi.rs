@@ -0,0 +1,265 @@
+use super::extractor::KeyExtractor;
+use super::{
+    collate, datetime, deb_version, duration, general_numeric, human_size, ip, list, month,
+    natural, numeric, pep440, rpm_version, string, time, version, weekday,
+};
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer, SortOptions};
+use crate::util::OptAccordingToWord;
+use semver::Version;
+use std::cmp::Ordering;
+
+/// a sort key: how to extract it, how to compare it, and its direction.
+#[derive(Debug)]
+pub(crate) struct SortKey {
+    ext: KeyExtractor,
+    according_to: OptAccordingToWord,
+    reverse: bool,
+}
+impl SortKey {
+    pub fn new(a_ext: KeyExtractor, a_according_to: OptAccordingToWord, a_reverse: bool) -> Self {
+        Self {
+            ext: a_ext,
+            according_to: a_according_to,
+            reverse: a_reverse,
+        }
+    }
+}
+
+/// sort by the list of keys. the keys are compared lexicographically,
+/// and the original line number is the final tie-break.
+#[derive(Debug)]
+pub struct SortLinesBufferMulti {
+    keys: Vec<SortKey>,
+    buf_lines: Vec<SortLine>,
+    reverse: bool,
+    opts: SortOptions,
+}
+impl SortLinesBufferMulti {
+    pub(crate) fn new(a_keys: Vec<SortKey>, a_reverse: bool, a_opts: SortOptions) -> Self {
+        Self {
+            keys: a_keys,
+            buf_lines: Vec::new(),
+            reverse: a_reverse,
+            opts: a_opts,
+        }
+    }
+}
+impl SortLinesBuffer for SortLinesBufferMulti {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
+        let sort_line = self.make_line(self.buf_lines.len(), key, line)?;
+        self.buf_lines.push(sort_line);
+        Ok(())
+    }
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
+        use rayon::slice::ParallelSliceMut;
+        let mut buf_lines = std::mem::take(&mut self.buf_lines);
+        buf_lines.par_sort_unstable_by(|a, b| self.cmp_line(a, b));
+        if unique {
+            buf_lines.dedup_by(|a, b| self.eq_line(a, b));
+        }
+        let mut ret_vec = Vec::with_capacity(buf_lines.len());
+        for sort_line in buf_lines.into_iter() {
+            ret_vec.push(sort_line.key_line);
+        }
+        ret_vec
+    }
+    /// the `key` is used only for the color, all keys are extracted here.
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        let mut values = Vec::with_capacity(self.keys.len());
+        for sort_key in self.keys.iter() {
+            // a missing field is the whole line, like the single key.
+            let cols = match sort_key.ext.key_columns(&line) {
+                Some(cols) => cols,
+                None => KeyColumns::new(0, line.len()),
+            };
+            match KeyValue::new(sort_key.according_to, &self.opts, &cols, &line) {
+                Ok(value) => values.push(value),
+                Err(err) => return Err(BadKeyLine::new(err, cols, line)),
+            }
+        }
+        Ok(SortLine {
+            num,
+            values,
+            key_line: KeyLine::new(key, line),
+        })
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        for (i, sort_key) in self.keys.iter().enumerate() {
+            let r = a.values[i].cmp_value(&a.key_line.line, &b.values[i], &b.key_line.line);
+            // the reverse of the key and the global reverse cancel each other.
+            let r = if sort_key.reverse == self.reverse {
+                r
+            } else {
+                r.reverse()
+            };
+            if r != Ordering::Equal {
+                return r;
+            }
+        }
+        a.num.cmp(&b.num)
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        (0..self.keys.len()).all(|i| {
+            let r = a.values[i].cmp_value(&a.key_line.line, &b.values[i], &b.key_line.line);
+            r == Ordering::Equal
+        })
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SortLine {
+    num: usize,
+    values: Vec<KeyValue>,
+    key_line: KeyLine,
+}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
+/// the parsed value of a key.
+#[derive(Debug)]
+enum KeyValue {
+    String(KeyColumns),
+    MappedString(Box<str>),
+    Collate(Box<[u32]>),
+    Numeric(numeric::NumericKey),
+    Datetime(chrono::NaiveDateTime),
+    DebVersion(deb_version::DebVersion),
+    Duration(std::time::Duration),
+    GeneralNumeric(f64),
+    HumanSize(f64),
+    Ip(ip::IpKey),
+    LenientVersion(KeyColumns),
+    List(i64),
+    Month(i64),
+    Natural(KeyColumns),
+    Pep440(pep440::Pep440Version),
+    RpmVersion(rpm_version::RpmVersion),
+    Time(i128),
+    Version(Version),
+    Weekday(i64),
+}
+
+impl KeyValue {
+    fn new(
+        according_to: OptAccordingToWord,
+        opts: &SortOptions,
+        cols: &KeyColumns,
+        line: &str,
+    ) -> anyhow::Result<Self> {
+        let s = &line[cols.st..cols.ed];
+        let v = match according_to {
+            OptAccordingToWord::String => {
+                match string::make_string_key(s, opts.ignore_case, opts.normalize) {
+                    Some(key) => KeyValue::MappedString(key),
+                    None => KeyValue::String(KeyColumns::new(cols.st, cols.ed)),
+                }
+            }
+            OptAccordingToWord::Collate => {
+                KeyValue::Collate(collate::make_collate(s, &opts.collator, opts.normalize))
+            }
+            OptAccordingToWord::Numeric => KeyValue::Numeric(numeric::make_numeric(s)?),
+            OptAccordingToWord::Datetime => {
+                KeyValue::Datetime(datetime::make_datetime(s, opts.date_fmt.as_deref())?)
+            }
+            OptAccordingToWord::DebVersion => {
+                KeyValue::DebVersion(deb_version::make_deb_version(s)?)
+            }
+            OptAccordingToWord::Duration => KeyValue::Duration(duration::make_duration(s)?),
+            OptAccordingToWord::GeneralNumeric => {
+                KeyValue::GeneralNumeric(general_numeric::make_general_numeric(s, &opts.num_fmt)?)
+            }
+            OptAccordingToWord::HumanSize => KeyValue::HumanSize(human_size::make_human_size(s)?),
+            OptAccordingToWord::Ip => KeyValue::Ip(ip::make_ip(s, opts.ipv6_first)?),
+            OptAccordingToWord::LenientVersion => {
+                KeyValue::LenientVersion(KeyColumns::new(cols.st, cols.ed))
+            }
+            OptAccordingToWord::List => KeyValue::List(list::make_list(s, &opts.list_order)?),
+            OptAccordingToWord::Month => match month::make_month(s, &opts.month_names) {
+                Some(idx) => KeyValue::Month(idx),
+                None => return Err(anyhow!("invalid month strings")),
+            },
+            OptAccordingToWord::Natural => KeyValue::Natural(KeyColumns::new(cols.st, cols.ed)),
+            OptAccordingToWord::Pep440 => KeyValue::Pep440(pep440::make_pep440(s)?),
+            OptAccordingToWord::RpmVersion => {
+                KeyValue::RpmVersion(rpm_version::make_rpm_version(s)?)
+            }
+            OptAccordingToWord::Time => KeyValue::Time(time::make_time(s)?),
+            OptAccordingToWord::Version => KeyValue::Version(version::make_version(s)?),
+            OptAccordingToWord::Weekday => match weekday::make_weekday(s, opts.first_weekday) {
+                Some(idx) => KeyValue::Weekday(idx),
+                None => return Err(anyhow!("invalid weekday strings")),
+            },
+        };
+        Ok(v)
+    }
+    /// compare the values of the same key. `a_line` and `b_line` are
+    /// the lines of the values.
+    #[inline]
+    fn cmp_value(&self, a_line: &str, other: &KeyValue, b_line: &str) -> Ordering {
+        match (self, other) {
+            (KeyValue::String(a), KeyValue::String(b)) => {
+                a_line[a.st..a.ed].cmp(&b_line[b.st..b.ed])
+            }
+            (KeyValue::MappedString(a), KeyValue::MappedString(b)) => a.cmp(b),
+            (KeyValue::Collate(a), KeyValue::Collate(b)) => a.cmp(b),
+            (KeyValue::Numeric(a), KeyValue::Numeric(b)) => a.cmp(b),
+            (KeyValue::Datetime(a), KeyValue::Datetime(b)) => a.cmp(b),
+            (KeyValue::DebVersion(a), KeyValue::DebVersion(b)) => a.cmp(b),
+            (KeyValue::Duration(a), KeyValue::Duration(b)) => a.cmp(b),
+            (KeyValue::GeneralNumeric(a), KeyValue::GeneralNumeric(b)) => {
+                general_numeric::cmp_general_numeric(*a, *b)
+            }
+            (KeyValue::HumanSize(a), KeyValue::HumanSize(b)) => {
+                general_numeric::cmp_general_numeric(*a, *b)
+            }
+            (KeyValue::Ip(a), KeyValue::Ip(b)) => a.cmp(b),
+            (KeyValue::LenientVersion(a), KeyValue::LenientVersion(b)) => {
+                version::cmp_lenient_version(&a_line[a.st..a.ed], &b_line[b.st..b.ed])
+            }
+            (KeyValue::List(a), KeyValue::List(b)) => a.cmp(b),
+            (KeyValue::Month(a), KeyValue::Month(b)) => a.cmp(b),
+            (KeyValue::Natural(a), KeyValue::Natural(b)) => {
+                natural::cmp_natural(&a_line[a.st..a.ed], &b_line[b.st..b.ed])
+            }
+            (KeyValue::Pep440(a), KeyValue::Pep440(b)) => a.cmp(b),
+            (KeyValue::RpmVersion(a), KeyValue::RpmVersion(b)) => a.cmp(b),
+            (KeyValue::Time(a), KeyValue::Time(b)) => a.cmp(b),
+            (KeyValue::Version(a), KeyValue::Version(b)) => a.cmp(b),
+            (KeyValue::Weekday(a), KeyValue::Weekday(b)) => a.cmp(b),
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod debug {
+    use super::*;
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferMulti>(), 256);
+        assert_eq!(std::mem::size_of::<SortLine>(), 72);
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferMulti>(), 168);
+        assert_eq!(std::mem::size_of::<SortLine>(), 36);
+    }
+}
@@ -0,0 +1,242 @@
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
+use std::cmp::Ordering;
+
+#[derive(Debug)]
+pub struct SortLinesBufferNatural {
+    buf_lines: Vec<SortLine>,
+    reverse: bool,
+}
+impl SortLinesBufferNatural {
+    pub fn new(a_reverse: bool) -> Self {
+        Self {
+            buf_lines: Vec::new(),
+            reverse: a_reverse,
+        }
+    }
+}
+impl SortLinesBuffer for SortLinesBufferNatural {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
+        let sort_line = SortLine::new(self.buf_lines.len(), key, line);
+        self.buf_lines.push(sort_line);
+        Ok(())
+    }
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
+        use rayon::slice::ParallelSliceMut;
+        if !self.reverse {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
+        } else {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
+        }
+        let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
+        for sort_line in self.buf_lines.into_iter() {
+            ret_vec.push(sort_line.key_line);
+        }
+        ret_vec
+    }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        Ok(SortLine::new(num, key, line))
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SortLine {
+    num: usize,
+    key_line: KeyLine,
+}
+
+impl SortLine {
+    fn new(a_num: usize, a_key: KeyColumns, a_line: String) -> Self {
+        Self {
+            num: a_num,
+            key_line: KeyLine::new(a_key, a_line),
+        }
+    }
+    #[inline]
+    fn key_str(&self) -> &str {
+        &self.key_line.line[self.key_line.key.st..self.key_line.key.ed]
+    }
+}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
+/// compare the strings in the natural order. the runs of the digits are
+/// compared as the numbers of any length, and the other runs are compared
+/// as the strings. if they are equal, the one with the fewer leading zeros
+/// is the first, so the order is equal only if the strings are equal.
+pub(crate) fn cmp_natural(a: &str, b: &str) -> Ordering {
+    let mut a_it = a.as_bytes();
+    let mut b_it = b.as_bytes();
+    let mut zeros = Ordering::Equal;
+    while let (Some((a_run, a_is_digit)), Some((b_run, b_is_digit))) =
+        (next_run(a_it), next_run(b_it))
+    {
+        let r = match (a_is_digit, b_is_digit) {
+            (true, true) => {
+                let a_num = trim_zeros(a_run);
+                let b_num = trim_zeros(b_run);
+                if zeros == Ordering::Equal {
+                    zeros = (a_run.len() - a_num.len()).cmp(&(b_run.len() - b_num.len()));
+                }
+                a_num.len().cmp(&b_num.len()).then_with(|| a_num.cmp(b_num))
+            }
+            (false, false) => {
+                // "file" and "file.txt" are compared by the next bytes,
+                // ex) the end of "file" or the digit of "file1".
+                let n = a_run.len().min(b_run.len());
+                match a_run[..n].cmp(&b_run[..n]) {
+                    Ordering::Equal if a_run.len() != b_run.len() => a_it.get(n).cmp(&b_it.get(n)),
+                    r => r,
+                }
+            }
+            _ => a_run.cmp(b_run),
+        };
+        if r != Ordering::Equal {
+            return r;
+        }
+        a_it = &a_it[a_run.len()..];
+        b_it = &b_it[b_run.len()..];
+    }
+    a_it.len().cmp(&b_it.len()).then(zeros)
+}
+
+/// the next run of the digits or the non-digits, and whether it is digits.
+#[inline]
+fn next_run(s: &[u8]) -> Option<(&[u8], bool)> {
+    let is_digit = s.first()?.is_ascii_digit();
+    let len = s
+        .iter()
+        .position(|c| c.is_ascii_digit() != is_digit)
+        .unwrap_or(s.len());
+    Some((&s[..len], is_digit))
+}
+
+#[inline]
+fn trim_zeros(s: &[u8]) -> &[u8] {
+    let len = s.iter().position(|&c| c != b'0').unwrap_or(s.len());
+    &s[len..]
+}
+
+impl PartialOrd for SortLine {
+    #[inline]
+    fn partial_cmp(&self, other: &SortLine) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortLine {
+    #[inline]
+    fn cmp(&self, other: &SortLine) -> Ordering {
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
+        let one = self.key_str();
+        let two = other.key_str();
+        cmp_natural(one, two)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl PartialEq for SortLine {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        let one = self.key_str();
+        let two = other.key_str();
+        one == two
+    }
+}
+
+impl Eq for SortLine {}
+
+#[cfg(test)]
+mod debug {
+    use super::*;
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<String>(), 24);
+        assert_eq!(std::mem::size_of::<SortLinesBufferNatural>(), 32);
+        assert_eq!(std::mem::size_of::<SortLine>(), 48);
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<String>(), 12);
+        assert_eq!(std::mem::size_of::<SortLinesBufferNatural>(), 16);
+        assert_eq!(std::mem::size_of::<SortLine>(), 24);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_cmp_natural() {
+        assert_eq!(cmp_natural("file2", "file10"), Ordering::Less);
+        assert_eq!(cmp_natural("file10", "file10"), Ordering::Equal);
+        assert_eq!(cmp_natural("file", "file1"), Ordering::Less);
+        assert_eq!(cmp_natural("file.txt", "file1.txt"), Ordering::Less);
+        assert_eq!(cmp_natural("file1.txt", "file"), Ordering::Greater);
+        assert_eq!(cmp_natural("a2b", "a2c"), Ordering::Less);
+        assert_eq!(cmp_natural("a10b", "a9c"), Ordering::Greater);
+    }
+    #[test]
+    fn test_cmp_natural_zeros() {
+        assert_eq!(cmp_natural("007", "7"), Ordering::Greater);
+        assert_eq!(cmp_natural("007", "8"), Ordering::Less);
+        assert_eq!(cmp_natural("x01y2", "x1y02"), Ordering::Greater);
+        assert_eq!(cmp_natural("0", "00"), Ordering::Less);
+    }
+    #[test]
+    fn test_cmp_natural_long() {
+        let a = "v123456789012345678901234567890";
+        let b = "v123456789012345678901234567891";
+        assert_eq!(cmp_natural(a, b), Ordering::Less);
+        assert_eq!(cmp_natural("v99999999999999999999999", b), Ordering::Less);
+    }
+}
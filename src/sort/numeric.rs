@@ -1,5 +1,4 @@
-use super::{KeyColumns, KeyLine, SortLinesBuffer};
-use anyhow::Context;
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
 use std::cmp::Ordering;
 
 #[derive(Debug)]
@@ -16,17 +15,21 @@ impl SortLinesBufferNumeric {
     }
 }
 impl SortLinesBuffer for SortLinesBufferNumeric {
-    fn push_line(&mut self, key: KeyColumns, line: String) -> anyhow::Result<()> {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
         let sort_line = SortLine::new(self.buf_lines.len(), key, line)?;
         self.buf_lines.push(sort_line);
         Ok(())
     }
-    fn into_sorted_vec(mut self) -> Vec<KeyLine> {
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
         use rayon::slice::ParallelSliceMut;
         if !self.reverse {
             self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
         } else {
-            self.buf_lines.par_sort_unstable_by(|a, b| b.cmp(a));
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
         }
         let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
         for sort_line in self.buf_lines.into_iter() {
@@ -34,20 +37,36 @@ impl SortLinesBuffer for SortLinesBufferNumeric {
         }
         ret_vec
     }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        SortLine::new(num, key, line)
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
 }
 
 #[derive(Debug)]
-struct SortLine {
+pub(crate) struct SortLine {
     num: usize,
-    key: f64,
+    key: NumericKey,
     key_line: KeyLine,
 }
 
 impl SortLine {
-    fn new(a_num: usize, a_key: KeyColumns, a_line: String) -> anyhow::Result<Self> {
-        let key_num = a_line[a_key.st..a_key.ed]
-            .parse::<f64>()
-            .with_context(|| format!("({},{}):'{}'", a_key.st, a_key.ed, a_line))?;
+    fn new(a_num: usize, a_key: KeyColumns, a_line: String) -> Result<Self, BadKeyLine> {
+        let key_num = match make_numeric(&a_line[a_key.st..a_key.ed]) {
+            Ok(key_num) => key_num,
+            Err(err) => return Err(BadKeyLine::new(err, a_key, a_line)),
+        };
         Ok(Self {
             num: a_num,
             key: key_num,
@@ -56,6 +75,131 @@ impl SortLine {
     }
 }
 
+/// the numeric key. the decimal number, like `1.5`, `1e5`, is compared
+/// exactly in any length, and the others, `inf` and `nan`, are compared as `f64`.
+#[derive(Debug)]
+pub(crate) enum NumericKey {
+    Decimal(Decimal),
+    Float(f64),
+}
+
+/// the decimal number `0.digits * 10^exp`, without the leading and trailing
+/// zeros of `digits`. zero has the empty `digits`, and is not negative.
+#[derive(Debug)]
+pub(crate) struct Decimal {
+    neg: bool,
+    exp: i64,
+    digits: Box<str>,
+}
+
+impl Decimal {
+    fn cmp_abs(&self, other: &Decimal) -> Ordering {
+        match (self.digits.is_empty(), other.digits.is_empty()) {
+            (true, true) => Ordering::Equal,
+            (true, false) => Ordering::Less,
+            (false, true) => Ordering::Greater,
+            (false, false) => self
+                .exp
+                .cmp(&other.exp)
+                .then_with(|| self.digits.cmp(&other.digits)),
+        }
+    }
+}
+
+pub(crate) fn make_numeric(s: &str) -> anyhow::Result<NumericKey> {
+    let approx = s.parse::<f64>()?;
+    let (neg, digits) = match s.as_bytes().first() {
+        Some(b'-') => (true, &s[1..]),
+        Some(b'+') => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (mantissa, exp) = match digits.find(['e', 'E']) {
+        Some(idx) => (&digits[..idx], &digits[(idx + 1)..]),
+        None => (digits, "0"),
+    };
+    let (int, frac) = match mantissa.find('.') {
+        Some(idx) => (&mantissa[..idx], &mantissa[(idx + 1)..]),
+        None => (mantissa, ""),
+    };
+    let is_digits = |a: &str| a.bytes().all(|c| c.is_ascii_digit());
+    if !is_digits(int) || !is_digits(frac) {
+        return Ok(NumericKey::Float(approx));
+    }
+    // the exponent that overflows is saturated, it is far out of `f64`.
+    let exp = exp.parse::<i64>().unwrap_or(if exp.starts_with('-') {
+        i64::MIN / 4
+    } else {
+        i64::MAX / 4
+    });
+    let all = format!("{int}{frac}");
+    let digits = all.trim_start_matches('0');
+    let exp = exp + int.len() as i64 - (all.len() - digits.len()) as i64;
+    let digits = digits.trim_end_matches('0');
+    Ok(NumericKey::Decimal(Decimal {
+        neg: neg && !digits.is_empty(),
+        exp: if digits.is_empty() { 0 } else { exp },
+        digits: digits.into(),
+    }))
+}
+
+impl Ord for NumericKey {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (NumericKey::Decimal(a), NumericKey::Decimal(b)) => match (a.neg, b.neg) {
+                (false, false) => a.cmp_abs(b),
+                (true, true) => b.cmp_abs(a),
+                _ => b.neg.cmp(&a.neg),
+            },
+            (NumericKey::Float(a), NumericKey::Float(b)) => a.total_cmp(b),
+            // `inf` and `nan` are greater than any decimal, and the negatives
+            // of them are less, like `f64::total_cmp()`.
+            (NumericKey::Decimal(_), NumericKey::Float(b)) => {
+                if b.is_sign_negative() {
+                    Ordering::Greater
+                } else {
+                    Ordering::Less
+                }
+            }
+            (NumericKey::Float(a), NumericKey::Decimal(_)) => {
+                if a.is_sign_negative() {
+                    Ordering::Less
+                } else {
+                    Ordering::Greater
+                }
+            }
+        }
+    }
+}
+
+impl PartialOrd for NumericKey {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for NumericKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for NumericKey {}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
 impl PartialOrd for SortLine {
     #[inline]
     fn partial_cmp(&self, other: &SortLine) -> Option<Ordering> {
@@ -66,7 +210,24 @@ impl PartialOrd for SortLine {
 impl Ord for SortLine {
     #[inline]
     fn cmp(&self, other: &SortLine) -> Ordering {
-        let r = self.key.total_cmp(&other.key);
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
         match r {
             Ordering::Equal => self.num.cmp(&other.num),
             _ => r,
@@ -77,12 +238,61 @@ impl Ord for SortLine {
 impl PartialEq for SortLine {
     #[inline]
     fn eq(&self, other: &Self) -> bool {
-        self.key.total_cmp(&other.key) == Ordering::Equal
+        self.key == other.key
     }
 }
 
 impl Eq for SortLine {}
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn cmp(a: &str, b: &str) -> Ordering {
+        make_numeric(a).unwrap().cmp(&make_numeric(b).unwrap())
+    }
+    #[test]
+    fn test_cmp_decimal() {
+        assert_eq!(
+            cmp("9007199254740993", "9007199254740992"),
+            Ordering::Greater
+        );
+        assert_eq!(
+            cmp(
+                "123456789012345678901234567890",
+                "123456789012345678901234567891"
+            ),
+            Ordering::Less
+        );
+        assert_eq!(cmp("0.10", ".1"), Ordering::Equal);
+        assert_eq!(cmp("007", "7.0"), Ordering::Equal);
+        assert_eq!(cmp("-0", "+0.00"), Ordering::Equal);
+        assert_eq!(cmp("-2", "-10"), Ordering::Greater);
+        assert_eq!(cmp("-0.5", "0.1"), Ordering::Less);
+        assert_eq!(cmp("1.05", "1.5"), Ordering::Less);
+    }
+    #[test]
+    fn test_cmp_float() {
+        assert_eq!(cmp("1e3", "999"), Ordering::Greater);
+        assert_eq!(cmp("1e3", "1000"), Ordering::Equal);
+        assert_eq!(cmp("1.5E-2", "0.015"), Ordering::Equal);
+        assert_eq!(cmp("-1e3", "-999.9"), Ordering::Less);
+        assert_eq!(
+            cmp("9007199254740993e0", "9007199254740992"),
+            Ordering::Greater
+        );
+        assert_eq!(cmp("1e400", "inf"), Ordering::Less);
+        assert_eq!(cmp("1e-400", "0"), Ordering::Greater);
+        assert_eq!(cmp("0e5", "-0.0"), Ordering::Equal);
+        assert_eq!(cmp("-inf", "-1"), Ordering::Less);
+        assert_eq!(cmp("inf", "1e308"), Ordering::Greater);
+    }
+    #[test]
+    fn test_make_numeric_invalid() {
+        let e = make_numeric("12a").unwrap_err();
+        assert_eq!(e.to_string(), "invalid float literal");
+    }
+}
+
 #[cfg(test)]
 mod debug {
     use super::*;
@@ -90,12 +300,12 @@ mod debug {
     #[test]
     fn size_of() {
         assert_eq!(std::mem::size_of::<SortLinesBufferNumeric>(), 32);
-        assert_eq!(std::mem::size_of::<SortLine>(), 56);
+        assert_eq!(std::mem::size_of::<SortLine>(), 80);
     }
     #[cfg(target_pointer_width = "32")]
     #[test]
     fn size_of() {
         assert_eq!(std::mem::size_of::<SortLinesBufferNumeric>(), 16);
-        assert_eq!(std::mem::size_of::<SortLine>(), 32);
+        assert_eq!(std::mem::size_of::<SortLine>(), 48);
     }
 }
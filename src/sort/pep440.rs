@@ -0,0 +1,335 @@
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
+use regex::Regex;
+use std::cmp::Ordering;
+
+#[derive(Debug)]
+pub struct SortLinesBufferPep440 {
+    buf_lines: Vec<SortLine>,
+    reverse: bool,
+}
+impl SortLinesBufferPep440 {
+    pub fn new(a_reverse: bool) -> Self {
+        Self {
+            buf_lines: Vec::new(),
+            reverse: a_reverse,
+        }
+    }
+}
+impl SortLinesBuffer for SortLinesBufferPep440 {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
+        let sort_line = SortLine::new(self.buf_lines.len(), key, line)?;
+        self.buf_lines.push(sort_line);
+        Ok(())
+    }
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
+        use rayon::slice::ParallelSliceMut;
+        if !self.reverse {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
+        } else {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
+        }
+        let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
+        for sort_line in self.buf_lines.into_iter() {
+            ret_vec.push(sort_line.key_line);
+        }
+        ret_vec
+    }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        SortLine::new(num, key, line)
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SortLine {
+    num: usize,
+    key: Pep440Version,
+    key_line: KeyLine,
+}
+
+impl SortLine {
+    fn new(a_num: usize, a_key: KeyColumns, a_line: String) -> Result<Self, BadKeyLine> {
+        let key_num = match make_pep440(&a_line[a_key.st..a_key.ed]) {
+            Ok(key_num) => key_num,
+            Err(err) => return Err(BadKeyLine::new(err, a_key, a_line)),
+        };
+        Ok(Self {
+            num: a_num,
+            key: key_num,
+            key_line: KeyLine::new(a_key, a_line),
+        })
+    }
+}
+
+/// the version of the python package by PEP 440,
+/// `[N!]N(.N)*[{a|b|rc}N][.postN][.devN][+local]`.
+/// the fields are in order of the comparison.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub(crate) struct Pep440Version {
+    epoch: u64,
+    /// the release numbers without the trailing zeros, `1.0` is `1`.
+    release: Vec<u64>,
+    pre: PreRelease,
+    post: Option<u64>,
+    dev: DevRelease,
+    local: Option<Vec<LocalSegment>>,
+}
+
+/// the pre-release, in order of the comparison.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum PreRelease {
+    /// only the dev-release, `1.0.dev1` is before `1.0a1`.
+    DevOnly,
+    /// `a`, `b` or `rc`, as 0, 1 or 2, and the number.
+    Pre(u8, u64),
+    None,
+}
+
+/// the dev-release, in order of the comparison.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum DevRelease {
+    Dev(u64),
+    None,
+}
+
+/// a segment of the local version. the number is after the letters.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum LocalSegment {
+    Alpha(Box<str>),
+    Num(u64),
+}
+
+/// the version of `s` by PEP 440. the spellings that PEP 440 normalizes,
+/// like `v1.0`, `1.0-alpha.1` and `1.0-1`, are accepted.
+pub(crate) fn make_pep440(s: &str) -> anyhow::Result<Pep440Version> {
+    lazy_static! {
+        static ref RE: Regex = Regex::new(concat!(
+            r"(?i)^v?(?:([0-9]+)!)?([0-9]+(?:\.[0-9]+)*)",
+            r"(?:[-_.]?(a|b|c|rc|alpha|beta|pre|preview)[-_.]?([0-9]+)?)?",
+            r"(?:-([0-9]+)|[-_.]?(post|rev|r)[-_.]?([0-9]+)?)?",
+            r"(?:[-_.]?(dev)[-_.]?([0-9]+)?)?",
+            r"(?:\+([a-z0-9]+(?:[-_.][a-z0-9]+)*))?$",
+        ))
+        .unwrap();
+    }
+    let s = s.trim();
+    let caps = match RE.captures(s) {
+        Some(caps) => caps,
+        None => return Err(anyhow!("invalid version '{s}' while parsing pep440")),
+    };
+    let num = |i: usize| -> anyhow::Result<Option<u64>> {
+        match caps.get(i) {
+            Some(m) => Ok(Some(parse_num(m.as_str())?)),
+            None => Ok(None),
+        }
+    };
+    let epoch = num(1)?.unwrap_or(0);
+    let mut release = caps[2]
+        .split('.')
+        .map(parse_num)
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    while release.last() == Some(&0) {
+        release.pop();
+    }
+    let pre = match caps.get(3) {
+        Some(m) => {
+            let letter = match m.as_str().to_ascii_lowercase().as_str() {
+                "a" | "alpha" => 0,
+                "b" | "beta" => 1,
+                _ => 2,
+            };
+            Some((letter, num(4)?.unwrap_or(0)))
+        }
+        None => None,
+    };
+    let post = match (num(5)?, caps.get(6)) {
+        (Some(n), _) => Some(n),
+        (None, Some(_)) => Some(num(7)?.unwrap_or(0)),
+        (None, None) => None,
+    };
+    let dev = match caps.get(8) {
+        Some(_) => Some(num(9)?.unwrap_or(0)),
+        None => None,
+    };
+    let local = caps.get(10).map(|m| {
+        m.as_str()
+            .split(['-', '_', '.'])
+            .map(|seg| match seg.parse::<u64>() {
+                Ok(n) if seg.bytes().all(|c| c.is_ascii_digit()) => LocalSegment::Num(n),
+                _ => LocalSegment::Alpha(seg.to_ascii_lowercase().into()),
+            })
+            .collect()
+    });
+    Ok(Pep440Version {
+        epoch,
+        release,
+        pre: match (pre, post, dev) {
+            (Some((letter, n)), _, _) => PreRelease::Pre(letter, n),
+            (None, None, Some(_)) => PreRelease::DevOnly,
+            _ => PreRelease::None,
+        },
+        post,
+        dev: match dev {
+            Some(n) => DevRelease::Dev(n),
+            None => DevRelease::None,
+        },
+        local,
+    })
+}
+
+#[inline]
+fn parse_num(s: &str) -> anyhow::Result<u64> {
+    s.parse::<u64>()
+        .map_err(|_| anyhow!("too large number '{s}' while parsing pep440"))
+}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
+impl PartialOrd for SortLine {
+    #[inline]
+    fn partial_cmp(&self, other: &SortLine) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortLine {
+    #[inline]
+    fn cmp(&self, other: &SortLine) -> Ordering {
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl PartialEq for SortLine {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for SortLine {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn cmp(a: &str, b: &str) -> Ordering {
+        make_pep440(a).unwrap().cmp(&make_pep440(b).unwrap())
+    }
+    #[test]
+    fn test_cmp_pep440() {
+        let vers = [
+            "1.0.dev456",
+            "1.0a1",
+            "1.0a2.dev456",
+            "1.0a12.dev456",
+            "1.0a12",
+            "1.0b1.dev456",
+            "1.0b2",
+            "1.0b2.post345.dev456",
+            "1.0b2.post345",
+            "1.0rc1.dev456",
+            "1.0rc1",
+            "1.0",
+            "1.0+abc.5",
+            "1.0+abc.7",
+            "1.0+5",
+            "1.0.post456.dev34",
+            "1.0.post456",
+            "1.0.15",
+            "1.1.dev1",
+            "1!0.5",
+        ];
+        for w in vers.windows(2) {
+            assert_eq!(cmp(w[0], w[1]), Ordering::Less, "{} < {}", w[0], w[1]);
+        }
+    }
+    #[test]
+    fn test_cmp_pep440_normalized() {
+        assert_eq!(cmp("1.0", "1.0.0"), Ordering::Equal);
+        assert_eq!(cmp("v1.0", "1.0"), Ordering::Equal);
+        assert_eq!(cmp("1.0-ALPHA.1", "1.0a1"), Ordering::Equal);
+        assert_eq!(cmp("1.0c1", "1.0rc1"), Ordering::Equal);
+        assert_eq!(cmp("1.0-1", "1.0.post1"), Ordering::Equal);
+        assert_eq!(cmp("1.0rev", "1.0.post0"), Ordering::Equal);
+        assert_eq!(cmp("1.0+Ubuntu-1", "1.0+ubuntu.1"), Ordering::Equal);
+        assert_eq!(cmp("0!1.0", "1.0"), Ordering::Equal);
+    }
+    #[test]
+    fn test_make_pep440_invalid() {
+        let e = make_pep440("1.0-beta+").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "invalid version '1.0-beta+' while parsing pep440"
+        );
+        let e = make_pep440("1.99999999999999999999").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "too large number '99999999999999999999' while parsing pep440"
+        );
+    }
+}
+
+#[cfg(test)]
+mod debug {
+    use super::*;
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferPep440>(), 32);
+        assert_eq!(std::mem::size_of::<SortLine>(), 152);
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferPep440>(), 16);
+        assert_eq!(std::mem::size_of::<SortLine>(), 92);
+    }
+}
@@ -0,0 +1,350 @@
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
+use std::cmp::Ordering;
+
+#[derive(Debug)]
+pub struct SortLinesBufferRpmVersion {
+    buf_lines: Vec<SortLine>,
+    reverse: bool,
+}
+impl SortLinesBufferRpmVersion {
+    pub fn new(a_reverse: bool) -> Self {
+        Self {
+            buf_lines: Vec::new(),
+            reverse: a_reverse,
+        }
+    }
+}
+impl SortLinesBuffer for SortLinesBufferRpmVersion {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
+        let sort_line = SortLine::new(self.buf_lines.len(), key, line)?;
+        self.buf_lines.push(sort_line);
+        Ok(())
+    }
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
+        use rayon::slice::ParallelSliceMut;
+        if !self.reverse {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
+        } else {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
+        }
+        let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
+        for sort_line in self.buf_lines.into_iter() {
+            ret_vec.push(sort_line.key_line);
+        }
+        ret_vec
+    }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        SortLine::new(num, key, line)
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SortLine {
+    num: usize,
+    key: RpmVersion,
+    key_line: KeyLine,
+}
+
+impl SortLine {
+    fn new(a_num: usize, a_key: KeyColumns, a_line: String) -> Result<Self, BadKeyLine> {
+        let key_num = match make_rpm_version(&a_line[a_key.st..a_key.ed]) {
+            Ok(key_num) => key_num,
+            Err(err) => return Err(BadKeyLine::new(err, a_key, a_line)),
+        };
+        Ok(Self {
+            num: a_num,
+            key: key_num,
+            key_line: KeyLine::new(a_key, a_line),
+        })
+    }
+}
+
+/// the rpm package version, `[epoch:]version[-release]`.
+#[derive(Debug)]
+pub(crate) struct RpmVersion {
+    epoch: u64,
+    version: Box<str>,
+    release: Box<str>,
+}
+
+/// the rpm package version of `s`, like `rpmdev-vercmp`.
+/// the missing epoch is 0, and the missing release is the oldest.
+pub(crate) fn make_rpm_version(s: &str) -> anyhow::Result<RpmVersion> {
+    let s = s.trim();
+    if s.is_empty() {
+        return Err(anyhow!("empty string while parsing rpm-version"));
+    }
+    let (epoch, rest) = match s.split_once(':') {
+        Some((epoch_s, rest)) => {
+            let epoch = epoch_s.parse::<u64>().map_err(|_| {
+                anyhow!("epoch '{epoch_s}' is not a number while parsing rpm-version")
+            })?;
+            (epoch, rest)
+        }
+        None => (0, s),
+    };
+    let (version, release) = rest.rsplit_once('-').unwrap_or((rest, ""));
+    Ok(RpmVersion {
+        epoch,
+        version: version.into(),
+        release: release.into(),
+    })
+}
+
+/// compare the parts of the rpm version, the `rpmvercmp()` of rpm.
+pub(crate) fn rpmvercmp(a: &str, b: &str) -> Ordering {
+    if a == b {
+        return Ordering::Equal;
+    }
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    let is_sep = |c: &u8| !c.is_ascii_alphanumeric() && *c != b'~' && *c != b'^';
+    let (mut i, mut k) = (0, 0);
+    while i < a.len() || k < b.len() {
+        while a.get(i).map_or(false, is_sep) {
+            i += 1;
+        }
+        while b.get(k).map_or(false, is_sep) {
+            k += 1;
+        }
+        // the tilde is older than anything, even the end.
+        if a.get(i) == Some(&b'~') || b.get(k) == Some(&b'~') {
+            if a.get(i) != Some(&b'~') {
+                return Ordering::Greater;
+            }
+            if b.get(k) != Some(&b'~') {
+                return Ordering::Less;
+            }
+            i += 1;
+            k += 1;
+            continue;
+        }
+        // the caret is newer than the end, but older than anything else.
+        if a.get(i) == Some(&b'^') || b.get(k) == Some(&b'^') {
+            if i >= a.len() {
+                return Ordering::Less;
+            }
+            if k >= b.len() {
+                return Ordering::Greater;
+            }
+            if a[i] != b'^' {
+                return Ordering::Greater;
+            }
+            if b[k] != b'^' {
+                return Ordering::Less;
+            }
+            i += 1;
+            k += 1;
+            continue;
+        }
+        if i >= a.len() || k >= b.len() {
+            break;
+        }
+        let is_num = a[i].is_ascii_digit();
+        let is_same = |c: &u8| {
+            if is_num {
+                c.is_ascii_digit()
+            } else {
+                c.is_ascii_alphabetic()
+            }
+        };
+        let a_st = i;
+        while a.get(i).map_or(false, is_same) {
+            i += 1;
+        }
+        let b_st = k;
+        while b.get(k).map_or(false, is_same) {
+            k += 1;
+        }
+        let (a_seg, b_seg) = (&a[a_st..i], &b[b_st..k]);
+        // the numeric segment is newer than the alpha segment.
+        if b_seg.is_empty() {
+            return if is_num {
+                Ordering::Greater
+            } else {
+                Ordering::Less
+            };
+        }
+        let r = if is_num {
+            let a_seg = trim_zeros(a_seg);
+            let b_seg = trim_zeros(b_seg);
+            a_seg.len().cmp(&b_seg.len()).then_with(|| a_seg.cmp(b_seg))
+        } else {
+            a_seg.cmp(b_seg)
+        };
+        if r != Ordering::Equal {
+            return r;
+        }
+    }
+    match (i >= a.len(), k >= b.len()) {
+        (true, true) => Ordering::Equal,
+        (false, _) => Ordering::Greater,
+        (true, false) => Ordering::Less,
+    }
+}
+
+#[inline]
+fn trim_zeros(s: &[u8]) -> &[u8] {
+    let len = s.iter().position(|&c| c != b'0').unwrap_or(s.len());
+    &s[len..]
+}
+
+impl Ord for RpmVersion {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.epoch
+            .cmp(&other.epoch)
+            .then_with(|| rpmvercmp(&self.version, &other.version))
+            .then_with(|| rpmvercmp(&self.release, &other.release))
+    }
+}
+
+impl PartialOrd for RpmVersion {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl PartialEq for RpmVersion {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for RpmVersion {}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
+impl PartialOrd for SortLine {
+    #[inline]
+    fn partial_cmp(&self, other: &SortLine) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortLine {
+    #[inline]
+    fn cmp(&self, other: &SortLine) -> Ordering {
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl PartialEq for SortLine {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for SortLine {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    fn cmp(a: &str, b: &str) -> Ordering {
+        make_rpm_version(a)
+            .unwrap()
+            .cmp(&make_rpm_version(b).unwrap())
+    }
+    #[test]
+    fn test_rpmvercmp() {
+        assert_eq!(rpmvercmp("1.0", "1.0"), Ordering::Equal);
+        assert_eq!(rpmvercmp("1.0", "2.0"), Ordering::Less);
+        assert_eq!(rpmvercmp("2.0.1", "2.0"), Ordering::Greater);
+        assert_eq!(rpmvercmp("2.0.1a", "2.0.1"), Ordering::Greater);
+        assert_eq!(rpmvercmp("5.5p1", "5.5p10"), Ordering::Less);
+        assert_eq!(rpmvercmp("10xyz", "10.1xyz"), Ordering::Less);
+        assert_eq!(rpmvercmp("xyz10", "xyz10.1"), Ordering::Less);
+        assert_eq!(rpmvercmp("1.0a", "1.0.1"), Ordering::Less);
+        assert_eq!(rpmvercmp("1b.fc17", "1.fc17"), Ordering::Less);
+        assert_eq!(rpmvercmp("1.0~rc1", "1.0"), Ordering::Less);
+        assert_eq!(rpmvercmp("1.0~rc1", "1.0~rc2"), Ordering::Less);
+        assert_eq!(rpmvercmp("1.0^", "1.0"), Ordering::Greater);
+        assert_eq!(rpmvercmp("1.0^git1", "1.0.1"), Ordering::Less);
+        assert_eq!(rpmvercmp("1.0^git1", "1.0~rc1"), Ordering::Greater);
+        assert_eq!(rpmvercmp("1.0", "1_0"), Ordering::Equal);
+        assert_eq!(rpmvercmp("010", "10"), Ordering::Equal);
+    }
+    #[test]
+    fn test_cmp_rpm_version() {
+        assert_eq!(cmp("1:1.0-1", "2.0-1"), Ordering::Greater);
+        assert_eq!(cmp("1.0-1.el9", "1.0-2.el9"), Ordering::Less);
+        assert_eq!(cmp("1.0", "1.0-1"), Ordering::Less);
+        assert_eq!(cmp("0:1.0-1", "1.0-1"), Ordering::Equal);
+    }
+    #[test]
+    fn test_make_rpm_version_invalid() {
+        let e = make_rpm_version("").unwrap_err();
+        assert_eq!(e.to_string(), "empty string while parsing rpm-version");
+        let e = make_rpm_version("x:1.0").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "epoch 'x' is not a number while parsing rpm-version"
+        );
+    }
+}
+
+#[cfg(test)]
+mod debug {
+    use super::*;
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferRpmVersion>(), 32);
+        assert_eq!(std::mem::size_of::<SortLine>(), 88);
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferRpmVersion>(), 16);
+        assert_eq!(std::mem::size_of::<SortLine>(), 48);
+    }
+}
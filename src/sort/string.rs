@@ -1,5 +1,8 @@
-use super::{KeyColumns, KeyLine, SortLinesBuffer};
+use super::case_fold::case_fold;
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
+use crate::util::OptNormalizeForm;
 use std::cmp::Ordering;
+use unicode_normalization::UnicodeNormalization;
 
 #[derive(Debug)]
 pub struct SortLinesBufferString {
@@ -15,17 +18,21 @@ impl SortLinesBufferString {
     }
 }
 impl SortLinesBuffer for SortLinesBufferString {
-    fn push_line(&mut self, key: KeyColumns, line: String) -> anyhow::Result<()> {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
         let sort_line = SortLine::new(self.buf_lines.len(), key, line);
         self.buf_lines.push(sort_line);
         Ok(())
     }
-    fn into_sorted_vec(mut self) -> Vec<KeyLine> {
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
         use rayon::slice::ParallelSliceMut;
         if !self.reverse {
             self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
         } else {
-            self.buf_lines.par_sort_unstable_by(|a, b| b.cmp(a));
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
         }
         let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
         for sort_line in self.buf_lines.into_iter() {
@@ -33,10 +40,25 @@ impl SortLinesBuffer for SortLinesBufferString {
         }
         ret_vec
     }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        Ok(SortLine::new(num, key, line))
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
 }
 
 #[derive(Debug)]
-struct SortLine {
+pub(crate) struct SortLine {
     num: usize,
     key_line: KeyLine,
 }
@@ -54,6 +76,70 @@ impl SortLine {
     }
 }
 
+/// the key of `s` folded by `ignore_case` and normalized by `normalize`.
+/// it is none if neither of them is given, then `s` is the key as is.
+pub(crate) fn make_string_key(
+    s: &str,
+    ignore_case: bool,
+    normalize: Option<OptNormalizeForm>,
+) -> Option<Box<str>> {
+    (ignore_case || normalize.is_some()).then(|| make_mapped_key(s, ignore_case, normalize))
+}
+
+/// the key of `s` folded by `ignore_case` and normalized by `normalize`.
+pub(crate) fn make_mapped_key(
+    s: &str,
+    ignore_case: bool,
+    normalize: Option<OptNormalizeForm>,
+) -> Box<str> {
+    let folded;
+    let s = if ignore_case {
+        // decompose before the case folding, like the canonical caseless
+        // match of Unicode. `U+0345` is folded after the reordering.
+        folded = match normalize {
+            Some(OptNormalizeForm::Nfc | OptNormalizeForm::Nfd) => {
+                case_fold(&make_normalized(s, OptNormalizeForm::Nfd))
+            }
+            Some(OptNormalizeForm::Nfkc | OptNormalizeForm::Nfkd) => {
+                case_fold(&make_normalized(s, OptNormalizeForm::Nfkd))
+            }
+            None => case_fold(s),
+        };
+        folded.as_str()
+    } else {
+        s
+    };
+    match normalize {
+        Some(form) => make_normalized(s, form).into(),
+        None => s.into(),
+    }
+}
+
+/// `s` normalized by the Unicode normalization `form`.
+pub(crate) fn make_normalized(s: &str, form: OptNormalizeForm) -> String {
+    match form {
+        OptNormalizeForm::Nfc => s.nfc().collect(),
+        OptNormalizeForm::Nfd => s.nfd().collect(),
+        OptNormalizeForm::Nfkc => s.nfkc().collect(),
+        OptNormalizeForm::Nfkd => s.nfkd().collect(),
+    }
+}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
 impl PartialOrd for SortLine {
     #[inline]
     fn partial_cmp(&self, other: &SortLine) -> Option<Ordering> {
@@ -64,9 +150,26 @@ impl PartialOrd for SortLine {
 impl Ord for SortLine {
     #[inline]
     fn cmp(&self, other: &SortLine) -> Ordering {
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
         let one = self.key_str();
         let two = other.key_str();
-        let r = one.cmp(two);
+        one.cmp(two)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
         match r {
             Ordering::Equal => self.num.cmp(&other.num),
             _ => r,
@@ -103,3 +206,44 @@ mod debug {
         assert_eq!(std::mem::size_of::<SortLine>(), 24);
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_make_normalized() {
+        let nfc = "caf\u{e9}";
+        let nfd = "cafe\u{301}";
+        assert_eq!(make_normalized(nfd, OptNormalizeForm::Nfc), nfc);
+        assert_eq!(make_normalized(nfc, OptNormalizeForm::Nfd), nfd);
+        assert_eq!(
+            make_normalized("\u{fb01}le", OptNormalizeForm::Nfc),
+            "\u{fb01}le"
+        );
+        assert_eq!(
+            make_normalized("\u{fb01}le", OptNormalizeForm::Nfkc),
+            "file"
+        );
+        assert_eq!(
+            make_normalized("\u{ff21}\u{e9}", OptNormalizeForm::Nfkd),
+            "Ae\u{301}"
+        );
+    }
+    #[test]
+    fn test_make_string_key() {
+        assert_eq!(make_string_key("Caf\u{e9}", false, None), None);
+        assert_eq!(
+            make_string_key("Caf\u{e9}", true, None).as_deref(),
+            Some("caf\u{e9}")
+        );
+        let key = make_string_key("Cafe\u{301}", true, Some(OptNormalizeForm::Nfc));
+        assert_eq!(key.as_deref(), Some("caf\u{e9}"));
+        let key = make_string_key("Cafe\u{301}", false, Some(OptNormalizeForm::Nfc));
+        assert_eq!(key.as_deref(), Some("Caf\u{e9}"));
+        // the canonical equivalents, but `U+0345` is before `U+0301` in the first.
+        let a = make_string_key("\u{391}\u{345}\u{301}", true, Some(OptNormalizeForm::Nfc));
+        let b = make_string_key("\u{3b1}\u{301}\u{345}", true, Some(OptNormalizeForm::Nfc));
+        assert_eq!(a.as_deref(), Some("\u{3ac}\u{3b9}"));
+        assert_eq!(a, b);
+    }
+}
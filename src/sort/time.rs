@@ -1,7 +1,6 @@
-use super::{KeyColumns, KeyLine, SortLinesBuffer};
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
 use anyhow::Context;
 use std::cmp::Ordering;
-use std::time::Duration;
 
 #[derive(Debug)]
 pub struct SortLinesBufferTime {
@@ -17,17 +16,21 @@ impl SortLinesBufferTime {
     }
 }
 impl SortLinesBuffer for SortLinesBufferTime {
-    fn push_line(&mut self, key: KeyColumns, line: String) -> anyhow::Result<()> {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
         let sort_line = SortLine::new(self.buf_lines.len(), key, line)?;
         self.buf_lines.push(sort_line);
         Ok(())
     }
-    fn into_sorted_vec(mut self) -> Vec<KeyLine> {
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
         use rayon::slice::ParallelSliceMut;
         if !self.reverse {
             self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
         } else {
-            self.buf_lines.par_sort_unstable_by(|a, b| b.cmp(a));
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
         }
         let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
         for sort_line in self.buf_lines.into_iter() {
@@ -35,19 +38,36 @@ impl SortLinesBuffer for SortLinesBufferTime {
         }
         ret_vec
     }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        SortLine::new(num, key, line)
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
 }
 
 #[derive(Debug)]
-struct SortLine {
+pub(crate) struct SortLine {
     num: usize,
-    key: Duration,
+    key: i128,
     key_line: KeyLine,
 }
 
 impl SortLine {
-    fn new(a_num: usize, a_key: KeyColumns, a_line: String) -> anyhow::Result<Self> {
-        let key_ver = make_time(&a_line[a_key.st..a_key.ed])
-            .with_context(|| format!("({},{}):'{}'", a_key.st, a_key.ed, a_line))?;
+    fn new(a_num: usize, a_key: KeyColumns, a_line: String) -> Result<Self, BadKeyLine> {
+        let key_ver = match make_time(&a_line[a_key.st..a_key.ed]) {
+            Ok(key_ver) => key_ver,
+            Err(err) => return Err(BadKeyLine::new(err, a_key, a_line)),
+        };
         Ok(Self {
             num: a_num,
             key: key_ver,
@@ -56,8 +76,18 @@ impl SortLine {
     }
 }
 
-fn make_time(s: &str) -> anyhow::Result<Duration> {
-    for c in s.chars() {
+/// the time of `s` in nanoseconds, `[-][D-|Dd ][[H:]M:]S[.F]`.
+///
+/// the fraction `F` is a decimal fraction of a second, and the digits under
+/// nanoseconds are truncated. the days are like `1-03:04:05` of slurm or
+/// `2d 03:04:05`.
+pub(crate) fn make_time(s: &str) -> anyhow::Result<i128> {
+    let (neg, key_s) = match s.strip_prefix('-') {
+        Some(key_s) => (true, key_s),
+        None => (false, s.strip_prefix('+').unwrap_or(s)),
+    };
+    let (days, key_s) = split_days(key_s)?;
+    for c in key_s.chars() {
         match c {
             '0'..='9' | ':' | '.' => (),
             _ => {
@@ -66,64 +96,69 @@ fn make_time(s: &str) -> anyhow::Result<Duration> {
         }
     }
     //
-    let key_s = s;
-    let (millis, idx) = if key_s.is_empty() {
-        (0, 0)
-    } else {
-        match key_s.rfind('.') {
-            Some(idx) => {
-                let num = &key_s[(idx + 1)..]
-                    .parse::<u64>()
-                    .with_context(|| format!("can not parse millis: '{}'", &key_s[idx..]))?;
-                (*num, idx)
+    let (nanos, key_s) = match key_s.rfind('.') {
+        Some(idx) => {
+            let frac = &key_s[(idx + 1)..];
+            if frac.is_empty() || !frac.bytes().all(|c| c.is_ascii_digit()) {
+                return Err(anyhow!("can not parse fraction: '{}'", &key_s[idx..]));
             }
-            None => (0, key_s.len()),
+            // "5" is 500_000_000 ns, and "123456789012" is 123_456_789 ns.
+            let digits = format!("{:0<9.9}", frac);
+            (digits.parse::<i128>()?, &key_s[..idx])
         }
+        None => (0, key_s),
     };
-    let key_s = &key_s[..idx];
-    let (seconds, idx) = if key_s.is_empty() {
-        (0, 0)
+    let mut parts = if key_s.is_empty() {
+        Vec::new()
     } else {
-        match key_s.rfind(':') {
-            Some(idx) => {
-                let num = &key_s[(idx + 1)..].parse::<u64>().with_context(|| {
-                    format!(
-                        "can not parse seconds: '{}', already: {millis}ms",
-                        &key_s[idx..]
-                    )
-                })?;
-                (*num, idx)
-            }
-            None => (0, key_s.len()),
-        }
+        key_s.split(':').collect::<Vec<_>>()
     };
-    let key_s = &key_s[..idx];
-    let (minutes, idx) = if key_s.is_empty() {
-        (0, 0)
-    } else {
-        let (kk, ii) = match key_s.rfind(':') {
-            Some(idx) => (&key_s[(idx + 1)..], idx),
-            None => (key_s, 0),
+    if parts.len() > 3 {
+        return Err(anyhow!("too many ':' while parsing time"));
+    }
+    let mut secs: i128 = days as i128 * 24 * 60 * 60;
+    let units = [("seconds", 1), ("minutes", 60), ("hours", 60 * 60)];
+    for (name, unit) in units.iter() {
+        let part = match parts.pop() {
+            Some(part) => part,
+            None => break,
         };
-        let num = kk.parse::<u64>().with_context(|| {
-            format!("can not parse minutes: '{kk}', already: {seconds}.{millis}")
-        })?;
-        (num, ii)
-    };
-    let key_s = &key_s[..idx];
-    let (hours, _idx) = if key_s.is_empty() {
-        (0, 0)
-    } else {
-        let num = key_s.parse::<u64>().with_context(|| {
-            format!("can not parse hours: '{key_s}', already: {minutes}:{seconds}.{millis}")
-        })?;
-        (num, key_s.len())
+        let num = part
+            .parse::<u64>()
+            .with_context(|| format!("can not parse {name}: '{part}'"))?;
+        secs += num as i128 * unit;
+    }
+    let v = secs * 1_000_000_000 + nanos;
+    Ok(if neg { -v } else { v })
+}
+
+/// the days and the rest of `s`, `D-` or `Dd `.
+fn split_days(s: &str) -> anyhow::Result<(u64, &str)> {
+    let idx = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let rest = match s[idx..].chars().next() {
+        Some('-') if idx > 0 => &s[(idx + 1)..],
+        Some('d') if idx > 0 => s[(idx + 1)..].trim_start(),
+        _ => return Ok((0, s)),
     };
-    //
-    //eprintln!("AAA: {hours}:{minutes}:{seconds}.{millis}");
-    let dur_sec = Duration::from_secs(hours * 60 * 60 + minutes * 60 + seconds);
-    let dur_milli = Duration::from_millis(millis);
-    Ok(dur_sec + dur_milli)
+    let days = s[..idx]
+        .parse::<u64>()
+        .with_context(|| format!("can not parse days: '{}'", &s[..idx]))?;
+    Ok((days, rest))
+}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
 }
 
 impl PartialOrd for SortLine {
@@ -136,7 +171,24 @@ impl PartialOrd for SortLine {
 impl Ord for SortLine {
     #[inline]
     fn cmp(&self, other: &SortLine) -> Ordering {
-        let r = self.key.cmp(&other.key);
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
         match r {
             Ordering::Equal => self.num.cmp(&other.num),
             _ => r,
@@ -153,6 +205,46 @@ impl PartialEq for SortLine {
 
 impl Eq for SortLine {}
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    const NS: i128 = 1_000_000_000;
+    #[test]
+    fn test_make_time() {
+        assert_eq!(make_time("").unwrap(), 0);
+        assert_eq!(make_time("5").unwrap(), 5 * NS);
+        assert_eq!(make_time("1:00.5").unwrap(), 60 * NS + NS / 2);
+        assert_eq!(make_time("1:00.123456").unwrap(), 60 * NS + 123_456_000);
+        assert_eq!(make_time("0.1234567891").unwrap(), 123_456_789);
+        assert_eq!(make_time("2:03:04").unwrap(), (2 * 3600 + 3 * 60 + 4) * NS);
+        assert_eq!(make_time("-1:30").unwrap(), -90 * NS);
+        assert_eq!(make_time("+1:30").unwrap(), 90 * NS);
+    }
+    #[test]
+    fn test_make_time_days() {
+        let v = (2 * 86400 + 3 * 3600 + 4 * 60 + 5) * NS;
+        assert_eq!(make_time("2d 03:04:05").unwrap(), v);
+        assert_eq!(make_time("2-03:04:05").unwrap(), v);
+        assert_eq!(make_time("-2-03:04:05").unwrap(), -v);
+        assert_eq!(make_time("1d").unwrap(), 86400 * NS);
+    }
+    #[test]
+    fn test_make_time_invalid() {
+        let e = make_time("1:0a").unwrap_err();
+        assert_eq!(e.to_string(), "unexpected character 'a' while parsing time");
+        let e = make_time("1:2:3:4").unwrap_err();
+        assert_eq!(e.to_string(), "too many ':' while parsing time");
+        let e = make_time("1.2.3").unwrap_err();
+        assert_eq!(e.to_string(), "can not parse seconds: '1.2'");
+        let e = make_time("1:").unwrap_err();
+        assert_eq!(e.to_string(), "can not parse seconds: ''");
+        let e = make_time("1.").unwrap_err();
+        assert_eq!(e.to_string(), "can not parse fraction: '.'");
+        let e = make_time("1.5:30").unwrap_err();
+        assert_eq!(e.to_string(), "can not parse fraction: '.5:30'");
+    }
+}
+
 #[cfg(test)]
 mod debug {
     use super::*;
@@ -167,7 +259,7 @@ mod debug {
     fn size_of() {
         assert_eq!(std::mem::size_of::<SortLinesBufferTime>(), 16);
         #[cfg(not(any(target_arch = "arm", target_arch = "mips")))]
-        assert_eq!(std::mem::size_of::<SortLine>(), 36);
+        assert_eq!(std::mem::size_of::<SortLine>(), 48);
         #[cfg(any(target_arch = "arm", target_arch = "mips"))]
         assert_eq!(std::mem::size_of::<SortLine>(), 40);
     }
@@ -1,5 +1,4 @@
-use super::{KeyColumns, KeyLine, SortLinesBuffer};
-use anyhow::Context;
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
 use semver::{Error, Version};
 use std::cmp::Ordering;
 
@@ -17,17 +16,21 @@ impl SortLinesBufferVersion {
     }
 }
 impl SortLinesBuffer for SortLinesBufferVersion {
-    fn push_line(&mut self, key: KeyColumns, line: String) -> anyhow::Result<()> {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
         let sort_line = SortLine::new(self.buf_lines.len(), key, line)?;
         self.buf_lines.push(sort_line);
         Ok(())
     }
-    fn into_sorted_vec(mut self) -> Vec<KeyLine> {
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
         use rayon::slice::ParallelSliceMut;
         if !self.reverse {
             self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
         } else {
-            self.buf_lines.par_sort_unstable_by(|a, b| b.cmp(a));
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
         }
         let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
         for sort_line in self.buf_lines.into_iter() {
@@ -35,19 +38,36 @@ impl SortLinesBuffer for SortLinesBufferVersion {
         }
         ret_vec
     }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        SortLine::new(num, key, line)
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
 }
 
 #[derive(Debug)]
-struct SortLine {
+pub(crate) struct SortLine {
     num: usize,
     key: Version,
     key_line: KeyLine,
 }
 
 impl SortLine {
-    fn new(a_num: usize, a_key: KeyColumns, a_line: String) -> anyhow::Result<Self> {
-        let key_ver = make_version(&a_line[a_key.st..a_key.ed])
-            .with_context(|| format!("({},{}):'{}'", a_key.st, a_key.ed, a_line))?;
+    fn new(a_num: usize, a_key: KeyColumns, a_line: String) -> Result<Self, BadKeyLine> {
+        let key_ver = match make_version(&a_line[a_key.st..a_key.ed]) {
+            Ok(key_ver) => key_ver,
+            Err(err) => return Err(BadKeyLine::new(err.into(), a_key, a_line)),
+        };
         Ok(Self {
             num: a_num,
             key: key_ver,
@@ -56,7 +76,7 @@ impl SortLine {
     }
 }
 
-fn make_version(s: &str) -> Result<Version, Error> {
+pub(crate) fn make_version(s: &str) -> Result<Version, Error> {
     match Version::parse(s) {
         Ok(ver) => Ok(ver),
         Err(err) => match err.to_string().as_str() {
@@ -71,6 +91,21 @@ fn make_version(s: &str) -> Result<Version, Error> {
     }
 }
 
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
 impl PartialOrd for SortLine {
     #[inline]
     fn partial_cmp(&self, other: &SortLine) -> Option<Ordering> {
@@ -81,7 +116,24 @@ impl PartialOrd for SortLine {
 impl Ord for SortLine {
     #[inline]
     fn cmp(&self, other: &SortLine) -> Ordering {
-        let r = self.key.cmp(&other.key);
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
         match r {
             Ordering::Equal => self.num.cmp(&other.num),
             _ => r,
@@ -98,6 +150,251 @@ impl PartialEq for SortLine {
 
 impl Eq for SortLine {}
 
+/// the lenient version, like `sort -V`. any string is a version.
+#[derive(Debug)]
+pub struct SortLinesBufferLenientVersion {
+    buf_lines: Vec<LenientSortLine>,
+    reverse: bool,
+}
+impl SortLinesBufferLenientVersion {
+    pub fn new(a_reverse: bool) -> Self {
+        Self {
+            buf_lines: Vec::new(),
+            reverse: a_reverse,
+        }
+    }
+}
+impl SortLinesBuffer for SortLinesBufferLenientVersion {
+    type Line = LenientSortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
+        let sort_line = LenientSortLine::new(self.buf_lines.len(), key, line);
+        self.buf_lines.push(sort_line);
+        Ok(())
+    }
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
+        use rayon::slice::ParallelSliceMut;
+        if !self.reverse {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
+        } else {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
+        }
+        let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
+        for sort_line in self.buf_lines.into_iter() {
+            ret_vec.push(sort_line.key_line);
+        }
+        ret_vec
+    }
+    fn make_line(
+        &self,
+        num: usize,
+        key: KeyColumns,
+        line: String,
+    ) -> Result<LenientSortLine, BadKeyLine> {
+        Ok(LenientSortLine::new(num, key, line))
+    }
+    #[inline]
+    fn cmp_line(&self, a: &LenientSortLine, b: &LenientSortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &LenientSortLine, b: &LenientSortLine) -> bool {
+        a == b
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct LenientSortLine {
+    num: usize,
+    key_line: KeyLine,
+}
+
+impl LenientSortLine {
+    fn new(a_num: usize, a_key: KeyColumns, a_line: String) -> Self {
+        Self {
+            num: a_num,
+            key_line: KeyLine::new(a_key, a_line),
+        }
+    }
+    #[inline]
+    fn key_str(&self) -> &str {
+        &self.key_line.line[self.key_line.key.st..self.key_line.key.ed]
+    }
+}
+
+impl BufLine for LenientSortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
+/// compare the versions like `filevercmp()` of gnulib, that is `sort -V`.
+/// the file suffixes, like `.tar.gz`, are compared only if the rests are equal.
+pub(crate) fn cmp_lenient_version(a: &str, b: &str) -> Ordering {
+    let (a, b) = (a.as_bytes(), b.as_bytes());
+    // the empty string is the first, and then ".", "..", and the names
+    // that start with ".".
+    match (a.is_empty(), b.is_empty()) {
+        (true, true) => return Ordering::Equal,
+        (true, false) => return Ordering::Less,
+        (false, true) => return Ordering::Greater,
+        _ => (),
+    }
+    match (a[0] == b'.', b[0] == b'.') {
+        (true, true) => {
+            for dots in [&b"."[..], &b".."[..]] {
+                match (a == dots, b == dots) {
+                    (true, true) => return Ordering::Equal,
+                    (true, false) => return Ordering::Less,
+                    (false, true) => return Ordering::Greater,
+                    _ => (),
+                }
+            }
+        }
+        (true, false) => return Ordering::Less,
+        (false, true) => return Ordering::Greater,
+        _ => (),
+    }
+    let a_len = file_prefix_len(a);
+    let b_len = file_prefix_len(b);
+    match filever_revcmp(&a[..a_len], &b[..b_len]) {
+        Ordering::Equal if a_len != a.len() || b_len != b.len() => filever_revcmp(a, b),
+        r => r,
+    }
+}
+
+/// the length of `s` without the file suffix, `(\.[A-Za-z~][A-Za-z0-9~]*)*$`.
+fn file_prefix_len(s: &[u8]) -> usize {
+    let mut prefix_len = 0;
+    let mut i = 0;
+    while i < s.len() {
+        i += 1;
+        prefix_len = i;
+        while i + 1 < s.len()
+            && s[i] == b'.'
+            && (s[i + 1].is_ascii_alphabetic() || s[i + 1] == b'~')
+        {
+            i += 2;
+            while i < s.len() && (s[i].is_ascii_alphanumeric() || s[i] == b'~') {
+                i += 1;
+            }
+        }
+    }
+    prefix_len
+}
+
+/// the order of a character of the non-digit part. `~` is the smallest,
+/// and then the end, the letters and the others.
+#[inline]
+fn filever_order(c: Option<u8>) -> i32 {
+    match c {
+        None => -1,
+        Some(c) if c.is_ascii_digit() => 0,
+        Some(c) if c.is_ascii_alphabetic() => c as i32,
+        Some(b'~') => -2,
+        Some(c) => c as i32 + 256,
+    }
+}
+
+fn filever_revcmp(a: &[u8], b: &[u8]) -> Ordering {
+    let is_digit = |s: &[u8], i: usize| s.get(i).map_or(false, |c| c.is_ascii_digit());
+    let (mut i, mut k) = (0, 0);
+    while i < a.len() || k < b.len() {
+        while (i < a.len() && !is_digit(a, i)) || (k < b.len() && !is_digit(b, k)) {
+            let ac = filever_order(a.get(i).copied());
+            let bc = filever_order(b.get(k).copied());
+            if ac != bc {
+                return ac.cmp(&bc);
+            }
+            i += 1;
+            k += 1;
+        }
+        while a.get(i) == Some(&b'0') {
+            i += 1;
+        }
+        while b.get(k) == Some(&b'0') {
+            k += 1;
+        }
+        let mut first_diff = Ordering::Equal;
+        while is_digit(a, i) && is_digit(b, k) {
+            if first_diff == Ordering::Equal {
+                first_diff = a[i].cmp(&b[k]);
+            }
+            i += 1;
+            k += 1;
+        }
+        if is_digit(a, i) {
+            return Ordering::Greater;
+        }
+        if is_digit(b, k) {
+            return Ordering::Less;
+        }
+        if first_diff != Ordering::Equal {
+            return first_diff;
+        }
+    }
+    Ordering::Equal
+}
+
+impl PartialOrd for LenientSortLine {
+    #[inline]
+    fn partial_cmp(&self, other: &LenientSortLine) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for LenientSortLine {
+    #[inline]
+    fn cmp(&self, other: &LenientSortLine) -> Ordering {
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl LenientSortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &LenientSortLine) -> Ordering {
+        cmp_lenient_version(self.key_str(), other.key_str())
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &LenientSortLine) -> Ordering {
+        let r = other.cmp_key(self);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl PartialEq for LenientSortLine {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        cmp_lenient_version(self.key_str(), other.key_str()) == Ordering::Equal
+    }
+}
+
+impl Eq for LenientSortLine {}
+
 #[cfg(test)]
 mod debug {
     use super::*;
@@ -106,11 +403,58 @@ mod debug {
     fn size_of() {
         assert_eq!(std::mem::size_of::<SortLinesBufferVersion>(), 32);
         assert_eq!(std::mem::size_of::<SortLine>(), 88);
+        assert_eq!(std::mem::size_of::<SortLinesBufferLenientVersion>(), 32);
+        assert_eq!(std::mem::size_of::<LenientSortLine>(), 48);
     }
     #[cfg(target_pointer_width = "32")]
     #[test]
     fn size_of() {
         assert_eq!(std::mem::size_of::<SortLinesBufferVersion>(), 16);
         assert_eq!(std::mem::size_of::<SortLine>(), 64);
+        assert_eq!(std::mem::size_of::<SortLinesBufferLenientVersion>(), 16);
+        assert_eq!(std::mem::size_of::<LenientSortLine>(), 24);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_cmp_lenient_version() {
+        assert_eq!(cmp_lenient_version("1.2.3.4", "1.2.3.10"), Ordering::Less);
+        assert_eq!(cmp_lenient_version("v1.9", "v1.10"), Ordering::Less);
+        assert_eq!(
+            cmp_lenient_version("1.0-beta2", "1.0-beta10"),
+            Ordering::Less
+        );
+        assert_eq!(cmp_lenient_version("1.0~rc1", "1.0"), Ordering::Less);
+        assert_eq!(cmp_lenient_version("1.0", "1.0a"), Ordering::Less);
+        assert_eq!(cmp_lenient_version("1.01", "1.1"), Ordering::Equal);
+        assert_eq!(cmp_lenient_version("", "a"), Ordering::Less);
+        assert_eq!(cmp_lenient_version(".", ".."), Ordering::Less);
+        assert_eq!(cmp_lenient_version("..", ".a"), Ordering::Less);
+        assert_eq!(cmp_lenient_version(".z", "a"), Ordering::Less);
+    }
+    #[test]
+    fn test_cmp_lenient_version_suffix() {
+        assert_eq!(
+            cmp_lenient_version("foo-1.2.tar.gz", "foo-1.10.tar.gz"),
+            Ordering::Less
+        );
+        assert_eq!(
+            cmp_lenient_version("foo-1.2.tar.gz", "foo-1.2.tar.bz2"),
+            Ordering::Greater
+        );
+        assert_eq!(
+            cmp_lenient_version("foo-1.2.tar.gz", "foo-1.2"),
+            Ordering::Greater
+        );
+    }
+    #[test]
+    fn test_file_prefix_len() {
+        assert_eq!(file_prefix_len(b"foo-1.2.tar.gz"), 7);
+        assert_eq!(file_prefix_len(b"foo"), 3);
+        assert_eq!(file_prefix_len(b"1.2"), 3);
+        assert_eq!(file_prefix_len(b".bashrc"), 7);
     }
 }
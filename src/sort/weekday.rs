@@ -0,0 +1,205 @@
+use super::month::normalize;
+use super::{BadKeyLine, BufLine, KeyColumns, KeyLine, SortLinesBuffer};
+use crate::util::OptFirstWeekday;
+use std::cmp::Ordering;
+
+#[derive(Debug)]
+pub struct SortLinesBufferWeekday {
+    buf_lines: Vec<SortLine>,
+    reverse: bool,
+    first_weekday: OptFirstWeekday,
+}
+impl SortLinesBufferWeekday {
+    pub fn new(a_reverse: bool, a_first_weekday: OptFirstWeekday) -> Self {
+        Self {
+            buf_lines: Vec::new(),
+            reverse: a_reverse,
+            first_weekday: a_first_weekday,
+        }
+    }
+}
+impl SortLinesBuffer for SortLinesBufferWeekday {
+    type Line = SortLine;
+    fn push_line(&mut self, key: KeyColumns, line: String) -> Result<(), BadKeyLine> {
+        let sort_line = SortLine::new(self.buf_lines.len(), key, line, self.first_weekday)?;
+        self.buf_lines.push(sort_line);
+        Ok(())
+    }
+    fn into_sorted_vec(mut self, unique: bool) -> Vec<KeyLine> {
+        use rayon::slice::ParallelSliceMut;
+        if !self.reverse {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp(b));
+        } else {
+            self.buf_lines.par_sort_unstable_by(|a, b| a.cmp_reverse(b));
+        }
+        if unique {
+            self.buf_lines.dedup_by(|a, b| a == b);
+        }
+        let mut ret_vec = Vec::with_capacity(self.buf_lines.len());
+        for sort_line in self.buf_lines.into_iter() {
+            ret_vec.push(sort_line.key_line);
+        }
+        ret_vec
+    }
+    fn make_line(&self, num: usize, key: KeyColumns, line: String) -> Result<SortLine, BadKeyLine> {
+        SortLine::new(num, key, line, self.first_weekday)
+    }
+    #[inline]
+    fn cmp_line(&self, a: &SortLine, b: &SortLine) -> Ordering {
+        if !self.reverse {
+            a.cmp(b)
+        } else {
+            a.cmp_reverse(b)
+        }
+    }
+    #[inline]
+    fn eq_line(&self, a: &SortLine, b: &SortLine) -> bool {
+        a == b
+    }
+}
+
+#[derive(Debug)]
+pub(crate) struct SortLine {
+    num: usize,
+    key: i64,
+    key_line: KeyLine,
+}
+
+const DICT_FULL: [(&str, &str, &str); 7] = [
+    ("mon", "mon", "monday"),
+    ("tue", "tues", "tuesday"),
+    ("wed", "weds", "wednesday"),
+    ("thu", "thurs", "thursday"),
+    ("fri", "fri", "friday"),
+    ("sat", "sat", "saturday"),
+    ("sun", "sun", "sunday"),
+];
+
+impl SortLine {
+    fn new(
+        a_num: usize,
+        a_key: KeyColumns,
+        a_line: String,
+        a_first_weekday: OptFirstWeekday,
+    ) -> Result<Self, BadKeyLine> {
+        let idx = match make_weekday(&a_line[a_key.st..a_key.ed], a_first_weekday) {
+            Some(idx) => idx,
+            None => {
+                let err = anyhow!("invalid weekday strings");
+                return Err(BadKeyLine::new(err, a_key, a_line));
+            }
+        };
+        Ok(Self {
+            num: a_num,
+            key: idx,
+            key_line: KeyLine::new(a_key, a_line),
+        })
+    }
+}
+
+/// the weekday index of `s`, origin 0 at `first_weekday`.
+/// `None` if `s` is not a weekday name. `s` is normalized like the month
+/// names, then ` Tue` and `Mon.` are the weekday names.
+pub(crate) fn make_weekday(s: &str, first_weekday: OptFirstWeekday) -> Option<i64> {
+    let key = normalize(s);
+    let idx = DICT_FULL
+        .iter()
+        .position(|item| item.0 == key || item.1 == key || item.2 == key)? as i64;
+    match first_weekday {
+        OptFirstWeekday::Monday => Some(idx),
+        OptFirstWeekday::Sunday => Some((idx + 1) % 7),
+    }
+}
+
+impl BufLine for SortLine {
+    #[inline]
+    fn num(&self) -> usize {
+        self.num
+    }
+    #[inline]
+    fn key_line(&self) -> &KeyLine {
+        &self.key_line
+    }
+    #[inline]
+    fn into_key_line(self) -> KeyLine {
+        self.key_line
+    }
+}
+
+impl PartialOrd for SortLine {
+    #[inline]
+    fn partial_cmp(&self, other: &SortLine) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SortLine {
+    #[inline]
+    fn cmp(&self, other: &SortLine) -> Ordering {
+        let r = self.cmp_key(other);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl SortLine {
+    /// compare the keys only.
+    #[inline]
+    fn cmp_key(&self, other: &SortLine) -> Ordering {
+        self.key.cmp(&other.key)
+    }
+    /// compare in the reverse order of the key, and in the order of the line number.
+    #[inline]
+    fn cmp_reverse(&self, other: &SortLine) -> Ordering {
+        let r = other.cmp_key(self);
+        match r {
+            Ordering::Equal => self.num.cmp(&other.num),
+            _ => r,
+        }
+    }
+}
+
+impl PartialEq for SortLine {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.key == other.key
+    }
+}
+
+impl Eq for SortLine {}
+
+#[cfg(test)]
+mod debug {
+    use super::*;
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferWeekday>(), 32);
+        assert_eq!(std::mem::size_of::<SortLine>(), 56);
+    }
+    #[cfg(target_pointer_width = "32")]
+    #[test]
+    fn size_of() {
+        assert_eq!(std::mem::size_of::<SortLinesBufferWeekday>(), 16);
+        assert_eq!(std::mem::size_of::<SortLine>(), 32);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    #[test]
+    fn test_make_weekday() {
+        assert_eq!(make_weekday("Mon", OptFirstWeekday::Monday), Some(0));
+        assert_eq!(make_weekday("MONDAY", OptFirstWeekday::Monday), Some(0));
+        assert_eq!(make_weekday("tues", OptFirstWeekday::Monday), Some(1));
+        assert_eq!(make_weekday("Sun", OptFirstWeekday::Monday), Some(6));
+        assert_eq!(make_weekday("Sun", OptFirstWeekday::Sunday), Some(0));
+        assert_eq!(make_weekday("Sat", OptFirstWeekday::Sunday), Some(6));
+        assert_eq!(make_weekday("Mon.", OptFirstWeekday::Monday), Some(0));
+        assert_eq!(make_weekday(" Tue", OptFirstWeekday::Monday), Some(1));
+        assert_eq!(make_weekday("Mo", OptFirstWeekday::Monday), None);
+    }
+}
@@ -1,3 +1,15 @@
+/// the error without a message, only the exit status is a failure.
+#[derive(Debug)]
+pub struct SilentError;
+
+impl std::fmt::Display for SilentError {
+    fn fmt(&self, _f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        Ok(())
+    }
+}
+
+impl std::error::Error for SilentError {}
+
 pub trait BrokenPipeError {
     fn is_broken_pipe(&self) -> bool;
 }
@@ -13,5 +13,34 @@ pub use self::opt_color_when::OptColorWhen;
 //pub use self::opt_color_when::OptColorWhenParseError;
 
 mod opt_max_buffer_size;
+pub(crate) use self::opt_max_buffer_size::size_prefix_exp;
 pub use self::opt_max_buffer_size::OptMaxBufferSize;
 //pub use self::opt_max_buffer_size::OptMaxBufferSizeParseError;
+
+mod opt_key_def;
+pub use self::opt_key_def::OptKeyDef;
+//pub use self::opt_key_def::OptKeyDefParseError;
+
+mod opt_on_bad_key;
+pub use self::opt_on_bad_key::OptOnBadKey;
+//pub use self::opt_on_bad_key::OptOnBadKeyParseError;
+
+mod opt_first_weekday;
+pub use self::opt_first_weekday::OptFirstWeekday;
+//pub use self::opt_first_weekday::OptFirstWeekdayParseError;
+
+mod opt_month_locale;
+pub use self::opt_month_locale::OptMonthLocale;
+//pub use self::opt_month_locale::OptMonthLocaleParseError;
+
+mod opt_on_unknown;
+pub use self::opt_on_unknown::OptOnUnknown;
+//pub use self::opt_on_unknown::OptOnUnknownParseError;
+
+mod opt_collate_locale;
+pub use self::opt_collate_locale::OptCollateLocale;
+//pub use self::opt_collate_locale::OptCollateLocaleParseError;
+
+mod opt_normalize_form;
+pub use self::opt_normalize_form::OptNormalizeForm;
+//pub use self::opt_normalize_form::OptNormalizeFormParseError;
@@ -1,23 +1,49 @@
 //{{{ OptAccordingToWord
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
 pub enum OptAccordingToWord {
+    Collate,
+    Datetime,
+    DebVersion,
+    Duration,
+    GeneralNumeric,
+    HumanSize,
+    Ip,
+    LenientVersion,
+    List,
     Month,
+    Natural,
     Numeric,
+    Pep440,
+    RpmVersion,
     #[default]
     String,
     Time,
     Version,
+    Weekday,
 }
 
 impl ::std::str::FromStr for OptAccordingToWord {
     type Err = OptAccordingToWordParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         let oc = match s {
+            "collate" => OptAccordingToWord::Collate,
+            "datetime" => OptAccordingToWord::Datetime,
+            "deb-version" => OptAccordingToWord::DebVersion,
+            "duration" => OptAccordingToWord::Duration,
+            "general-numeric" => OptAccordingToWord::GeneralNumeric,
+            "human-size" => OptAccordingToWord::HumanSize,
+            "ip" => OptAccordingToWord::Ip,
+            "lenient-version" => OptAccordingToWord::LenientVersion,
+            "list" => OptAccordingToWord::List,
             "month" => OptAccordingToWord::Month,
+            "natural" => OptAccordingToWord::Natural,
             "numeric" => OptAccordingToWord::Numeric,
+            "pep440" => OptAccordingToWord::Pep440,
+            "rpm-version" => OptAccordingToWord::RpmVersion,
             "string" => OptAccordingToWord::String,
             "time" => OptAccordingToWord::Time,
             "version" => OptAccordingToWord::Version,
+            "weekday" => OptAccordingToWord::Weekday,
             _ => {
                 let s = format!("can not parse '{s}'");
                 return Err(OptAccordingToWordParseError::new(s));
@@ -31,10 +57,23 @@ impl ::std::fmt::Display for OptAccordingToWord {
     fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
         let s = match *self {
             OptAccordingToWord::Numeric => "numeric",
+            OptAccordingToWord::Collate => "collate",
+            OptAccordingToWord::Datetime => "datetime",
+            OptAccordingToWord::DebVersion => "deb-version",
+            OptAccordingToWord::Duration => "duration",
+            OptAccordingToWord::GeneralNumeric => "general-numeric",
+            OptAccordingToWord::HumanSize => "human-size",
+            OptAccordingToWord::Ip => "ip",
+            OptAccordingToWord::LenientVersion => "lenient-version",
+            OptAccordingToWord::List => "list",
             OptAccordingToWord::Month => "month",
+            OptAccordingToWord::Natural => "natural",
+            OptAccordingToWord::Pep440 => "pep440",
+            OptAccordingToWord::RpmVersion => "rpm-version",
             OptAccordingToWord::String => "string",
             OptAccordingToWord::Time => "time",
             OptAccordingToWord::Version => "version",
+            OptAccordingToWord::Weekday => "weekday",
         };
         write!(f, "{s}")
     }
@@ -84,6 +123,61 @@ mod tests {
         assert_eq!(format!("{col}"), "month");
     }
     #[test]
+    fn test_display_datetime() {
+        let col = OptAccordingToWord::Datetime;
+        assert_eq!(format!("{col}"), "datetime");
+    }
+    #[test]
+    fn test_display_deb_version() {
+        let col = OptAccordingToWord::DebVersion;
+        assert_eq!(format!("{col}"), "deb-version");
+    }
+    #[test]
+    fn test_display_duration() {
+        let col = OptAccordingToWord::Duration;
+        assert_eq!(format!("{col}"), "duration");
+    }
+    #[test]
+    fn test_display_general_numeric() {
+        let col = OptAccordingToWord::GeneralNumeric;
+        assert_eq!(format!("{col}"), "general-numeric");
+    }
+    #[test]
+    fn test_display_human_size() {
+        let col = OptAccordingToWord::HumanSize;
+        assert_eq!(format!("{col}"), "human-size");
+    }
+    #[test]
+    fn test_display_ip() {
+        let col = OptAccordingToWord::Ip;
+        assert_eq!(format!("{col}"), "ip");
+    }
+    #[test]
+    fn test_display_lenient_version() {
+        let col = OptAccordingToWord::LenientVersion;
+        assert_eq!(format!("{col}"), "lenient-version");
+    }
+    #[test]
+    fn test_display_list() {
+        let col = OptAccordingToWord::List;
+        assert_eq!(format!("{col}"), "list");
+    }
+    #[test]
+    fn test_display_natural() {
+        let col = OptAccordingToWord::Natural;
+        assert_eq!(format!("{col}"), "natural");
+    }
+    #[test]
+    fn test_display_pep440() {
+        let col = OptAccordingToWord::Pep440;
+        assert_eq!(format!("{col}"), "pep440");
+    }
+    #[test]
+    fn test_display_rpm_version() {
+        let col = OptAccordingToWord::RpmVersion;
+        assert_eq!(format!("{col}"), "rpm-version");
+    }
+    #[test]
     fn test_display_string() {
         let col = OptAccordingToWord::String;
         assert_eq!(format!("{col}"), "string");
@@ -99,6 +193,11 @@ mod tests {
         assert_eq!(format!("{col}"), "version");
     }
     #[test]
+    fn test_display_weekday() {
+        let col = OptAccordingToWord::Weekday;
+        assert_eq!(format!("{col}"), "weekday");
+    }
+    #[test]
     fn test_from_str_numeric() {
         let col: OptAccordingToWord = match FromStr::from_str("numeric") {
             Ok(c) => c,
@@ -119,6 +218,116 @@ mod tests {
         assert_eq!(col, OptAccordingToWord::Month);
     }
     #[test]
+    fn test_from_str_datetime() {
+        let col: OptAccordingToWord = match FromStr::from_str("datetime") {
+            Ok(c) => c,
+            Err(_) => {
+                unreachable!();
+            }
+        };
+        assert_eq!(col, OptAccordingToWord::Datetime);
+    }
+    #[test]
+    fn test_from_str_deb_version() {
+        let col: OptAccordingToWord = match FromStr::from_str("deb-version") {
+            Ok(c) => c,
+            Err(_) => {
+                unreachable!();
+            }
+        };
+        assert_eq!(col, OptAccordingToWord::DebVersion);
+    }
+    #[test]
+    fn test_from_str_duration() {
+        let col: OptAccordingToWord = match FromStr::from_str("duration") {
+            Ok(c) => c,
+            Err(_) => {
+                unreachable!();
+            }
+        };
+        assert_eq!(col, OptAccordingToWord::Duration);
+    }
+    #[test]
+    fn test_from_str_general_numeric() {
+        let col: OptAccordingToWord = match FromStr::from_str("general-numeric") {
+            Ok(c) => c,
+            Err(_) => {
+                unreachable!();
+            }
+        };
+        assert_eq!(col, OptAccordingToWord::GeneralNumeric);
+    }
+    #[test]
+    fn test_from_str_human_size() {
+        let col: OptAccordingToWord = match FromStr::from_str("human-size") {
+            Ok(c) => c,
+            Err(_) => {
+                unreachable!();
+            }
+        };
+        assert_eq!(col, OptAccordingToWord::HumanSize);
+    }
+    #[test]
+    fn test_from_str_ip() {
+        let col: OptAccordingToWord = match FromStr::from_str("ip") {
+            Ok(c) => c,
+            Err(_) => {
+                unreachable!();
+            }
+        };
+        assert_eq!(col, OptAccordingToWord::Ip);
+    }
+    #[test]
+    fn test_from_str_lenient_version() {
+        let col: OptAccordingToWord = match FromStr::from_str("lenient-version") {
+            Ok(c) => c,
+            Err(_) => {
+                unreachable!();
+            }
+        };
+        assert_eq!(col, OptAccordingToWord::LenientVersion);
+    }
+    #[test]
+    fn test_from_str_list() {
+        let col: OptAccordingToWord = match FromStr::from_str("list") {
+            Ok(c) => c,
+            Err(_) => {
+                unreachable!();
+            }
+        };
+        assert_eq!(col, OptAccordingToWord::List);
+    }
+    #[test]
+    fn test_from_str_natural() {
+        let col: OptAccordingToWord = match FromStr::from_str("natural") {
+            Ok(c) => c,
+            Err(_) => {
+                unreachable!();
+            }
+        };
+        assert_eq!(col, OptAccordingToWord::Natural);
+    }
+    #[test]
+    fn test_from_str_pep440() {
+        let col: OptAccordingToWord = match FromStr::from_str("pep440") {
+            Ok(c) => c,
+            Err(_) => {
+                unreachable!();
+            }
+        };
+        assert_eq!(col, OptAccordingToWord::Pep440);
+    }
+    #[test]
+    fn test_from_str_rpm_version() {
+        let col: OptAccordingToWord = match FromStr::from_str("rpm-version") {
+            Ok(c) => c,
+            Err(_) => {
+                unreachable!();
+            }
+        };
+        assert_eq!(col, OptAccordingToWord::RpmVersion);
+    }
+    #[test]
     fn test_from_str_string() {
         let col: OptAccordingToWord = match FromStr::from_str("string") {
             Ok(c) => c,
@@ -149,6 +358,31 @@ mod tests {
         assert_eq!(col, OptAccordingToWord::Version);
     }
     #[test]
+    fn test_from_str_weekday() {
+        let col: OptAccordingToWord = match FromStr::from_str("weekday") {
+            Ok(c) => c,
+            Err(_) => {
+                unreachable!();
+            }
+        };
+        assert_eq!(col, OptAccordingToWord::Weekday);
+    }
+    #[test]
+    fn test_display_collate() {
+        let col = OptAccordingToWord::Collate;
+        assert_eq!(format!("{col}"), "collate");
+    }
+    #[test]
+    fn test_from_str_collate() {
+        let col: OptAccordingToWord = match FromStr::from_str("collate") {
+            Ok(c) => c,
+            Err(_) => {
+                unreachable!();
+            }
+        };
+        assert_eq!(col, OptAccordingToWord::Collate);
+    }
+    #[test]
     fn test_from_str_invalid() {
         let _col: OptAccordingToWord = match FromStr::from_str("other") {
             Ok(_c) => _c,
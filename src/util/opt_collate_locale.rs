@@ -0,0 +1,108 @@
+//{{{ OptCollateLocale
+/// the locale of the built-in tailoring of the collate mode.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptCollateLocale {
+    #[default]
+    Root,
+    Da,
+    Es,
+    Fi,
+    Nb,
+    Sv,
+}
+
+impl ::std::str::FromStr for OptCollateLocale {
+    type Err = OptCollateLocaleParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let oc = match s {
+            "root" => OptCollateLocale::Root,
+            "da" => OptCollateLocale::Da,
+            "es" => OptCollateLocale::Es,
+            "fi" => OptCollateLocale::Fi,
+            "nb" => OptCollateLocale::Nb,
+            "sv" => OptCollateLocale::Sv,
+            _ => {
+                let s = format!("can not parse '{s}'");
+                return Err(OptCollateLocaleParseError::new(s));
+            }
+        };
+        Ok(oc)
+    }
+}
+
+impl ::std::fmt::Display for OptCollateLocale {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        let s = match *self {
+            OptCollateLocale::Root => "root",
+            OptCollateLocale::Da => "da",
+            OptCollateLocale::Es => "es",
+            OptCollateLocale::Fi => "fi",
+            OptCollateLocale::Nb => "nb",
+            OptCollateLocale::Sv => "sv",
+        };
+        write!(f, "{s}")
+    }
+}
+//}}} OptCollateLocale
+
+//{{{ OptCollateLocaleParseError
+#[derive(Debug)]
+pub struct OptCollateLocaleParseError {
+    desc: String,
+}
+
+impl OptCollateLocaleParseError {
+    fn new(s: String) -> OptCollateLocaleParseError {
+        OptCollateLocaleParseError { desc: s }
+    }
+}
+
+impl ::std::fmt::Display for OptCollateLocaleParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        self.desc.fmt(f)
+    }
+}
+
+impl ::std::error::Error for OptCollateLocaleParseError {
+    fn description(&self) -> &str {
+        self.desc.as_str()
+    }
+}
+//}}} OptCollateLocaleParseError
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", OptCollateLocale::Root), "root");
+        assert_eq!(format!("{}", OptCollateLocale::Da), "da");
+        assert_eq!(format!("{}", OptCollateLocale::Es), "es");
+        assert_eq!(format!("{}", OptCollateLocale::Fi), "fi");
+        assert_eq!(format!("{}", OptCollateLocale::Nb), "nb");
+        assert_eq!(format!("{}", OptCollateLocale::Sv), "sv");
+    }
+    #[test]
+    fn test_from_str() {
+        let v: OptCollateLocale = FromStr::from_str("root").unwrap();
+        assert_eq!(v, OptCollateLocale::Root);
+        let v: OptCollateLocale = FromStr::from_str("da").unwrap();
+        assert_eq!(v, OptCollateLocale::Da);
+        let v: OptCollateLocale = FromStr::from_str("es").unwrap();
+        assert_eq!(v, OptCollateLocale::Es);
+        let v: OptCollateLocale = FromStr::from_str("fi").unwrap();
+        assert_eq!(v, OptCollateLocale::Fi);
+        let v: OptCollateLocale = FromStr::from_str("nb").unwrap();
+        assert_eq!(v, OptCollateLocale::Nb);
+        let v: OptCollateLocale = FromStr::from_str("sv").unwrap();
+        assert_eq!(v, OptCollateLocale::Sv);
+    }
+    #[test]
+    fn test_from_str_invalid() {
+        let e = OptCollateLocale::from_str("en").unwrap_err();
+        assert_eq!(e.to_string(), "can not parse 'en'");
+    }
+}
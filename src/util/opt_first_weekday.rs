@@ -0,0 +1,84 @@
+//{{{ OptFirstWeekday
+/// the first day of the week of the weekday mode.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptFirstWeekday {
+    Sunday,
+    #[default]
+    Monday,
+}
+
+impl ::std::str::FromStr for OptFirstWeekday {
+    type Err = OptFirstWeekdayParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let oc = match s {
+            "sunday" => OptFirstWeekday::Sunday,
+            "monday" => OptFirstWeekday::Monday,
+            _ => {
+                let s = format!("can not parse '{s}'");
+                return Err(OptFirstWeekdayParseError::new(s));
+            }
+        };
+        Ok(oc)
+    }
+}
+
+impl ::std::fmt::Display for OptFirstWeekday {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        let s = match *self {
+            OptFirstWeekday::Sunday => "sunday",
+            OptFirstWeekday::Monday => "monday",
+        };
+        write!(f, "{s}")
+    }
+}
+//}}} OptFirstWeekday
+
+//{{{ OptFirstWeekdayParseError
+#[derive(Debug)]
+pub struct OptFirstWeekdayParseError {
+    desc: String,
+}
+
+impl OptFirstWeekdayParseError {
+    fn new(s: String) -> OptFirstWeekdayParseError {
+        OptFirstWeekdayParseError { desc: s }
+    }
+}
+
+impl ::std::fmt::Display for OptFirstWeekdayParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        self.desc.fmt(f)
+    }
+}
+
+impl ::std::error::Error for OptFirstWeekdayParseError {
+    fn description(&self) -> &str {
+        self.desc.as_str()
+    }
+}
+//}}} OptFirstWeekdayParseError
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", OptFirstWeekday::Sunday), "sunday");
+        assert_eq!(format!("{}", OptFirstWeekday::Monday), "monday");
+    }
+    #[test]
+    fn test_from_str() {
+        let v: OptFirstWeekday = FromStr::from_str("sunday").unwrap();
+        assert_eq!(v, OptFirstWeekday::Sunday);
+        let v: OptFirstWeekday = FromStr::from_str("monday").unwrap();
+        assert_eq!(v, OptFirstWeekday::Monday);
+    }
+    #[test]
+    fn test_from_str_invalid() {
+        let e = OptFirstWeekday::from_str("tuesday").unwrap_err();
+        assert_eq!(e.to_string(), "can not parse 'tuesday'");
+    }
+}
@@ -0,0 +1,338 @@
+use super::OptAccordingToWord;
+
+//{{{ OptKeyDef
+/// the key definition of the fields, like a POSIX sort: `F[.C][,F[.C]][:<word>][:r]`
+///
+/// `F` is a field number and `C` is a character position in the field,
+/// both are origin 1. if the end `C` is omitted or `0`, it means the end of
+/// the field. if the end is omitted, it means the end of the line.
+/// `<word>` is the according-to word of this key, and `r` reverses this key.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OptKeyDef {
+    field_st: usize,
+    char_st: usize,
+    field_ed: Option<usize>,
+    char_ed: usize,
+    according_to: Option<OptAccordingToWord>,
+    reverse: bool,
+}
+
+impl OptKeyDef {
+    pub fn new(field_st: usize, char_st: usize, field_ed: Option<usize>, char_ed: usize) -> Self {
+        Self {
+            field_st,
+            char_st,
+            field_ed,
+            char_ed,
+            according_to: None,
+            reverse: false,
+        }
+    }
+    pub fn with_according_to(mut self, a: OptAccordingToWord) -> Self {
+        self.according_to = Some(a);
+        self
+    }
+    pub fn with_reverse(mut self, a: bool) -> Self {
+        self.reverse = a;
+        self
+    }
+    /// the according-to word of this key, if it is specified.
+    pub fn according_to(&self) -> Option<OptAccordingToWord> {
+        self.according_to
+    }
+    /// true if this key is reversed.
+    pub fn is_reverse(&self) -> bool {
+        self.reverse
+    }
+    /// true if this key has the `:<word>` or the `:r`.
+    pub fn has_modifiers(&self) -> bool {
+        self.according_to.is_some() || self.reverse
+    }
+    /// the byte range of the key in the `line`.
+    /// `sep` is the field separator, if it is empty, the fields are
+    /// separated by the blanks and the leading blanks are not in the field.
+    /// `None` if the `line` has not the start field.
+    pub fn key_range(&self, line: &str, sep: &str) -> Option<(usize, usize)> {
+        let (f1_st, f1_ed) = nth_field(line, sep, self.field_st - 1)?;
+        let st = char_pos(line, f1_st, f1_ed, self.char_st - 1);
+        let ed = match self.field_ed {
+            None => line.len(),
+            Some(field_ed) => match nth_field(line, sep, field_ed - 1) {
+                Some((f2_st, f2_ed)) => {
+                    if self.char_ed == 0 {
+                        f2_ed
+                    } else {
+                        char_pos(line, f2_st, f2_ed, self.char_ed)
+                    }
+                }
+                None => line.len(),
+            },
+        };
+        Some((st, ed.max(st)))
+    }
+}
+
+/// the byte range of the `n`th field, origin 0.
+fn nth_field(line: &str, sep: &str, n: usize) -> Option<(usize, usize)> {
+    if sep.is_empty() {
+        let is_blank = |b: u8| b == b' ' || b == b'\t';
+        let bytes = line.as_bytes();
+        let mut i = 0;
+        let mut k = 0;
+        loop {
+            while i < bytes.len() && is_blank(bytes[i]) {
+                i += 1;
+            }
+            if i >= bytes.len() {
+                return None;
+            }
+            let st = i;
+            while i < bytes.len() && !is_blank(bytes[i]) {
+                i += 1;
+            }
+            if k == n {
+                return Some((st, i));
+            }
+            k += 1;
+        }
+    } else {
+        let mut st = 0;
+        let mut k = 0;
+        for (idx, _) in line.match_indices(sep) {
+            if k == n {
+                return Some((st, idx));
+            }
+            st = idx + sep.len();
+            k += 1;
+        }
+        if k == n {
+            Some((st, line.len()))
+        } else {
+            None
+        }
+    }
+}
+
+/// the byte position of the `c`th character in the field, origin 0.
+fn char_pos(line: &str, st: usize, ed: usize, c: usize) -> usize {
+    match line[st..ed].char_indices().nth(c) {
+        Some((i, _)) => st + i,
+        None => ed,
+    }
+}
+
+impl ::std::str::FromStr for OptKeyDef {
+    type Err = OptKeyDefParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parse_pos = |a: &str, is_st: bool| -> Result<(usize, usize), OptKeyDefParseError> {
+            let (f, c) = match a.find('.') {
+                Some(idx) => (&a[..idx], Some(&a[(idx + 1)..])),
+                None => (a, None),
+            };
+            let f = match f.parse::<usize>() {
+                Ok(0) => {
+                    let s = format!("can not parse '{s}': field number is zero");
+                    return Err(OptKeyDefParseError::new(s));
+                }
+                Ok(f) => f,
+                Err(err) => {
+                    let s = format!("can not parse '{s}': {err}");
+                    return Err(OptKeyDefParseError::new(s));
+                }
+            };
+            let c = match c.map(|c| c.parse::<usize>()) {
+                None => {
+                    if is_st {
+                        1
+                    } else {
+                        0
+                    }
+                }
+                Some(Ok(0)) if is_st => {
+                    let s = format!("can not parse '{s}': character offset is zero");
+                    return Err(OptKeyDefParseError::new(s));
+                }
+                Some(Ok(c)) => c,
+                Some(Err(err)) => {
+                    let s = format!("can not parse '{s}': {err}");
+                    return Err(OptKeyDefParseError::new(s));
+                }
+            };
+            Ok((f, c))
+        };
+        let mut it = s.split(':');
+        let pos = it.next().unwrap_or_default();
+        let (st, ed) = match pos.find(',') {
+            Some(idx) => (&pos[..idx], Some(&pos[(idx + 1)..])),
+            None => (pos, None),
+        };
+        let (field_st, char_st) = parse_pos(st, true)?;
+        let (field_ed, char_ed) = match ed {
+            Some(ed) => {
+                let (f, c) = parse_pos(ed, false)?;
+                (Some(f), c)
+            }
+            None => (None, 0),
+        };
+        let mut key_def = OptKeyDef::new(field_st, char_st, field_ed, char_ed);
+        for modifier in it {
+            if modifier == "r" {
+                key_def.reverse = true;
+            } else {
+                match modifier.parse::<OptAccordingToWord>() {
+                    Ok(_) if key_def.according_to.is_some() => {
+                        let s = format!("can not parse '{s}': duplicated word '{modifier}'");
+                        return Err(OptKeyDefParseError::new(s));
+                    }
+                    Ok(a) => key_def.according_to = Some(a),
+                    Err(_) => {
+                        let s = format!("can not parse '{s}': unknown modifier '{modifier}'");
+                        return Err(OptKeyDefParseError::new(s));
+                    }
+                }
+            }
+        }
+        Ok(key_def)
+    }
+}
+
+impl ::std::fmt::Display for OptKeyDef {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        write!(f, "{}.{}", self.field_st, self.char_st)?;
+        if let Some(field_ed) = self.field_ed {
+            write!(f, ",{}.{}", field_ed, self.char_ed)?;
+        }
+        if let Some(according_to) = self.according_to {
+            write!(f, ":{according_to}")?;
+        }
+        if self.reverse {
+            write!(f, ":r")?;
+        }
+        Ok(())
+    }
+}
+//}}} OptKeyDef
+
+//{{{ OptKeyDefParseError
+#[derive(Debug)]
+pub struct OptKeyDefParseError {
+    desc: String,
+}
+
+impl OptKeyDefParseError {
+    fn new(s: String) -> OptKeyDefParseError {
+        OptKeyDefParseError { desc: s }
+    }
+}
+
+impl ::std::fmt::Display for OptKeyDefParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        self.desc.fmt(f)
+    }
+}
+
+impl ::std::error::Error for OptKeyDefParseError {
+    fn description(&self) -> &str {
+        self.desc.as_str()
+    }
+}
+//}}} OptKeyDefParseError
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn test_display() {
+        let v = OptKeyDef::new(3, 1, Some(3), 0);
+        assert_eq!(format!("{v}"), "3.1,3.0");
+        let v = OptKeyDef::new(2, 4, None, 0);
+        assert_eq!(format!("{v}"), "2.4");
+        let v = OptKeyDef::new(2, 1, Some(2), 0)
+            .with_according_to(OptAccordingToWord::Numeric)
+            .with_reverse(true);
+        assert_eq!(format!("{v}"), "2.1,2.0:numeric:r");
+    }
+    #[test]
+    fn test_from_str() {
+        let v: OptKeyDef = FromStr::from_str("3,3").unwrap();
+        assert_eq!(v, OptKeyDef::new(3, 1, Some(3), 0));
+        let v: OptKeyDef = FromStr::from_str("2.4").unwrap();
+        assert_eq!(v, OptKeyDef::new(2, 4, None, 0));
+        let v: OptKeyDef = FromStr::from_str("1.2,3.4").unwrap();
+        assert_eq!(v, OptKeyDef::new(1, 2, Some(3), 4));
+    }
+    #[test]
+    fn test_from_str_modifiers() {
+        let v: OptKeyDef = FromStr::from_str("5,5:month").unwrap();
+        let k = OptKeyDef::new(5, 1, Some(5), 0).with_according_to(OptAccordingToWord::Month);
+        assert_eq!(v, k);
+        assert!(v.has_modifiers());
+        let v: OptKeyDef = FromStr::from_str("2,2:numeric:r").unwrap();
+        assert_eq!(v.according_to(), Some(OptAccordingToWord::Numeric));
+        assert!(v.is_reverse());
+        let v: OptKeyDef = FromStr::from_str("1:r").unwrap();
+        assert_eq!(v.according_to(), None);
+        assert!(v.is_reverse());
+        let v: OptKeyDef = FromStr::from_str("1,1").unwrap();
+        assert!(!v.has_modifiers());
+    }
+    #[test]
+    fn test_from_str_invalid() {
+        let e = OptKeyDef::from_str("0,1").unwrap_err();
+        assert_eq!(e.to_string(), "can not parse '0,1': field number is zero");
+        let e = OptKeyDef::from_str("1.0").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "can not parse '1.0': character offset is zero"
+        );
+        let e = OptKeyDef::from_str("a").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "can not parse 'a': invalid digit found in string"
+        );
+        let e = OptKeyDef::from_str("1,1:foo").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "can not parse '1,1:foo': unknown modifier 'foo'"
+        );
+        let e = OptKeyDef::from_str("1,1:month:numeric").unwrap_err();
+        assert_eq!(
+            e.to_string(),
+            "can not parse '1,1:month:numeric': duplicated word 'numeric'"
+        );
+    }
+    #[test]
+    fn test_key_range_sep() {
+        let line = "Apple:33:3.3:good:Mar";
+        let v = OptKeyDef::new(3, 1, Some(3), 0);
+        assert_eq!(v.key_range(line, ":"), Some((9, 12)));
+        let v = OptKeyDef::new(2, 2, None, 0);
+        assert_eq!(v.key_range(line, ":"), Some((7, 21)));
+        let v = OptKeyDef::new(5, 1, Some(5), 0);
+        assert_eq!(v.key_range(line, ":"), Some((18, 21)));
+        let v = OptKeyDef::new(6, 1, Some(6), 0);
+        assert_eq!(v.key_range(line, ":"), None);
+        let v = OptKeyDef::new(1, 1, Some(1), 2);
+        assert_eq!(v.key_range(line, ":"), Some((0, 2)));
+    }
+    #[test]
+    fn test_key_range_blank() {
+        let line = "  abc \t 12  xyz";
+        let v = OptKeyDef::new(2, 1, Some(2), 0);
+        assert_eq!(v.key_range(line, ""), Some((8, 10)));
+        let v = OptKeyDef::new(1, 2, Some(3), 1);
+        assert_eq!(v.key_range(line, ""), Some((3, 13)));
+        let v = OptKeyDef::new(4, 1, None, 0);
+        assert_eq!(v.key_range(line, ""), None);
+    }
+    #[test]
+    fn test_key_range_empty_field() {
+        let line = "a::c";
+        let v = OptKeyDef::new(2, 1, Some(2), 0);
+        assert_eq!(v.key_range(line, ":"), Some((2, 2)));
+        let v = OptKeyDef::new(3, 1, Some(3), 0);
+        assert_eq!(v.key_range(line, ":"), Some((3, 4)));
+    }
+}
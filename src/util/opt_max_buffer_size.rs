@@ -1,5 +1,4 @@
 use regex::Regex;
-use std::convert::TryInto;
 
 //{{{ OptMaxBufferSize
 #[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
@@ -17,11 +16,28 @@ impl OptMaxBufferSize {
     }
 }
 
+/// the prefixes of the sizes, `K` is 1024 and `Y` is 1024 to the 8th power.
+/// this is shared with `--according-to human-size`.
+pub(crate) const SIZE_PREFIXES: &[u8] = b"KMGTPEZY";
+
+/// the exponent of 1024 of the prefix `c` of `SIZE_PREFIXES`.
+#[inline]
+pub(crate) fn size_prefix_exp(c: u8) -> Option<u32> {
+    SIZE_PREFIXES
+        .iter()
+        .position(|&a| a == c)
+        .map(|idx| idx as u32 + 1)
+}
+
 impl ::std::str::FromStr for OptMaxBufferSize {
     type Err = OptMaxBufferSizeParseError;
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         lazy_static! {
-            static ref RE: Regex = Regex::new("([0-9]+)([KMGTPkmgtp])?[bB]?").unwrap();
+            static ref RE: Regex = {
+                let upper = std::str::from_utf8(SIZE_PREFIXES).unwrap();
+                let lower = upper.to_ascii_lowercase();
+                Regex::new(&format!("([0-9]+)([{upper}{lower}])?[bB]?")).unwrap()
+            };
         }
         if let Some(caps) = RE.captures(s) {
             let digit: usize = if let Some(mat) = caps.get(1) {
@@ -37,21 +53,16 @@ impl ::std::str::FromStr for OptMaxBufferSize {
                 return Err(OptMaxBufferSizeParseError::new(s));
             };
             let unit: usize = if let Some(mat) = caps.get(2) {
-                let un: u64 = match mat.as_str() {
-                    "K" | "k" => 1024,
-                    "M" | "m" => 1024 * 1024,
-                    "G" | "g" => 1024 * 1024 * 1024,
-                    "T" | "t" => 1024 * 1024 * 1024 * 1024,
-                    "P" | "p" => 1024 * 1024 * 1024 * 1024 * 1024,
-                    _ => 1,
-                };
-                if un > usize::MAX.try_into().unwrap()
-                    || digit as u64 * un > usize::MAX.try_into().unwrap()
+                let exp = size_prefix_exp(mat.as_str().as_bytes()[0].to_ascii_uppercase());
+                match exp
+                    .and_then(|exp| 1024usize.checked_pow(exp))
+                    .filter(|un| digit.checked_mul(*un).is_some())
                 {
-                    let s = format!("can not parse '{s}': overflow");
-                    return Err(OptMaxBufferSizeParseError::new(s));
-                } else {
-                    un.try_into().unwrap()
+                    Some(un) => un,
+                    None => {
+                        let s = format!("can not parse '{s}': overflow");
+                        return Err(OptMaxBufferSizeParseError::new(s));
+                    }
                 }
             } else {
                 1
@@ -218,6 +229,14 @@ mod tests {
             }
         };
     }
+    #[cfg(target_pointer_width = "64")]
+    #[test]
+    fn test_from_str_1e() {
+        let col: OptMaxBufferSize = FromStr::from_str("1E").unwrap();
+        assert_eq!(col, OptMaxBufferSize::new(1 << 60));
+        let err = <OptMaxBufferSize as FromStr>::from_str("1Z").unwrap_err();
+        assert_eq!(err.to_string(), "can not parse '1Z': overflow");
+    }
     #[test]
     fn test_from_str_invalid() {
         let _col: OptMaxBufferSize = match FromStr::from_str("other") {
@@ -0,0 +1,96 @@
+//{{{ OptMonthLocale
+/// the locale of the built-in month names.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptMonthLocale {
+    #[default]
+    En,
+    Ja,
+    De,
+    Fr,
+}
+
+impl ::std::str::FromStr for OptMonthLocale {
+    type Err = OptMonthLocaleParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let oc = match s {
+            "en" => OptMonthLocale::En,
+            "ja" => OptMonthLocale::Ja,
+            "de" => OptMonthLocale::De,
+            "fr" => OptMonthLocale::Fr,
+            _ => {
+                let s = format!("can not parse '{s}'");
+                return Err(OptMonthLocaleParseError::new(s));
+            }
+        };
+        Ok(oc)
+    }
+}
+
+impl ::std::fmt::Display for OptMonthLocale {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        let s = match *self {
+            OptMonthLocale::En => "en",
+            OptMonthLocale::Ja => "ja",
+            OptMonthLocale::De => "de",
+            OptMonthLocale::Fr => "fr",
+        };
+        write!(f, "{s}")
+    }
+}
+//}}} OptMonthLocale
+
+//{{{ OptMonthLocaleParseError
+#[derive(Debug)]
+pub struct OptMonthLocaleParseError {
+    desc: String,
+}
+
+impl OptMonthLocaleParseError {
+    fn new(s: String) -> OptMonthLocaleParseError {
+        OptMonthLocaleParseError { desc: s }
+    }
+}
+
+impl ::std::fmt::Display for OptMonthLocaleParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        self.desc.fmt(f)
+    }
+}
+
+impl ::std::error::Error for OptMonthLocaleParseError {
+    fn description(&self) -> &str {
+        self.desc.as_str()
+    }
+}
+//}}} OptMonthLocaleParseError
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", OptMonthLocale::En), "en");
+        assert_eq!(format!("{}", OptMonthLocale::Ja), "ja");
+        assert_eq!(format!("{}", OptMonthLocale::De), "de");
+        assert_eq!(format!("{}", OptMonthLocale::Fr), "fr");
+    }
+    #[test]
+    fn test_from_str() {
+        let v: OptMonthLocale = FromStr::from_str("en").unwrap();
+        assert_eq!(v, OptMonthLocale::En);
+        let v: OptMonthLocale = FromStr::from_str("ja").unwrap();
+        assert_eq!(v, OptMonthLocale::Ja);
+        let v: OptMonthLocale = FromStr::from_str("de").unwrap();
+        assert_eq!(v, OptMonthLocale::De);
+        let v: OptMonthLocale = FromStr::from_str("fr").unwrap();
+        assert_eq!(v, OptMonthLocale::Fr);
+    }
+    #[test]
+    fn test_from_str_invalid() {
+        let e = OptMonthLocale::from_str("es").unwrap_err();
+        assert_eq!(e.to_string(), "can not parse 'es'");
+    }
+}
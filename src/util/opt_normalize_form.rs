@@ -0,0 +1,96 @@
+//{{{ OptNormalizeForm
+/// the normalization form of the keys.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptNormalizeForm {
+    #[default]
+    Nfc,
+    Nfd,
+    Nfkc,
+    Nfkd,
+}
+
+impl ::std::str::FromStr for OptNormalizeForm {
+    type Err = OptNormalizeFormParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let oc = match s {
+            "nfc" => OptNormalizeForm::Nfc,
+            "nfd" => OptNormalizeForm::Nfd,
+            "nfkc" => OptNormalizeForm::Nfkc,
+            "nfkd" => OptNormalizeForm::Nfkd,
+            _ => {
+                let s = format!("can not parse '{s}'");
+                return Err(OptNormalizeFormParseError::new(s));
+            }
+        };
+        Ok(oc)
+    }
+}
+
+impl ::std::fmt::Display for OptNormalizeForm {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        let s = match *self {
+            OptNormalizeForm::Nfc => "nfc",
+            OptNormalizeForm::Nfd => "nfd",
+            OptNormalizeForm::Nfkc => "nfkc",
+            OptNormalizeForm::Nfkd => "nfkd",
+        };
+        write!(f, "{s}")
+    }
+}
+//}}} OptNormalizeForm
+
+//{{{ OptNormalizeFormParseError
+#[derive(Debug)]
+pub struct OptNormalizeFormParseError {
+    desc: String,
+}
+
+impl OptNormalizeFormParseError {
+    fn new(s: String) -> OptNormalizeFormParseError {
+        OptNormalizeFormParseError { desc: s }
+    }
+}
+
+impl ::std::fmt::Display for OptNormalizeFormParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        self.desc.fmt(f)
+    }
+}
+
+impl ::std::error::Error for OptNormalizeFormParseError {
+    fn description(&self) -> &str {
+        self.desc.as_str()
+    }
+}
+//}}} OptNormalizeFormParseError
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", OptNormalizeForm::Nfc), "nfc");
+        assert_eq!(format!("{}", OptNormalizeForm::Nfd), "nfd");
+        assert_eq!(format!("{}", OptNormalizeForm::Nfkc), "nfkc");
+        assert_eq!(format!("{}", OptNormalizeForm::Nfkd), "nfkd");
+    }
+    #[test]
+    fn test_from_str() {
+        let v: OptNormalizeForm = FromStr::from_str("nfc").unwrap();
+        assert_eq!(v, OptNormalizeForm::Nfc);
+        let v: OptNormalizeForm = FromStr::from_str("nfd").unwrap();
+        assert_eq!(v, OptNormalizeForm::Nfd);
+        let v: OptNormalizeForm = FromStr::from_str("nfkc").unwrap();
+        assert_eq!(v, OptNormalizeForm::Nfkc);
+        let v: OptNormalizeForm = FromStr::from_str("nfkd").unwrap();
+        assert_eq!(v, OptNormalizeForm::Nfkd);
+    }
+    #[test]
+    fn test_from_str_invalid() {
+        let e = OptNormalizeForm::from_str("NFC").unwrap_err();
+        assert_eq!(e.to_string(), "can not parse 'NFC'");
+    }
+}
@@ -0,0 +1,102 @@
+//{{{ OptOnBadKey
+/// the policy for the lines whose key is not found or can not be parsed.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptOnBadKey {
+    #[default]
+    Error,
+    First,
+    Last,
+    Skip,
+    KeepInPlace,
+}
+
+impl ::std::str::FromStr for OptOnBadKey {
+    type Err = OptOnBadKeyParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let oc = match s {
+            "error" => OptOnBadKey::Error,
+            "first" => OptOnBadKey::First,
+            "last" => OptOnBadKey::Last,
+            "skip" => OptOnBadKey::Skip,
+            "keep-in-place" => OptOnBadKey::KeepInPlace,
+            _ => {
+                let s = format!("can not parse '{s}'");
+                return Err(OptOnBadKeyParseError::new(s));
+            }
+        };
+        Ok(oc)
+    }
+}
+
+impl ::std::fmt::Display for OptOnBadKey {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        let s = match *self {
+            OptOnBadKey::Error => "error",
+            OptOnBadKey::First => "first",
+            OptOnBadKey::Last => "last",
+            OptOnBadKey::Skip => "skip",
+            OptOnBadKey::KeepInPlace => "keep-in-place",
+        };
+        write!(f, "{s}")
+    }
+}
+//}}} OptOnBadKey
+
+//{{{ OptOnBadKeyParseError
+#[derive(Debug)]
+pub struct OptOnBadKeyParseError {
+    desc: String,
+}
+
+impl OptOnBadKeyParseError {
+    fn new(s: String) -> OptOnBadKeyParseError {
+        OptOnBadKeyParseError { desc: s }
+    }
+}
+
+impl ::std::fmt::Display for OptOnBadKeyParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        self.desc.fmt(f)
+    }
+}
+
+impl ::std::error::Error for OptOnBadKeyParseError {
+    fn description(&self) -> &str {
+        self.desc.as_str()
+    }
+}
+//}}} OptOnBadKeyParseError
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", OptOnBadKey::Error), "error");
+        assert_eq!(format!("{}", OptOnBadKey::First), "first");
+        assert_eq!(format!("{}", OptOnBadKey::Last), "last");
+        assert_eq!(format!("{}", OptOnBadKey::Skip), "skip");
+        assert_eq!(format!("{}", OptOnBadKey::KeepInPlace), "keep-in-place");
+    }
+    #[test]
+    fn test_from_str() {
+        let v: OptOnBadKey = FromStr::from_str("error").unwrap();
+        assert_eq!(v, OptOnBadKey::Error);
+        let v: OptOnBadKey = FromStr::from_str("first").unwrap();
+        assert_eq!(v, OptOnBadKey::First);
+        let v: OptOnBadKey = FromStr::from_str("last").unwrap();
+        assert_eq!(v, OptOnBadKey::Last);
+        let v: OptOnBadKey = FromStr::from_str("skip").unwrap();
+        assert_eq!(v, OptOnBadKey::Skip);
+        let v: OptOnBadKey = FromStr::from_str("keep-in-place").unwrap();
+        assert_eq!(v, OptOnBadKey::KeepInPlace);
+    }
+    #[test]
+    fn test_from_str_invalid() {
+        let e = OptOnBadKey::from_str("other").unwrap_err();
+        assert_eq!(e.to_string(), "can not parse 'other'");
+    }
+}
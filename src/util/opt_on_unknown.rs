@@ -0,0 +1,90 @@
+//{{{ OptOnUnknown
+/// the policy for the values that are not in the order of the list mode.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OptOnUnknown {
+    #[default]
+    Error,
+    First,
+    Last,
+}
+
+impl ::std::str::FromStr for OptOnUnknown {
+    type Err = OptOnUnknownParseError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let oc = match s {
+            "error" => OptOnUnknown::Error,
+            "first" => OptOnUnknown::First,
+            "last" => OptOnUnknown::Last,
+            _ => {
+                let s = format!("can not parse '{s}'");
+                return Err(OptOnUnknownParseError::new(s));
+            }
+        };
+        Ok(oc)
+    }
+}
+
+impl ::std::fmt::Display for OptOnUnknown {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        let s = match *self {
+            OptOnUnknown::Error => "error",
+            OptOnUnknown::First => "first",
+            OptOnUnknown::Last => "last",
+        };
+        write!(f, "{s}")
+    }
+}
+//}}} OptOnUnknown
+
+//{{{ OptOnUnknownParseError
+#[derive(Debug)]
+pub struct OptOnUnknownParseError {
+    desc: String,
+}
+
+impl OptOnUnknownParseError {
+    fn new(s: String) -> OptOnUnknownParseError {
+        OptOnUnknownParseError { desc: s }
+    }
+}
+
+impl ::std::fmt::Display for OptOnUnknownParseError {
+    fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+        self.desc.fmt(f)
+    }
+}
+
+impl ::std::error::Error for OptOnUnknownParseError {
+    fn description(&self) -> &str {
+        self.desc.as_str()
+    }
+}
+//}}} OptOnUnknownParseError
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(format!("{}", OptOnUnknown::Error), "error");
+        assert_eq!(format!("{}", OptOnUnknown::First), "first");
+        assert_eq!(format!("{}", OptOnUnknown::Last), "last");
+    }
+    #[test]
+    fn test_from_str() {
+        let v: OptOnUnknown = FromStr::from_str("error").unwrap();
+        assert_eq!(v, OptOnUnknown::Error);
+        let v: OptOnUnknown = FromStr::from_str("first").unwrap();
+        assert_eq!(v, OptOnUnknown::First);
+        let v: OptOnUnknown = FromStr::from_str("last").unwrap();
+        assert_eq!(v, OptOnUnknown::Last);
+    }
+    #[test]
+    fn test_from_str_invalid() {
+        let e = OptOnUnknown::from_str("skip").unwrap_err();
+        assert_eq!(e.to_string(), "can not parse 'skip'");
+    }
+}
@@ -7,32 +7,90 @@ macro_rules! help_msg {
             indoc::indoc!(
                 r#"
             Usage:
-              aki-resort [options]
+              aki-resort [options] [<file>...]
 
             sort lines of text.
 
             Ordering options:
               -r, --reverse                 reverse the result of comparisons
+              -f, --ignore-case             fold the case of the strings to compare them
+                  --normalize <form>        normalize the keys of string and collate by <form>
                   --according-to <word>     sort according to <word>
+                  --grouping-char <c>       the grouping character of general-numeric
+                  --decimal-char <c>        the decimal character of general-numeric
+                  --date-format <fmt>       the format of datetime
+                  --ipv6-first              sort IPv6 before IPv4 of ip
+                  --first-weekday <day>     the first day of the week of weekday
+                  --month-locale <loc>      the locale of the month names of month
+                  --month-names <file>      read the month names of month from <file>
+                  --order <list>            the order of list, the comma separated values
+                  --order-file <file>       read the order of list from <file>
+                  --on-unknown <unknown>    sort the values not in the order by <unknown>
+                  --collate-locale <lang>   the locale tailoring of collate
               -h, --head <num>              unsort the first <num> lines.
               -t, --tail <num>              unsort the last <num> lines.
+                  --on-bad-key <policy>     the lines with a bad key are output by <policy>
 
             Other options:
                   --color <when>            use markers to highlight the matching strings
               -e, --exp <exp>               regular expression. sort by the entires match
-              -u, --unique                  output only the first line of an equal
+              -k, --key <keydef>            sort by the fields of <keydef>, can be repeated
+                  --field-separator <sep>   use <sep> as the field separator
+              -u, --unique                  output only the first line of an equal key
+                  --unique-line             output only the first line of an equal line in a row
+              -c, --check                   check whether the input is sorted, do not sort
+              -C, --check-quiet             like --check, but do not report the first disorder
+              -m, --merge                   merge the already sorted files, do not sort
                   --max-buffer <size>       max buffer size
+              -S, --buffer-size <size>      sort by chunks of <size> with temporary files
+              -T, --temp-dir <dir>          directory of temporary files
 
               -H, --help        display this help and exit
               -V, --version     display version information and exit
               -X <x-options>    x options. try -X help
 
+            Arguments:
+              <file>    utf-8 encoded plain text file. '-' means the standard input.
+                        if no <file> is given, read the standard input.
+
             Option Parameters:
-              <word>    'month', 'numeric', 'string', 'time', 'version'
+              <form>    'nfc', 'nfd', 'nfkc', or 'nfkd'. the keys are compared after normalized
+                        by the Unicode normalization form, and the output lines are not changed.
+              <word>    'collate', 'datetime', 'deb-version', 'duration', 'general-numeric',
+                        'human-size', 'ip', 'lenient-version', 'list', 'month', 'natural',
+                        'numeric', 'pep440', 'rpm-version', 'string', 'time', 'version', 'weekday'
+              <c>       a character. the default of --decimal-char is '.', and the default of
+                        --grouping-char is ',', or none if --decimal-char is ','.
+                        an empty --grouping-char means no grouping.
+              <fmt>     strftime-like format, ex) '%Y/%m/%d %H:%M'. the time zone and the time
+                        can be omitted, then they are UTC and 00:00. without --date-format,
+                        ISO 8601, RFC 2822, syslog and common log format are parsed.
+              <day>     'sunday' or 'monday'. the default is 'monday'.
+              <loc>     'en', 'ja', 'de', or 'fr'. the default is 'en'.
+              <file>    a file of the month names or the order. a line has the names of
+                        a month, from January to December, or the values of a position of
+                        the order, separated by ','. the lines of '#' are comments.
+              <list>    the comma separated values, ex) 'low,medium,high,critical'.
+                        the values are compared case-insensitively.
+              <unknown> 'error', 'first', or 'last'. the default is 'error'. an unknown
+                        value is a bad key by 'error'.
+              <lang>    'root', 'da', 'es', 'fi', 'nb', or 'sv'. the default is 'root', the order
+                        of the Unicode Collation Algorithm without a tailoring.
+              <policy>  'error', 'first', 'last', 'skip', or 'keep-in-place'. a bad key can not
+                        be parsed, or is not found except 'error'. the line number of a bad key
+                        is displayed by 'error' and 'skip'.
               <when>    'always', 'never', or 'auto'
               <exp>     regular expression, sort by the entires match.
+              <keydef>  F[.C][,F[.C]][:<word>][:r]: F is a field number and C is a character
+                        position in the field, origin 1. ex) '3,3' is the 3rd field.
+                        <word> is the according-to of the key, and 'r' reverses the key.
+                        the keys are compared in order of the options.
+              <sep>     a field separator string. the default is the blanks.
               <size>    if a reading size is more than <size>, then it is not output,
-                        quit and display error message.
+                        quit and display error message. (--max-buffer)
+                        if a chunk of lines is more than <size>, then it is sorted and
+                        written to a temporary file, and they are merged. (--buffer-size)
+              <dir>     the default is the system temporary directory.
 
             Environments:
               AKI_RESORT_COLOR_SEQ_ST   color start sequence specified by ansi
@@ -49,6 +107,18 @@ macro_rules! help_msg {
                 cat file1.txt | aki-resort -e "[^:]+:[^:]+:([0-9.]+):" --according-to version
               This sort via 1st chunk of numeric time character according to time:
                 cat file1.txt | aki-resort -e "([0-9]+:([0-9]+:)?[0-9]+(.[0-9]+)?)" --according-to time
+              This sort via 3rd field of ':' separated according to version:
+                cat file1.txt | aki-resort -k 3,3 --field-separator ":" --according-to version
+              This sort via 5th field according to month, then 2nd field according to numeric in reverse:
+                cat file1.txt | aki-resort --field-separator ":" -k 5,5:month -k 2,2:numeric:r
+              This sort the concatenation of files and the standard input:
+                cat file3.txt | aki-resort file1.txt file2.txt -
+              This sort a large file with temporary files by chunks of 64MiB:
+                aki-resort -S 64M large.txt
+              This check whether a file is sorted according to numeric:
+                aki-resort -c --according-to numeric file1.txt
+              This merge the already sorted files according to numeric:
+                aki-resort -m --according-to numeric sorted1.txt sorted2.txt
             "#
             ),
             "\n",
@@ -120,6 +190,34 @@ macro_rules! fixture_fruit_footer {
     };
 }
 
+#[allow(unused_macros)]
+macro_rules! fixture_month_names {
+    () => {
+        "fixtures/month_names.txt"
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! fixture_order_levels {
+    () => {
+        "fixtures/order_levels.txt"
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! fixture_sorted_1 {
+    () => {
+        "fixtures/sorted_1.txt"
+    };
+}
+
+#[allow(unused_macros)]
+macro_rules! fixture_sorted_2 {
+    () => {
+        "fixtures/sorted_2.txt"
+    };
+}
+
 #[allow(unused_macros)]
 macro_rules! fixture_time {
     () => {
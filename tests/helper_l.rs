@@ -18,6 +18,7 @@ macro_rules! do_execute {
         let r = execute(&sioe, &program, $args);
         match r {
             Ok(_) => {}
+            Err(ref err) if err.downcast_ref::<SilentError>().is_some() => {}
             Err(ref err) => {
                 let _ = sioe.pg_err().write_line(format!("{program}: {err:#}"));
             }
@@ -39,6 +40,7 @@ macro_rules! do_execute {
         let r = execute_with_env(&sioe, &program, $args, $env);
         match r {
             Ok(_) => {}
+            Err(ref err) if err.downcast_ref::<SilentError>().is_some() => {}
             Err(ref err) => {
                 let _ = sioe.pg_err().write_line(format!("{program}: {err:#}"));
             }
@@ -162,328 +162,2426 @@ mod test_1_regex_options_e {
     }
 }
 
+mod test_1_merge_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_merge() {
+        let in_w = "0 c\n3 c\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "-m",
+                "-k",
+                "1,1",
+                "--according-to",
+                "numeric",
+                fixture_sorted_1!(),
+                fixture_sorted_2!(),
+                "-",
+            ],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "0 c\n1 a\n2 b\n3 a\n3 b\n3 c\n5 a\n10 b\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_merge_unique() {
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "--merge",
+                "-u",
+                "-k",
+                "1,1",
+                "--according-to",
+                "numeric",
+                fixture_sorted_1!(),
+                fixture_sorted_2!(),
+            ],
+            b"" as &[u8],
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "1 a\n2 b\n3 a\n5 a\n10 b\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_merge_reverse() {
+        let in_w = "9 c\n4 c\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-m", "-r", "--according-to", "numeric", "-e", "[0-9]+", "-"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "9 c\n4 c\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_merge_stdin_twice() {
+        let in_w = "c\nb\na\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-m", "-r", "-", "-"], in_w.as_bytes());
+        assert!(oup
+            .stderr
+            .contains("merge: can not read the standard input more than once"));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_merge_unsorted() {
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "-m",
+                "--field-separator",
+                ":",
+                "-k",
+                "2,2",
+                "--according-to",
+                "numeric",
+                fixture_fruit!(),
+            ],
+            b"" as &[u8],
+        );
+        assert_eq!(
+            oup.stderr,
+            concat!(
+                program_name!(),
+                ": fixtures/fruit.txt: line 3: disorder: '4' after '222'\n"
+            )
+        );
+        assert_eq!(oup.stdout, "Apple:33:3.3:good:Mar\n");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_merge_with_head() {
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-m", "-h", "1", fixture_sorted_1!()],
+            b"" as &[u8],
+        );
+        assert!(oup
+            .stderr
+            .contains("merge: can not be used with --head or --tail"));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+}
+
+mod test_1_check_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_sorted() {
+        let in_w = "1\n2\n2\n10\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-c", "--according-to", "numeric"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_disorder() {
+        let in_w = "1\n2\n2\n10\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["--check"], in_w.as_bytes());
+        assert_eq!(
+            oup.stderr,
+            concat!(program_name!(), ": line 4: disorder: '10' after '2'\n")
+        );
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_disorder_quiet() {
+        let in_w = "1\n2\n2\n10\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-C"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_duplicate_unique() {
+        let in_w = "1\n2\n2.0\n10\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-c", "-u", "--according-to", "numeric"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(
+            oup.stderr,
+            concat!(program_name!(), ": line 3: duplicate: '2.0' after '2'\n")
+        );
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_reverse_equal_keys() {
+        let in_w = "3 c\n2 b\n2 a\n1 d\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-c", "-r", "-k", "1,1", "--according-to", "numeric"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_header_key() {
+        let in_w = "name:num\nb:1\na:2\nc:3\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "-c",
+                "-h",
+                "1",
+                "--field-separator",
+                ":",
+                "-k",
+                "2,2",
+                "--according-to",
+                "numeric",
+            ],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_bad_key() {
+        let in_w = "1\nx\n2\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-c", "--according-to", "numeric"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(
+            oup.stderr,
+            concat!(
+                program_name!(),
+                ": line 2: (0,1):'x': invalid float literal\n"
+            )
+        );
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_more_than_one_input() {
+        let in_w = "1\n2\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-c", "-", "-"], in_w.as_bytes());
+        assert!(oup
+            .stderr
+            .contains("check: can not be used with more than one input"));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+}
+
+mod test_1_on_bad_key_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    const IN_W: &str = "3 c\nx\n1 a\nfoo bar\n2 b\n";
+    //
+    #[test]
+    fn test_error() {
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-e", "^[0-9]+", "--according-to", "numeric"],
+            IN_W.as_bytes(),
+        );
+        assert_eq!(
+            oup.stderr,
+            concat!(
+                program_name!(),
+                ": line 2: (0,1):'x': invalid float literal\n"
+            )
+        );
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_first() {
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "-e",
+                "^[0-9]+",
+                "--according-to",
+                "numeric",
+                "--on-bad-key",
+                "first",
+            ],
+            IN_W.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "x\nfoo bar\n1 a\n2 b\n3 c\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_last() {
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "-e",
+                "^[0-9]+",
+                "--according-to",
+                "numeric",
+                "--on-bad-key",
+                "last",
+                "-r",
+            ],
+            IN_W.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "3 c\n2 b\n1 a\nx\nfoo bar\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_skip() {
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "-e",
+                "^[0-9]+",
+                "--according-to",
+                "numeric",
+                "--on-bad-key",
+                "skip",
+            ],
+            IN_W.as_bytes(),
+        );
+        assert_eq!(
+            oup.stderr,
+            concat!(
+                program_name!(),
+                ": line 2: skipped: 'x': the key is not found\n",
+                program_name!(),
+                ": line 4: skipped: 'foo bar': the key is not found\n",
+            )
+        );
+        assert_eq!(oup.stdout, "1 a\n2 b\n3 c\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_skip_parse_error() {
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "-k",
+                "1,1",
+                "--according-to",
+                "numeric",
+                "--on-bad-key",
+                "skip",
+                "-h",
+                "1",
+            ],
+            IN_W.as_bytes(),
+        );
+        assert_eq!(
+            oup.stderr,
+            concat!(
+                program_name!(),
+                ": line 2: skipped: (0,1):'x': invalid float literal\n",
+                program_name!(),
+                ": line 4: skipped: (0,3):'foo bar': invalid float literal\n",
+            )
+        );
+        assert_eq!(oup.stdout, "3 c\n1 a\n2 b\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_keep_in_place() {
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "-e",
+                "^[0-9]+",
+                "--according-to",
+                "numeric",
+                "--on-bad-key",
+                "keep-in-place",
+            ],
+            IN_W.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "1 a\nx\n2 b\nfoo bar\n3 c\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_keep_in_place_buffer_size() {
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "-e",
+                "^[0-9]+",
+                "--according-to",
+                "numeric",
+                "--on-bad-key",
+                "keep-in-place",
+                "-S",
+                "4",
+            ],
+            IN_W.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "1 a\nx\n2 b\nfoo bar\n3 c\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_invalid() {
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["--on-bad-key", "other"], b"" as &[u8]);
+        assert!(oup.stderr.contains("on-bad-key: can not parse 'other'"));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+}
+
+mod test_1_unique_key_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_unique_numeric_key() {
+        let in_w = "1.0 a\n1 b\n2 c\n1.00 d\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-u", "-e", "^[^ ]+", "--according-to", "numeric"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "1.0 a\n2 c\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_unique_numeric_key_reverse() {
+        let in_w = "1.0 a\n1 b\n2 c\n1.00 d\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-u", "-r", "-e", "^[^ ]+", "--according-to", "numeric"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "2 c\n1.0 a\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_unique_reverse_first_line() {
+        let in_w = "a 1\na 2\nb 3\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-k", "1,1", "-r", "-u"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "b 3\na 1\n");
+        assert!(oup.status.success());
+        //
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-k", "1,1", "-r", "-u", "-S", "1"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "b 3\na 1\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_unique_multi_keys() {
+        let in_w = "a:1:x\nb:01:x\nc:1:y\nd:2:x\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "-u",
+                "--field-separator",
+                ":",
+                "-k",
+                "2,2:numeric",
+                "-k",
+                "3,3",
+            ],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "a:1:x\nc:1:y\nd:2:x\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_unique_buffer_size() {
+        let in_w = "1.0 a\n1 b\n2 c\n1.00 d\n3 e\n2.0 f\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-u", "-k", "1,1", "--according-to", "numeric", "-S", "5"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "1.0 a\n2 c\n3 e\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_unique_line() {
+        let in_w = "1.0 a\n1.0 a\n1 b\n2 c\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--unique-line", "-e", "^[^ ]+", "--according-to", "numeric"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "1.0 a\n1 b\n2 c\n");
+        assert!(oup.status.success());
+    }
+}
+
+mod test_1_multi_keys_e {
+    use exec_target::exec_target_with_env_in;
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    const IN_W: &str = "a:3:Mar\nb:10:Jan\nc:2:Mar\nd:10:Jan\ne:7:Feb\nf:3:Mar\n";
+    //
+    #[test]
+    fn test_month_numeric_r_string() {
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "--field-separator",
+                ":",
+                "-k",
+                "3,3:month",
+                "-k",
+                "2,2:numeric:r",
+                "-k",
+                "1,1",
+            ],
+            IN_W.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            "b:10:Jan\nd:10:Jan\ne:7:Feb\na:3:Mar\nf:3:Mar\nc:2:Mar\n"
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_default_according_to() {
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "--field-separator",
+                ":",
+                "--according-to",
+                "numeric",
+                "-k",
+                "2,2",
+                "-k",
+                "1,1:string:r",
+            ],
+            IN_W.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            "c:2:Mar\nf:3:Mar\na:3:Mar\ne:7:Feb\nd:10:Jan\nb:10:Jan\n"
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_reverse_all() {
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "--field-separator",
+                ":",
+                "-r",
+                "-k",
+                "3,3:month",
+                "-k",
+                "1,1:r",
+            ],
+            IN_W.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            "a:3:Mar\nc:2:Mar\nf:3:Mar\ne:7:Feb\nb:10:Jan\nd:10:Jan\n"
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_color_first_key() {
+        let oup = exec_target_with_env_in(
+            TARGET_EXE_PATH,
+            [
+                "--field-separator",
+                ":",
+                "-k",
+                "3,3:month",
+                "-k",
+                "2,2:numeric",
+                "--color",
+                "always",
+            ],
+            env_1!(),
+            "a:3:Mar\nb:10:Jan\nc:2:Mar\n".as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "b:10:<S>Jan<E>\nc:2:<S>Mar<E>\na:3:<S>Mar<E>\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_buffer_size() {
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "--field-separator",
+                ":",
+                "-k",
+                "3,3:month",
+                "-k",
+                "2,2:numeric:r",
+                "-S",
+                "10",
+            ],
+            IN_W.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            "b:10:Jan\nd:10:Jan\ne:7:Feb\na:3:Mar\nf:3:Mar\nc:2:Mar\n"
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_invalid_modifier() {
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-k", "1,1:foo"], b"" as &[u8]);
+        assert!(oup.stderr.contains("unknown modifier 'foo'"));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_missing_field() {
+        let in_w = "b x\nzz\na y\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-k", "2,2"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "b x\na y\nzz\n");
+        assert!(oup.status.success());
+        //
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-k", "2,2:string"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "b x\na y\nzz\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_missing_second_key() {
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--on-bad-key", "last", "-k", "1,1", "-k", "3,3"],
+            "b 1 z\na 2\nc 3 y\n".as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "b 1 z\nc 3 y\na 2\n");
+        assert!(oup.status.success());
+    }
+}
+
+mod test_1_key_options_e {
+    use exec_target::exec_target_with_env_in;
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_key_version() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "-k",
+                "3,3",
+                "--field-separator",
+                ":",
+                "--according-to",
+                "version",
+            ],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Orange:222:1.1.2:good:Jan\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Cherry:4:4:good:Oct\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_key_month_color() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_env_in(
+            TARGET_EXE_PATH,
+            [
+                "-k",
+                "5",
+                "--field-separator",
+                ":",
+                "--according-to",
+                "month",
+                "--color",
+                "always",
+            ],
+            env_1!(),
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Orange:222:1.1.2:good:<S>Jan<E>\n",
+                "Apple:33:3.3:good:<S>Mar<E>\n",
+                "Kiwi:1111:1.1.11:good:<S>Jun<E>\n",
+                "Cherry:4:4:good:<S>Oct<E>\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_key_blank_separated() {
+        let in_w = "a  10 x\nb 9 y\nc\t 100 z\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-k", "2,2", "--according-to", "numeric"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "b 9 y\na  10 x\nc\t 100 z\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_key_char_position() {
+        let in_w = "x:b3\ny:a2\nz:c1\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-k", "2.2,2.2", "--field-separator", ":"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "z:c1\ny:a2\nx:b3\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_key_invalid() {
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-k", "0,1"], b"" as &[u8]);
+        assert!(oup.stderr.contains("key: can not parse '0,1'"));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_key_with_exp() {
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-k", "1", "-e", "a"], b"" as &[u8]);
+        assert!(oup.stderr.contains("key: can not be used with --exp"));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+}
+
+mod test_1_file_args_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_file() {
+        let oup = exec_target_with_in(TARGET_EXE_PATH, [fixture_fruit!()], b"" as &[u8]);
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Apple:33:3.3:good:Mar\n",
+                "Cherry:4:4:good:Oct\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Orange:222:1.1.2:good:Jan\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_files_and_stdin() {
+        let in_w = "Banana:5:5:good:Feb\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [fixture_fruit!(), "-", fixture_fruit!()],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Apple:33:3.3:good:Mar\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Banana:5:5:good:Feb\n",
+                "Cherry:4:4:good:Oct\n",
+                "Cherry:4:4:good:Oct\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Orange:222:1.1.2:good:Jan\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_stdin_only() {
+        let in_w = "b\na\nc\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "a\nb\nc\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_dash_as_option_argument() {
+        let in_w = "b-2\na-3\nc-1\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-e", "-(.)", "-"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "c-1\nb-2\na-3\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_file_not_found() {
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [fixture_fruit!(), "fixtures/not_found.txt"],
+            b"" as &[u8],
+        );
+        assert!(oup
+            .stderr
+            .starts_with(concat!(program_name!(), ": fixtures/not_found.txt: ")));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_file_invalid_utf8() {
+        let oup = exec_target_with_in(TARGET_EXE_PATH, [fixture_invalid_utf8!()], b"" as &[u8]);
+        assert_eq!(
+            oup.stderr,
+            concat!(
+                program_name!(),
+                ": ",
+                fixture_invalid_utf8!(),
+                ": stream did not contain valid UTF-8\n",
+            )
+        );
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+}
+
 mod test_2_string_e {
     use exec_target::exec_target_with_in;
     const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
     //
     #[test]
-    fn test_t1() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let oup = exec_target_with_in(TARGET_EXE_PATH, &[] as &[&str], in_w.as_bytes());
-        assert_eq!(oup.stderr, "");
+    fn test_t1() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_in(TARGET_EXE_PATH, &[] as &[&str], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Apple:33:3.3:good:Mar\n",
+                "Cherry:4:4:good:Oct\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Orange:222:1.1.2:good:Jan\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t2() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-r"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Orange:222:1.1.2:good:Jan\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Cherry:4:4:good:Oct\n",
+                "Apple:33:3.3:good:Mar\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t3() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-e", "[0-9]+"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Cherry:4:4:good:Oct\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t4() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-e", "[0-9]+", "-r"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Cherry:4:4:good:Oct\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t5() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let in_w = in_w.clone() + &in_w;
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-e", "[0-9]+"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Cherry:4:4:good:Oct\n",
+                "Cherry:4:4:good:Oct\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t6_header() {
+        let in_w = std::fs::read_to_string(fixture_fruit_header!()).unwrap();
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-e", "[0-9]+", "-h", "1"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "name:number:version:nice:month\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Cherry:4:4:good:Oct\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t6_footer() {
+        let in_w = std::fs::read_to_string(fixture_fruit_footer!()).unwrap();
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-e", "[0-9]+", "-t", "1"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Cherry:4:4:good:Oct\n",
+                "This is footer line. 1\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+}
+
+mod test_2_string_ignore_case_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = "cherry\nBanana\napple\nÄpfel\nDate\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-f"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "apple\nBanana\ncherry\nDate\nÄpfel\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t2_unique() {
+        let in_w = "Straße\nstrasse\nSTRASSE\nΣΊΣΥΦΟΣ\nσίσυφος\napple\nAPPLE\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["--ignore-case", "-u"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "apple\nStraße\nΣΊΣΥΦΟΣ\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t3_keydef() {
+        let in_w = "2 b\n1 B\n1 a\n2 A\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-f", "-k", "2,2", "-k", "1,1:numeric:r"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "2 A\n1 a\n2 b\n1 B\n");
+        assert!(oup.status.success());
+    }
+}
+
+mod test_2_string_normalize_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_t1_unique() {
+        let in_w = "cafe\u{301}\nzoo\ncaf\u{e9}\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--normalize", "nfc", "-u"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "cafe\u{301}\nzoo\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t2_nfd() {
+        let in_w = "caf\u{e9}\ncafe\u{301}\ncafe\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["--normalize", "nfd"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "cafe\ncaf\u{e9}\ncafe\u{301}\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t3_nfkc_ignore_case() {
+        let in_w = "\u{fb01}le\nFILE\nfile\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-f", "--normalize", "nfkc", "-u"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "\u{fb01}le\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t4_keydef() {
+        let in_w = "1 cafe\u{301}\n2 caf\u{e9}\n3 bar\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-k", "2,2", "--normalize", "nfc", "-u"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "3 bar\n1 cafe\u{301}\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t5_collate() {
+        let in_w = "cafe\u{301}\ncaf\u{e9}\ncafe\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "collate", "--normalize", "nfc", "-u"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "cafe\ncafe\u{301}\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t6_invalid() {
+        let in_w = "a\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["--normalize", "NFC"], in_w.as_bytes());
+        assert!(oup.stderr.contains("can not parse 'NFC'"));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t7_nfc_nfd_ignore_case() {
+        let in_w = "\u{c9}lan\nE\u{301}lan\n\u{e9}LAN\nElan\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-f", "--normalize", "nfc", "-u"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "Elan\n\u{c9}lan\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t8_ignore_case_reorder() {
+        let in_w = "\u{391}\u{345}\u{301} a\n\u{3b1}\u{301}\u{345} b\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-k", "1,1", "-f", "--normalize", "nfc", "-u"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "\u{391}\u{345}\u{301} a\n");
+        assert!(oup.status.success());
+    }
+}
+
+mod test_2_collate_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = "zebra\néclair\nÉclair\neclair\nApple\napple\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "collate"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "apple\nApple\neclair\néclair\nÉclair\nzebra\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t2_reverse() {
+        let in_w = "かき\nカキ\nあめ\nco-op\ncoop\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "collate", "-r"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "カキ\nかき\nあめ\ncoop\nco-op\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t3_locale() {
+        let in_w = "öl\nzoo\nål\näpple\napple\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "collate", "--collate-locale", "sv"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "apple\nzoo\nål\näpple\nöl\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t4_keydef() {
+        let in_w = "2 élan\n1 elan\n3 zoo\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-k", "2,2:collate:r"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "3 zoo\n2 élan\n1 elan\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t5_invalid_locale() {
+        let in_w = "a\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "collate", "--collate-locale", "xx"],
+            in_w.as_bytes(),
+        );
+        assert!(oup.stderr.contains("can not parse 'xx'"));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t6_canonical_equivalence() {
+        let in_w = "e\u{301}clair\neclair\n\u{e9}clair\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "collate", "-u"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "eclair\ne\u{301}clair\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t7_locale_nfd() {
+        let in_w = "a\u{30a}l\nzoo\n\u{e5}m\napple\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "collate", "--collate-locale", "sv"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "apple\nzoo\na\u{30a}l\n\u{e5}m\n");
+        assert!(oup.status.success());
+        //
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "--according-to",
+                "collate",
+                "--collate-locale",
+                "sv",
+                "--normalize",
+                "nfd",
+            ],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "apple\nzoo\na\u{30a}l\n\u{e5}m\n");
+        assert!(oup.status.success());
+    }
+}
+
+mod test_2_string_color_e {
+    use exec_target::exec_target_with_env_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_env_in(
+            TARGET_EXE_PATH,
+            ["--color", "always"],
+            env_1!(),
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "<S>Apple:33:3.3:good:Mar<E>\n",
+                "<S>Cherry:4:4:good:Oct<E>\n",
+                "<S>Kiwi:1111:1.1.11:good:Jun<E>\n",
+                "<S>Orange:222:1.1.2:good:Jan<E>\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t2() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_env_in(
+            TARGET_EXE_PATH,
+            ["-r", "--color", "always"],
+            env_1!(),
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "<S>Orange:222:1.1.2:good:Jan<E>\n",
+                "<S>Kiwi:1111:1.1.11:good:Jun<E>\n",
+                "<S>Cherry:4:4:good:Oct<E>\n",
+                "<S>Apple:33:3.3:good:Mar<E>\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t3() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_env_in(
+            TARGET_EXE_PATH,
+            ["-e", "[0-9]+", "--color", "always"],
+            env_1!(),
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Cherry:<S>4<E>:4:good:Oct\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t4() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_env_in(
+            TARGET_EXE_PATH,
+            ["-e", "[0-9]+", "-r", "--color", "always"],
+            env_1!(),
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Cherry:<S>4<E>:4:good:Oct\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t5() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let in_w = in_w.clone() + &in_w;
+        let oup = exec_target_with_env_in(
+            TARGET_EXE_PATH,
+            ["-e", "[0-9]+", "--color", "always"],
+            env_1!(),
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Cherry:<S>4<E>:4:good:Oct\n",
+                "Cherry:<S>4<E>:4:good:Oct\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t6_header() {
+        let in_w = std::fs::read_to_string(fixture_fruit_header!()).unwrap();
+        let oup = exec_target_with_env_in(
+            TARGET_EXE_PATH,
+            ["-e", "[0-9]+", "-h", "1", "--color", "always"],
+            env_1!(),
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "name:number:version:nice:month\n",
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Cherry:<S>4<E>:4:good:Oct\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t6_footer() {
+        let in_w = std::fs::read_to_string(fixture_fruit_footer!()).unwrap();
+        let oup = exec_target_with_env_in(
+            TARGET_EXE_PATH,
+            ["-e", "[0-9]+", "-t", "1", "--color", "always"],
+            env_1!(),
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Cherry:<S>4<E>:4:good:Oct\n",
+                "This is footer line. 1\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+}
+
+mod test_2_numeric_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "numeric"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(
+            oup.stderr,
+            concat!(
+                program_name!(),
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': invalid float literal\n"
+            )
+        );
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t2() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "numeric", "-r"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(
+            oup.stderr,
+            concat!(
+                program_name!(),
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': invalid float literal\n"
+            )
+        );
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t3() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-e", "[0-9]+", "--according-to", "numeric"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Cherry:4:4:good:Oct\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t4() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-e", "[0-9]+", "--according-to", "numeric", "-r"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Cherry:4:4:good:Oct\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t5() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let in_w = in_w.clone() + &in_w;
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-e", "[0-9]+", "--according-to", "numeric"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Cherry:4:4:good:Oct\n",
+                "Cherry:4:4:good:Oct\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t6_header() {
+        let in_w = std::fs::read_to_string(fixture_fruit_header!()).unwrap();
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-e", "[0-9]+", "--according-to", "numeric", "-h", "1"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "name:number:version:nice:month\n",
+                "Cherry:4:4:good:Oct\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t6_footer() {
+        let in_w = std::fs::read_to_string(fixture_fruit_footer!()).unwrap();
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-e", "[0-9]+", "--according-to", "numeric", "-t", "1"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Cherry:4:4:good:Oct\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "This is footer line. 1\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+}
+
+mod test_2_numeric_precision_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = concat!(
+            "9007199254740993\n",
+            "123456789012345678901234567891\n",
+            "9007199254740992\n",
+            "123456789012345678901234567890\n",
+            "-0.000000000000000000001\n",
+        );
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "numeric"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "-0.000000000000000000001\n",
+                "9007199254740992\n",
+                "9007199254740993\n",
+                "123456789012345678901234567890\n",
+                "123456789012345678901234567891\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t2_float() {
+        let in_w = "1001\n1e3\n999.5\n1000\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "numeric", "-r"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "1001\n1e3\n1000\n999.5\n");
+        assert!(oup.status.success());
+    }
+    //
+    //
+    #[test]
+    fn test_t2_float_unique() {
+        let in_w = "1000 a\n1e3 b\n0.1e4 c\n999 d\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-k", "1,1:numeric", "-u"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "999 d\n1000 a\n");
+        assert!(oup.status.success());
+    }
+    #[test]
+    fn test_t3_unique() {
+        let in_w = "007 a\n7.0 b\n18446744073709551617 c\n18446744073709551616 d\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-k", "1,1:numeric", "-u"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            "007 a\n18446744073709551616 d\n18446744073709551617 c\n"
+        );
+        assert!(oup.status.success());
+    }
+}
+
+mod test_2_numeric_color_e {
+    use exec_target::exec_target_with_env_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_env_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "numeric", "--color", "always"],
+            env_1!(),
+            in_w.as_bytes(),
+        );
+        assert_eq!(
+            oup.stderr,
+            concat!(
+                program_name!(),
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': invalid float literal\n"
+            )
+        );
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t2() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_env_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "numeric", "-r", "--color", "always"],
+            env_1!(),
+            in_w.as_bytes(),
+        );
+        assert_eq!(
+            oup.stderr,
+            concat!(
+                program_name!(),
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': invalid float literal\n"
+            )
+        );
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t3() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_env_in(
+            TARGET_EXE_PATH,
+            [
+                "-e",
+                "[0-9]+",
+                "--according-to",
+                "numeric",
+                "--color",
+                "always",
+            ],
+            env_1!(),
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Cherry:<S>4<E>:4:good:Oct\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t4() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_env_in(
+            TARGET_EXE_PATH,
+            [
+                "-e",
+                "[0-9]+",
+                "--according-to",
+                "numeric",
+                "-r",
+                "--color",
+                "always",
+            ],
+            env_1!(),
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Cherry:<S>4<E>:4:good:Oct\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t5() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let in_w = in_w.clone() + &in_w;
+        let oup = exec_target_with_env_in(
+            TARGET_EXE_PATH,
+            [
+                "-e",
+                "[0-9]+",
+                "--according-to",
+                "numeric",
+                "--color",
+                "always",
+            ],
+            env_1!(),
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Cherry:<S>4<E>:4:good:Oct\n",
+                "Cherry:<S>4<E>:4:good:Oct\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t6_header() {
+        let in_w = std::fs::read_to_string(fixture_fruit_header!()).unwrap();
+        let oup = exec_target_with_env_in(
+            TARGET_EXE_PATH,
+            [
+                "-e",
+                "[0-9]+",
+                "--according-to",
+                "numeric",
+                "-h",
+                "1",
+                "--color",
+                "always",
+            ],
+            env_1!(),
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "name:number:version:nice:month\n",
+                "Cherry:<S>4<E>:4:good:Oct\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t6_footer() {
+        let in_w = std::fs::read_to_string(fixture_fruit_footer!()).unwrap();
+        let oup = exec_target_with_env_in(
+            TARGET_EXE_PATH,
+            [
+                "-e",
+                "[0-9]+",
+                "--according-to",
+                "numeric",
+                "-t",
+                "1",
+                "--color",
+                "always",
+            ],
+            env_1!(),
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Cherry:<S>4<E>:4:good:Oct\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+                "This is footer line. 1\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+}
+
+mod test_2_datetime_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = concat!(
+            "a 2024-03-01T12:00:00+09:00\n",
+            "b Fri, 01 Mar 2024 02:00:00 +0000\n",
+            "c 01/Mar/2024:04:00:00 +0000\n",
+            "d 2024-03-01 03:30:00Z\n",
+        );
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-e", "^\\S+ (.*)", "--according-to", "datetime"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "b Fri, 01 Mar 2024 02:00:00 +0000\n",
+                "a 2024-03-01T12:00:00+09:00\n",
+                "d 2024-03-01 03:30:00Z\n",
+                "c 01/Mar/2024:04:00:00 +0000\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t2_syslog() {
+        let in_w = "Mar 10 08:00:01 host b\nMar  9 23:59:59 host a\nFeb 29 00:00:00 host c\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-e", "^.{15}", "--according-to", "datetime", "-r"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            "Mar 10 08:00:01 host b\nMar  9 23:59:59 host a\nFeb 29 00:00:00 host c\n"
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t3_date_format() {
+        let in_w = "01.03.2024 x\n28.02.2024 y\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-k", "1,1:datetime", "--date-format", "%d.%m.%Y"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "28.02.2024 y\n01.03.2024 x\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t4_invalid() {
+        let in_w = "2024-03-01\n2024-13-01\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "datetime"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(
+            oup.stderr,
+            concat!(
+                program_name!(),
+                ": line 2: (0,10):'2024-13-01': unknown format while parsing datetime\n"
+            )
+        );
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+}
+
+mod test_2_deb_version_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = "2.3-4ubuntu1\n1:1.0\n2.3-4\n2.3~rc1-1\n2.3-10\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "deb-version"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            "2.3~rc1-1\n2.3-4\n2.3-4ubuntu1\n2.3-10\n1:1.0\n"
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t2_tilde_epoch() {
+        let in_w = "1.0+\n0:1.0\n1.0~\n1.0\n1.0~~\n1.0-0\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "deb-version", "-u"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "1.0~~\n1.0~\n0:1.0\n1.0+\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t3_invalid() {
+        let in_w = "1.0\n1.0_1\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "deb-version"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(
+            oup.stderr,
+            concat!(
+                program_name!(),
+                ": line 2: (0,5):'1.0_1': unexpected character '_' while parsing deb-version\n"
+            )
+        );
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+}
+
+mod test_2_duration_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = "test_a 1m2.5s\ntest_b 350\u{b5}s\ntest_c 1h30m\ntest_d 250ms\ntest_e 2d\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-k", "2", "--according-to", "duration"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            "test_b 350\u{b5}s\ntest_d 250ms\ntest_a 1m2.5s\ntest_c 1h30m\ntest_e 2d\n"
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t2_units_unique() {
+        let in_w = "1d\n24h\n86400s\n1440m\n250ms\n0.25s\n250000us\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "duration", "-u"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "250ms\n1d\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t3_invalid() {
+        let in_w = "1h\n1h5q\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "duration"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(
+            oup.stderr,
+            concat!(
+                program_name!(),
+                ": line 2: (0,4):'1h5q': unknown unit 'q' of '5q' while parsing duration\n"
+            )
+        );
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+}
+
+mod test_2_general_numeric_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = "1,234,567 x\n0x1F hex\n  +42 items\n1.5e+03ms\n0o17 oct\n-inf\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "general-numeric"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            "-inf\n0o17 oct\n0x1F hex\n  +42 items\n1.5e+03ms\n1,234,567 x\n"
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t2_radix_unique() {
+        let in_w = "0x10\n16\n1.6e1\n+16\n15\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "general-numeric", "-u"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "15\n0x10\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t3_decimal_char() {
+        let in_w = "1.234,5\n999,9\n1.000\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "--according-to",
+                "general-numeric",
+                "--grouping-char",
+                ".",
+                "--decimal-char",
+                ",",
+            ],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "999,9\n1.000\n1.234,5\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t4_invalid() {
+        let in_w = "12\nabc\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "general-numeric"],
+            in_w.as_bytes(),
+        );
         assert_eq!(
-            oup.stdout,
+            oup.stderr,
             concat!(
-                "Apple:33:3.3:good:Mar\n",
-                "Cherry:4:4:good:Oct\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Orange:222:1.1.2:good:Jan\n",
+                program_name!(),
+                ": line 2: (0,3):'abc': unexpected character 'a' while parsing general numeric\n"
             )
         );
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t5_key() {
+        let in_w = "a 1,000\nb 20\nc 1e3\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-k", "2,2:general-numeric:r", "-k", "1,1:r"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "c 1e3\na 1,000\nb 20\n");
         assert!(oup.status.success());
     }
     //
     #[test]
-    fn test_t2() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-r"], in_w.as_bytes());
+    fn test_t6_same_chars() {
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--grouping-char", ".", "--decimal-char", "."],
+            b"" as &[u8],
+        );
+        assert!(oup
+            .stderr
+            .contains("decimal-char: can not be the same as --grouping-char"));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+}
+
+mod test_2_human_size_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = "10K\tsrc\n2.5M\ttarget\n900\tREADME.md\n1G\t.git\n1.5KiB\tdoc\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-e", "^[^\t]+", "--according-to", "human-size"],
+            in_w.as_bytes(),
+        );
         assert_eq!(oup.stderr, "");
         assert_eq!(
             oup.stdout,
-            concat!(
-                "Orange:222:1.1.2:good:Jan\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Cherry:4:4:good:Oct\n",
-                "Apple:33:3.3:good:Mar\n",
-            )
+            "900\tREADME.md\n1.5KiB\tdoc\n10K\tsrc\n2.5M\ttarget\n1G\t.git\n"
         );
         assert!(oup.status.success());
     }
     //
     #[test]
-    fn test_t3() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-e", "[0-9]+"], in_w.as_bytes());
+    fn test_t2_si_iec() {
+        let in_w = "1KiB\n1K\n1kB\n1k\n1000\n0.5M\n512K\n1.5Ki\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "human-size", "-u"],
+            in_w.as_bytes(),
+        );
         assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "1kB\n1KiB\n1.5Ki\n0.5M\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t3_invalid() {
+        let in_w = "10K\n3XB\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "human-size"],
+            in_w.as_bytes(),
+        );
         assert_eq!(
-            oup.stdout,
+            oup.stderr,
             concat!(
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Cherry:4:4:good:Oct\n",
+                program_name!(),
+                ": line 2: (0,3):'3XB': unexpected unit 'XB' while parsing size\n"
             )
         );
-        assert!(oup.status.success());
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
     }
     //
     #[test]
-    fn test_t4() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-e", "[0-9]+", "-r"], in_w.as_bytes());
+    fn test_t4_key() {
+        let in_w = "b 2G\na 512M\nc 2GiB\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-k", "2,2:human-size:r", "-k", "1,1"],
+            in_w.as_bytes(),
+        );
         assert_eq!(oup.stderr, "");
-        assert_eq!(
-            oup.stdout,
-            concat!(
-                "Cherry:4:4:good:Oct\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-            )
+        assert_eq!(oup.stdout, "b 2G\nc 2GiB\na 512M\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t5_negative_zero() {
+        let in_w = "0\n-0\n0K\n+0B\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "human-size", "-u"],
+            in_w.as_bytes(),
         );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "0\n");
         assert!(oup.status.success());
     }
+}
+
+mod test_2_ip_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
     //
     #[test]
-    fn test_t5() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let in_w = in_w.clone() + &in_w;
-        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-e", "[0-9]+"], in_w.as_bytes());
+    fn test_t1() {
+        let in_w = "10.0.0.10\n10.0.0.9\n2001:db8::1\n::ffff:10.0.0.5\n192.168.0.0/24\n192.168.0.0/16\n::1\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["--according-to", "ip"], in_w.as_bytes());
         assert_eq!(oup.stderr, "");
         assert_eq!(
             oup.stdout,
-            concat!(
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Cherry:4:4:good:Oct\n",
-                "Cherry:4:4:good:Oct\n",
-            )
+            "::ffff:10.0.0.5\n10.0.0.9\n10.0.0.10\n192.168.0.0/16\n192.168.0.0/24\n::1\n2001:db8::1\n"
         );
         assert!(oup.status.success());
     }
     //
     #[test]
-    fn test_t6_header() {
-        let in_w = std::fs::read_to_string(fixture_fruit_header!()).unwrap();
+    fn test_t2_ipv6_first() {
+        let in_w = "10.0.0.10\n10.0.0.9\n2001:db8::1\n::ffff:10.0.0.5\n::1\n";
         let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            ["-e", "[0-9]+", "-h", "1"],
+            ["--according-to", "ip", "--ipv6-first"],
             in_w.as_bytes(),
         );
         assert_eq!(oup.stderr, "");
         assert_eq!(
             oup.stdout,
-            concat!(
-                "name:number:version:nice:month\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Cherry:4:4:good:Oct\n",
-            )
+            "::1\n2001:db8::1\n::ffff:10.0.0.5\n10.0.0.9\n10.0.0.10\n"
         );
         assert!(oup.status.success());
     }
     //
     #[test]
-    fn test_t6_footer() {
-        let in_w = std::fs::read_to_string(fixture_fruit_footer!()).unwrap();
+    fn test_t2_mapped_prefix() {
+        let in_w = "10.0.0.1\n::ffff:10.0.0.0/104\n10.0.0.0/8\n";
         let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            ["-e", "[0-9]+", "-t", "1"],
+            ["--according-to", "ip", "-u"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "::ffff:10.0.0.0/104\n10.0.0.1\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t3_keydef() {
+        let in_w = "a 10.0.0.2 GET\nb ::1 GET\nc 10.0.0.10 POST\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-k", "2,2:ip", "--ipv6-first"],
             in_w.as_bytes(),
         );
         assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "b ::1 GET\na 10.0.0.2 GET\nc 10.0.0.10 POST\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t4_invalid() {
+        let in_w = "10.0.0.1\n10.0.0.0/33\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["--according-to", "ip"], in_w.as_bytes());
         assert_eq!(
-            oup.stdout,
+            oup.stderr,
             concat!(
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Cherry:4:4:good:Oct\n",
-                "This is footer line. 1\n",
+                program_name!(),
+                ": line 2: (0,11):'10.0.0.0/33': invalid prefix '33' while parsing ip\n"
             )
         );
-        assert!(oup.status.success());
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
     }
 }
 
-mod test_2_string_color_e {
-    use exec_target::exec_target_with_env_in;
+mod test_2_lenient_version_e {
+    use exec_target::exec_target_with_in;
     const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
     //
     #[test]
     fn test_t1() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let oup = exec_target_with_env_in(
+        let in_w = "foo-1.10.tar.gz\nfoo-1.2.tar.gz\nv1.10\nv1.9\n1.0-beta10\n1.0-beta2\n1.2.3.4\n";
+        let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            ["--color", "always"],
-            env_1!(),
+            ["--according-to", "lenient-version"],
             in_w.as_bytes(),
         );
         assert_eq!(oup.stderr, "");
         assert_eq!(
             oup.stdout,
-            concat!(
-                "<S>Apple:33:3.3:good:Mar<E>\n",
-                "<S>Cherry:4:4:good:Oct<E>\n",
-                "<S>Kiwi:1111:1.1.11:good:Jun<E>\n",
-                "<S>Orange:222:1.1.2:good:Jan<E>\n",
-            )
+            "1.0-beta2\n1.0-beta10\n1.2.3.4\nfoo-1.2.tar.gz\nfoo-1.10.tar.gz\nv1.9\nv1.10\n"
         );
         assert!(oup.status.success());
     }
     //
     #[test]
-    fn test_t2() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let oup = exec_target_with_env_in(
+    fn test_t2_unique() {
+        let in_w = "1.01\n1.1\n1.0~rc1\n";
+        let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            ["-r", "--color", "always"],
-            env_1!(),
+            ["--according-to", "lenient-version", "-u"],
             in_w.as_bytes(),
         );
         assert_eq!(oup.stderr, "");
-        assert_eq!(
-            oup.stdout,
-            concat!(
-                "<S>Orange:222:1.1.2:good:Jan<E>\n",
-                "<S>Kiwi:1111:1.1.11:good:Jun<E>\n",
-                "<S>Cherry:4:4:good:Oct<E>\n",
-                "<S>Apple:33:3.3:good:Mar<E>\n",
-            )
-        );
+        assert_eq!(oup.stdout, "1.0~rc1\n1.01\n");
         assert!(oup.status.success());
     }
     //
     #[test]
-    fn test_t3() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let oup = exec_target_with_env_in(
+    fn test_t3_keydef() {
+        let in_w = "b v2.0.0.1\na v2.0.0.10\nb v2.0.0.10\n";
+        let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            ["-e", "[0-9]+", "--color", "always"],
-            env_1!(),
+            ["-k", "1,1", "-k", "2:lenient-version:r"],
             in_w.as_bytes(),
         );
         assert_eq!(oup.stderr, "");
-        assert_eq!(
-            oup.stdout,
-            concat!(
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Cherry:<S>4<E>:4:good:Oct\n",
-            )
-        );
+        assert_eq!(oup.stdout, "a v2.0.0.10\nb v2.0.0.10\nb v2.0.0.1\n");
         assert!(oup.status.success());
     }
+}
+
+mod test_2_list_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
     //
     #[test]
-    fn test_t4() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let oup = exec_target_with_env_in(
+    fn test_t1() {
+        let in_w = "high disk\nLow memo\ncritical db\nmedium cpu\nLOW log\n";
+        let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            ["-e", "[0-9]+", "-r", "--color", "always"],
-            env_1!(),
+            [
+                "-k",
+                "1,1",
+                "--according-to",
+                "list",
+                "--order",
+                "low,medium,high,critical",
+            ],
             in_w.as_bytes(),
         );
         assert_eq!(oup.stderr, "");
         assert_eq!(
             oup.stdout,
-            concat!(
-                "Cherry:<S>4<E>:4:good:Oct\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-            )
+            "Low memo\nLOW log\nmedium cpu\nhigh disk\ncritical db\n"
         );
         assert!(oup.status.success());
     }
     //
     #[test]
-    fn test_t5() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let in_w = in_w.clone() + &in_w;
-        let oup = exec_target_with_env_in(
+    fn test_t2_order_file() {
+        let in_w = "ERROR x\nInformation y\nwarn z\nTRACE w\nerr v\n";
+        let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            ["-e", "[0-9]+", "--color", "always"],
-            env_1!(),
+            ["-k", "1,1:list", "--order-file", fixture_order_levels!()],
             in_w.as_bytes(),
         );
         assert_eq!(oup.stderr, "");
         assert_eq!(
             oup.stdout,
-            concat!(
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Cherry:<S>4<E>:4:good:Oct\n",
-                "Cherry:<S>4<E>:4:good:Oct\n",
-            )
+            "TRACE w\nInformation y\nwarn z\nERROR x\nerr v\n"
         );
         assert!(oup.status.success());
     }
     //
     #[test]
-    fn test_t6_header() {
-        let in_w = std::fs::read_to_string(fixture_fruit_header!()).unwrap();
-        let oup = exec_target_with_env_in(
-            TARGET_EXE_PATH,
-            ["-e", "[0-9]+", "-h", "1", "--color", "always"],
-            env_1!(),
-            in_w.as_bytes(),
-        );
-        assert_eq!(oup.stderr, "");
-        assert_eq!(
-            oup.stdout,
-            concat!(
-                "name:number:version:nice:month\n",
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Cherry:<S>4<E>:4:good:Oct\n",
-            )
+    fn test_t3_on_unknown_first() {
+        let in_w = "prod\nqa\ndev\nstaging\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "--according-to",
+                "list",
+                "--order",
+                "dev,staging,prod",
+                "--on-unknown",
+                "first",
+            ],
+            in_w.as_bytes(),
         );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "qa\ndev\nstaging\nprod\n");
         assert!(oup.status.success());
     }
     //
     #[test]
-    fn test_t6_footer() {
-        let in_w = std::fs::read_to_string(fixture_fruit_footer!()).unwrap();
-        let oup = exec_target_with_env_in(
+    fn test_t4_on_unknown_last() {
+        let in_w = "prod\nqa\ndev\nstaging\n";
+        let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            ["-e", "[0-9]+", "-t", "1", "--color", "always"],
-            env_1!(),
+            [
+                "--according-to",
+                "list",
+                "--order",
+                "dev,staging,prod",
+                "--on-unknown",
+                "last",
+            ],
             in_w.as_bytes(),
         );
         assert_eq!(oup.stderr, "");
-        assert_eq!(
-            oup.stdout,
-            concat!(
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Cherry:<S>4<E>:4:good:Oct\n",
-                "This is footer line. 1\n",
-            )
-        );
+        assert_eq!(oup.stdout, "dev\nstaging\nprod\nqa\n");
         assert!(oup.status.success());
     }
-}
-
-mod test_2_numeric_e {
-    use exec_target::exec_target_with_in;
-    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
     //
     #[test]
-    fn test_t1() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+    fn test_t5_on_unknown_error() {
+        let in_w = "prod\nqa\ndev\n";
         let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            ["--according-to", "numeric"],
+            ["--according-to", "list", "--order", "dev,staging,prod"],
             in_w.as_bytes(),
         );
         assert_eq!(
             oup.stderr,
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': invalid float literal\n"
+                ": line 2: (0,2):'qa': unknown value 'qa' while parsing list\n"
             )
         );
         assert_eq!(oup.stdout, "");
@@ -491,335 +2589,306 @@ mod test_2_numeric_e {
     }
     //
     #[test]
-    fn test_t2() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+    fn test_t6_missing_order() {
+        let in_w = "prod\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["--according-to", "list"], in_w.as_bytes());
+        assert!(oup.stderr.contains("Missing option: order"));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t7_invalid_order() {
+        let in_w = "prod\n";
         let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            ["--according-to", "numeric", "-r"],
+            ["--according-to", "list", "--order", ","],
             in_w.as_bytes(),
         );
-        assert_eq!(
-            oup.stderr,
-            concat!(
-                program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': invalid float literal\n"
-            )
+        assert!(oup.stderr.contains("no values of the order"));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+        //
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "list", "--order", "high,low,high"],
+            in_w.as_bytes(),
         );
+        assert!(oup.stderr.contains("duplicate value 'high' of the order"));
         assert_eq!(oup.stdout, "");
         assert!(!oup.status.success());
     }
+}
+
+mod test_2_natural_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
     //
     #[test]
-    fn test_t3() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+    fn test_t1() {
+        let in_w = "file10.txt\nfile2.txt\nfile1.txt\nfile02.txt\nfile.txt\n";
         let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            ["-e", "[0-9]+", "--according-to", "numeric"],
+            ["--according-to", "natural"],
             in_w.as_bytes(),
         );
         assert_eq!(oup.stderr, "");
         assert_eq!(
             oup.stdout,
-            concat!(
-                "Cherry:4:4:good:Oct\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-            )
+            "file.txt\nfile1.txt\nfile2.txt\nfile02.txt\nfile10.txt\n"
         );
         assert!(oup.status.success());
     }
     //
     #[test]
-    fn test_t4() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+    fn test_t2_leading_zeros() {
+        let in_w = "a01\na1\na001\na1b\na\n";
         let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            ["-e", "[0-9]+", "--according-to", "numeric", "-r"],
+            ["--according-to", "natural", "-u"],
             in_w.as_bytes(),
         );
         assert_eq!(oup.stderr, "");
-        assert_eq!(
-            oup.stdout,
-            concat!(
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Cherry:4:4:good:Oct\n",
-            )
-        );
+        assert_eq!(oup.stdout, "a\na1\na01\na001\na1b\n");
         assert!(oup.status.success());
     }
     //
     #[test]
-    fn test_t5() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let in_w = in_w.clone() + &in_w;
+    fn test_t3() {
+        let in_w = "id 123456789012345678901234567890\nid 99999999999999999999\nid 7\n";
         let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            ["-e", "[0-9]+", "--according-to", "numeric"],
+            ["-e", "[0-9]+", "--according-to", "natural"],
             in_w.as_bytes(),
         );
         assert_eq!(oup.stderr, "");
         assert_eq!(
             oup.stdout,
-            concat!(
-                "Cherry:4:4:good:Oct\n",
-                "Cherry:4:4:good:Oct\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-            )
+            "id 7\nid 99999999999999999999\nid 123456789012345678901234567890\n"
         );
         assert!(oup.status.success());
     }
     //
     #[test]
-    fn test_t6_header() {
-        let in_w = std::fs::read_to_string(fixture_fruit_header!()).unwrap();
+    fn test_t4_key() {
+        let in_w = "b:v1.10\na:v1.9\nc:v1.9\n";
         let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            ["-e", "[0-9]+", "--according-to", "numeric", "-h", "1"],
+            ["--field-separator", ":", "-k", "2,2:natural", "-k", "1,1:r"],
             in_w.as_bytes(),
         );
         assert_eq!(oup.stderr, "");
-        assert_eq!(
-            oup.stdout,
-            concat!(
-                "name:number:version:nice:month\n",
-                "Cherry:4:4:good:Oct\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-            )
-        );
+        assert_eq!(oup.stdout, "c:v1.9\na:v1.9\nb:v1.10\n");
         assert!(oup.status.success());
     }
+}
+
+mod test_2_pep440_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
     //
     #[test]
-    fn test_t6_footer() {
-        let in_w = std::fs::read_to_string(fixture_fruit_footer!()).unwrap();
+    fn test_t1() {
+        let in_w = "2.0\n1.0.post1\n2.0rc1\n1!3.0\n1.0.dev3\n1.0\n1.0+local.1\n";
         let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            ["-e", "[0-9]+", "--according-to", "numeric", "-t", "1"],
+            ["--according-to", "pep440"],
             in_w.as_bytes(),
         );
         assert_eq!(oup.stderr, "");
         assert_eq!(
             oup.stdout,
-            concat!(
-                "Cherry:4:4:good:Oct\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "This is footer line. 1\n",
-            )
+            "1.0.dev3\n1.0\n1.0+local.1\n1.0.post1\n2.0rc1\n2.0\n1!3.0\n"
         );
         assert!(oup.status.success());
     }
-}
-
-mod test_2_numeric_color_e {
-    use exec_target::exec_target_with_env_in;
-    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
     //
     #[test]
-    fn test_t1() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let oup = exec_target_with_env_in(
+    fn test_t2_keydef() {
+        let in_w = "requests=2.31.0\nattrs=23.1.0\nrequests=2.4.post1\nattrs=23.1.0rc1\n";
+        let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            ["--according-to", "numeric", "--color", "always"],
-            env_1!(),
+            ["--field-separator", "=", "-k", "1,1", "-k", "2,2:pep440"],
             in_w.as_bytes(),
         );
+        assert_eq!(oup.stderr, "");
         assert_eq!(
-            oup.stderr,
-            concat!(
-                program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': invalid float literal\n"
-            )
+            oup.stdout,
+            "attrs=23.1.0rc1\nattrs=23.1.0\nrequests=2.4.post1\nrequests=2.31.0\n"
         );
-        assert_eq!(oup.stdout, "");
-        assert!(!oup.status.success());
+        assert!(oup.status.success());
     }
     //
     #[test]
-    fn test_t2() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let oup = exec_target_with_env_in(
+    fn test_t3_invalid() {
+        let in_w = "1.0\n1.0-beta+\n";
+        let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            ["--according-to", "numeric", "-r", "--color", "always"],
-            env_1!(),
+            ["--according-to", "pep440"],
             in_w.as_bytes(),
         );
         assert_eq!(
             oup.stderr,
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': invalid float literal\n"
+                ": line 2: (0,9):'1.0-beta+': invalid version '1.0-beta+' while parsing pep440\n"
             )
         );
         assert_eq!(oup.stdout, "");
         assert!(!oup.status.success());
     }
+}
+
+mod test_2_rpm_version_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
     //
     #[test]
-    fn test_t3() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let oup = exec_target_with_env_in(
+    fn test_t1() {
+        let in_w = "pkg 1.0-1.el9\npkg 1.0^git1-1\npkg 1.0~rc1-1\npkg 1:0.9-1\npkg 1.0-1.el10\npkg 1.0-2.el9\n";
+        let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            [
-                "-e",
-                "[0-9]+",
-                "--according-to",
-                "numeric",
-                "--color",
-                "always",
-            ],
-            env_1!(),
+            ["-k", "2", "--according-to", "rpm-version"],
             in_w.as_bytes(),
         );
         assert_eq!(oup.stderr, "");
         assert_eq!(
             oup.stdout,
-            concat!(
-                "Cherry:<S>4<E>:4:good:Oct\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-            )
+            "pkg 1.0~rc1-1\npkg 1.0-1.el9\npkg 1.0-1.el10\npkg 1.0-2.el9\npkg 1.0^git1-1\npkg 1:0.9-1\n"
         );
         assert!(oup.status.success());
     }
     //
     #[test]
-    fn test_t4() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let oup = exec_target_with_env_in(
+    fn test_t2_keydef() {
+        let in_w = "b 1.10\na 1.9\nb 1.9\n";
+        let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            [
-                "-e",
-                "[0-9]+",
-                "--according-to",
-                "numeric",
-                "-r",
-                "--color",
-                "always",
-            ],
-            env_1!(),
+            ["-k", "1,1", "-k", "2:rpm-version:r"],
             in_w.as_bytes(),
         );
         assert_eq!(oup.stderr, "");
-        assert_eq!(
-            oup.stdout,
-            concat!(
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Cherry:<S>4<E>:4:good:Oct\n",
-            )
-        );
+        assert_eq!(oup.stdout, "a 1.9\nb 1.10\nb 1.9\n");
         assert!(oup.status.success());
     }
     //
     #[test]
-    fn test_t5() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let in_w = in_w.clone() + &in_w;
-        let oup = exec_target_with_env_in(
+    fn test_t3_invalid() {
+        let in_w = "1.0\nx:1.0\n";
+        let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            [
-                "-e",
-                "[0-9]+",
-                "--according-to",
-                "numeric",
-                "--color",
-                "always",
-            ],
-            env_1!(),
+            ["--according-to", "rpm-version"],
             in_w.as_bytes(),
         );
-        assert_eq!(oup.stderr, "");
         assert_eq!(
-            oup.stdout,
+            oup.stderr,
             concat!(
-                "Cherry:<S>4<E>:4:good:Oct\n",
-                "Cherry:<S>4<E>:4:good:Oct\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+                program_name!(),
+                ": line 2: (0,5):'x:1.0': epoch 'x' is not a number while parsing rpm-version\n"
             )
         );
-        assert!(oup.status.success());
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
     }
+}
+
+mod test_2_weekday_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
     //
     #[test]
-    fn test_t6_header() {
-        let in_w = std::fs::read_to_string(fixture_fruit_header!()).unwrap();
-        let oup = exec_target_with_env_in(
+    fn test_t1() {
+        let in_w = "Sun backup\nwed report\nMonday sync\nFRI deploy\nTue cleanup\n";
+        let oup = exec_target_with_in(
             TARGET_EXE_PATH,
-            [
-                "-e",
-                "[0-9]+",
-                "--according-to",
-                "numeric",
-                "-h",
-                "1",
-                "--color",
-                "always",
-            ],
-            env_1!(),
+            ["-k", "1,1", "--according-to", "weekday"],
             in_w.as_bytes(),
         );
         assert_eq!(oup.stderr, "");
         assert_eq!(
             oup.stdout,
-            concat!(
-                "name:number:version:nice:month\n",
-                "Cherry:<S>4<E>:4:good:Oct\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-            )
+            "Monday sync\nTue cleanup\nwed report\nFRI deploy\nSun backup\n"
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t2_first_weekday() {
+        let in_w = "Sat\nMon\nSunday\nTue\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "weekday", "--first-weekday", "sunday"],
+            in_w.as_bytes(),
         );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "Sunday\nMon\nTue\nSat\n");
         assert!(oup.status.success());
     }
     //
     #[test]
-    fn test_t6_footer() {
-        let in_w = std::fs::read_to_string(fixture_fruit_footer!()).unwrap();
-        let oup = exec_target_with_env_in(
+    fn test_t3_keydef() {
+        let in_w = "0 3 * * Sun\n0 1 * * Mon\n0 2 * * Sun\n";
+        let oup = exec_target_with_in(
             TARGET_EXE_PATH,
             [
-                "-e",
-                "[0-9]+",
-                "--according-to",
-                "numeric",
-                "-t",
-                "1",
-                "--color",
-                "always",
+                "-k",
+                "5,5:weekday",
+                "-k",
+                "2,2:numeric",
+                "--first-weekday",
+                "sunday",
             ],
-            env_1!(),
             in_w.as_bytes(),
         );
         assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "0 2 * * Sun\n0 3 * * Sun\n0 1 * * Mon\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t4_invalid() {
+        let in_w = "Mon\nMo\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "weekday"],
+            in_w.as_bytes(),
+        );
         assert_eq!(
-            oup.stdout,
+            oup.stderr,
             concat!(
-                "Cherry:<S>4<E>:4:good:Oct\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-                "This is footer line. 1\n",
+                program_name!(),
+                ": line 2: (0,2):'Mo': invalid weekday strings\n"
             )
         );
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t5_first_weekday_invalid() {
+        let in_w = "Mon\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "weekday", "--first-weekday", "tuesday"],
+            in_w.as_bytes(),
+        );
+        assert!(oup
+            .stderr
+            .contains("first-weekday: can not parse 'tuesday'"));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t6_normalize() {
+        let in_w = "Wed\nMon.\n Tue\nmonday\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "weekday", "-u"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "Mon.\n Tue\nWed\n");
         assert!(oup.status.success());
     }
 }
@@ -840,7 +2909,7 @@ mod test_2_version_e {
             oup.stderr,
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing major version number\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing major version number\n",
             )
         );
         assert_eq!(oup.stdout, "");
@@ -859,7 +2928,7 @@ mod test_2_version_e {
             oup.stderr,
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing major version number\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing major version number\n",
             )
         );
         assert_eq!(oup.stdout, "");
@@ -1016,7 +3085,7 @@ mod test_2_version_color_e {
             oup.stderr,
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing major version number\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing major version number\n",
             )
         );
         assert_eq!(oup.stdout, "");
@@ -1036,7 +3105,7 @@ mod test_2_version_color_e {
             oup.stderr,
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing major version number\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing major version number\n",
             )
         );
         assert_eq!(oup.stdout, "");
@@ -1217,7 +3286,7 @@ mod test_2_month_e {
             oup.stderr,
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': invalid month strings\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': invalid month strings\n",
             )
         );
         assert_eq!(oup.stdout, "");
@@ -1236,7 +3305,7 @@ mod test_2_month_e {
             oup.stderr,
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': invalid month strings\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': invalid month strings\n",
             )
         );
         assert_eq!(oup.stdout, "");
@@ -1356,6 +3425,124 @@ mod test_2_month_e {
     }
 }
 
+mod test_2_month_locale_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_ja() {
+        let in_w = "10月 b\n2月 c\n１月 a\n12月 d\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "-k",
+                "1,1",
+                "--according-to",
+                "month",
+                "--month-locale",
+                "ja",
+            ],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "１月 a\n2月 c\n10月 b\n12月 d\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_de() {
+        let in_w = "Okt\nmärz\nJan\nDez.\nMai\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "month", "--month-locale", "de"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "Jan\nmärz\nMai\nOkt\nDez.\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_fr_keydef() {
+        let in_w = "3 févr. 2024\n1 janv. 2024\n2 août 2023\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "-k",
+                "3,3:numeric",
+                "-k",
+                "2,2:month",
+                "--month-locale",
+                "fr",
+            ],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "2 août 2023\n1 janv. 2024\n3 févr. 2024\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_names_file() {
+        let in_w = "Setiembre\nene\nAgosto\nfebrero\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "--according-to",
+                "month",
+                "--month-names",
+                fixture_month_names!(),
+            ],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "ene\nfebrero\nAgosto\nSetiembre\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_names_file_not_found() {
+        let in_w = "ene\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "--according-to",
+                "month",
+                "--month-names",
+                "fixtures/not_found.txt",
+            ],
+            in_w.as_bytes(),
+        );
+        assert!(oup
+            .stderr
+            .starts_with(concat!(program_name!(), ": fixtures/not_found.txt: ")));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_names_file_with_locale() {
+        let in_w = "ene\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            [
+                "--according-to",
+                "month",
+                "--month-locale",
+                "ja",
+                "--month-names",
+                fixture_month_names!(),
+            ],
+            in_w.as_bytes(),
+        );
+        assert!(oup
+            .stderr
+            .contains("month-names: can not be used with --month-locale"));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+}
+
 mod test_2_month_color_e {
     use exec_target::exec_target_with_env_in;
     const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
@@ -1373,7 +3560,7 @@ mod test_2_month_color_e {
             oup.stderr,
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': invalid month strings\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': invalid month strings\n",
             )
         );
         assert_eq!(oup.stdout, "");
@@ -1393,7 +3580,7 @@ mod test_2_month_color_e {
             oup.stderr,
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': invalid month strings\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': invalid month strings\n",
             )
         );
         assert_eq!(oup.stdout, "");
@@ -1570,7 +3757,7 @@ mod test_2_time_e {
             oup.stderr,
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing time\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing time\n",
             )
         );
         assert_eq!(oup.stdout, "");
@@ -1589,7 +3776,7 @@ mod test_2_time_e {
             oup.stderr,
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing time\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing time\n",
             )
         );
         assert_eq!(oup.stdout, "");
@@ -1772,6 +3959,91 @@ mod test_2_time_e {
         );
         assert!(oup.status.success());
     }
+    //
+    #[test]
+    fn test_t8_fraction_truncated() {
+        let in_w = "0.1234567891\n0.1234567899\n0.123456788\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["--according-to", "time", "-u"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "0.123456788\n0.1234567891\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t9_invalid_fraction() {
+        let in_w = "1:00\n1.5:30\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["--according-to", "time"], in_w.as_bytes());
+        assert_eq!(
+            oup.stderr,
+            concat!(
+                program_name!(),
+                ": line 2: (0,6):'1.5:30': can not parse fraction: '.5:30'\n"
+            )
+        );
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+}
+
+mod test_2_time_fraction_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = "1:00.5\n1:00.123456\n1:00.05\n1:00.4999999999\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["--according-to", "time"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            "1:00.05\n1:00.123456\n1:00.4999999999\n1:00.5\n"
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t2_days() {
+        let in_w = "a 2d 03:04:05\nb 1-23:59:59\nc 23:59:59.5\nd -0:01\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-e", "^\\S+ (.*)", "--according-to", "time", "-r"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            "a 2d 03:04:05\nb 1-23:59:59\nc 23:59:59.5\nd -0:01\n"
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t3_key() {
+        let in_w = "job1 -1:00\njob2 0:30\njob3 -0:00.5\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-k", "2,2:time"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "job1 -1:00\njob3 -0:00.5\njob2 0:30\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_t4_invalid() {
+        let in_w = "1:2:3:4\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["--according-to", "time"], in_w.as_bytes());
+        assert_eq!(
+            oup.stderr,
+            concat!(
+                program_name!(),
+                ": line 1: (0,7):'1:2:3:4': too many ':' while parsing time\n"
+            )
+        );
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
 }
 
 mod test_2_time_color_e {
@@ -1791,7 +4063,7 @@ mod test_2_time_color_e {
             oup.stderr,
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing time\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing time\n",
             )
         );
         assert_eq!(oup.stdout, "");
@@ -1811,7 +4083,7 @@ mod test_2_time_color_e {
             oup.stderr,
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing time\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing time\n",
             )
         );
         assert_eq!(oup.stdout, "");
@@ -2062,6 +4334,95 @@ mod test_3_e {
     }
 }
 
+mod test_3_buffer_size_e {
+    use exec_target::exec_target_with_in;
+    const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
+    //
+    #[test]
+    fn test_numeric() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-S", "1", "-e", "[0-9]+", "--according-to", "numeric"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(
+            oup.stdout,
+            concat!(
+                "Cherry:4:4:good:Oct\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_stable() {
+        let in_w = "b:1\na:2\nb:3\na:4\nb:5\na:6\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-S", "5", "-e", "^(.)"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "a:2\na:4\na:6\nb:1\nb:3\nb:5\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_carriage_return() {
+        // the last line without a newline keeps the '\r' of the end.
+        let in_w = "b\na\nc\r";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-S", "1"], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "a\nb\nc\r\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_header_footer() {
+        let in_w = "header\nd\nb\nc\na\nfooter\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-S", "1", "-h", "1", "-t", "1", "-r"],
+            in_w.as_bytes(),
+        );
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "header\nd\nc\nb\na\nfooter\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_regex_no_match() {
+        let in_w = "b:1\na:2\nc:3\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-S", "1", "-e", "d:."], in_w.as_bytes());
+        assert_eq!(oup.stderr, "");
+        assert_eq!(oup.stdout, "b:1\na:2\nc:3\n");
+        assert!(oup.status.success());
+    }
+    //
+    #[test]
+    fn test_buffer_size_invalid() {
+        let in_w = "b\na\nc\n";
+        let oup = exec_target_with_in(TARGET_EXE_PATH, ["-S", "a"], in_w.as_bytes());
+        assert!(oup.stderr.contains("buffer-size: can not parse"));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+    //
+    #[test]
+    fn test_temp_dir_not_found() {
+        let in_w = "b\na\nc\n";
+        let oup = exec_target_with_in(
+            TARGET_EXE_PATH,
+            ["-S", "1", "-T", "fixtures/not_found"],
+            in_w.as_bytes(),
+        );
+        assert!(oup.stderr.contains("aki-resort."));
+        assert_eq!(oup.stdout, "");
+        assert!(!oup.status.success());
+    }
+}
+
 mod test_4_combination_options_e {
     use exec_target::exec_target_with_in;
     const TARGET_EXE_PATH: &str = super::TARGET_EXE_PATH;
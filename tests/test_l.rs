@@ -164,297 +164,2153 @@ mod test_1_regex_options_l {
         assert!(r.is_ok());
     }
 }
+mod test_1_merge_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_merge() {
+        let in_w = "0 c\n3 c\n";
+        let (r, sioe) = do_execute!(
+            [
+                "-m",
+                "-k",
+                "1,1",
+                "--according-to",
+                "numeric",
+                fixture_sorted_1!(),
+                fixture_sorted_2!(),
+                "-",
+            ],
+            in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            "0 c\n1 a\n2 b\n3 a\n3 b\n3 c\n5 a\n10 b\n"
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_merge_unique() {
+        let (r, sioe) = do_execute!(
+            [
+                "--merge",
+                "-u",
+                "-k",
+                "1,1",
+                "--according-to",
+                "numeric",
+                fixture_sorted_1!(),
+                fixture_sorted_2!(),
+            ],
+            ""
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "1 a\n2 b\n3 a\n5 a\n10 b\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_merge_reverse() {
+        let in_w = "9 c\n4 c\n";
+        let (r, sioe) = do_execute!(
+            ["-m", "-r", "--according-to", "numeric", "-e", "[0-9]+", "-"],
+            in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "9 c\n4 c\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_merge_stdin_twice() {
+        let in_w = "c\nb\na\n";
+        let (r, sioe) = do_execute!(["-m", "-r", "-", "-"], in_w);
+        assert!(buff!(sioe, serr).contains("merge: can not read the standard input more than once"));
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_merge_unsorted() {
+        let (r, sioe) = do_execute!(
+            [
+                "-m",
+                "--field-separator",
+                ":",
+                "-k",
+                "2,2",
+                "--according-to",
+                "numeric",
+                fixture_fruit!(),
+            ],
+            ""
+        );
+        assert_eq!(
+            buff!(sioe, serr),
+            concat!(
+                program_name!(),
+                ": fixtures/fruit.txt: line 3: disorder: '4' after '222'\n"
+            )
+        );
+        assert_eq!(buff!(sioe, sout), "Apple:33:3.3:good:Mar\n");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_merge_with_head() {
+        let (r, sioe) = do_execute!(["-m", "-h", "1", fixture_sorted_1!()], "");
+        assert!(buff!(sioe, serr).contains("merge: can not be used with --head or --tail"));
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+}
+
+mod test_1_check_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_sorted() {
+        let in_w = "1\n2\n2\n10\n";
+        let (r, sioe) = do_execute!(["-c", "--according-to", "numeric"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_disorder() {
+        let in_w = "1\n2\n2\n10\n";
+        let (r, sioe) = do_execute!(["--check"], in_w);
+        assert_eq!(
+            buff!(sioe, serr),
+            concat!(program_name!(), ": line 4: disorder: '10' after '2'\n")
+        );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_disorder_quiet() {
+        let in_w = "1\n2\n2\n10\n";
+        let (r, sioe) = do_execute!(["-C"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_duplicate_unique() {
+        let in_w = "1\n2\n2.0\n10\n";
+        let (r, sioe) = do_execute!(["-c", "-u", "--according-to", "numeric"], in_w);
+        assert_eq!(
+            buff!(sioe, serr),
+            concat!(program_name!(), ": line 3: duplicate: '2.0' after '2'\n")
+        );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_reverse_equal_keys() {
+        let in_w = "3 c\n2 b\n2 a\n1 d\n";
+        let (r, sioe) = do_execute!(["-c", "-r", "-k", "1,1", "--according-to", "numeric"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_header_key() {
+        let in_w = "name:num\nb:1\na:2\nc:3\n";
+        let (r, sioe) = do_execute!(
+            [
+                "-c",
+                "-h",
+                "1",
+                "--field-separator",
+                ":",
+                "-k",
+                "2,2",
+                "--according-to",
+                "numeric",
+            ],
+            in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_bad_key() {
+        let in_w = "1\nx\n2\n";
+        let (r, sioe) = do_execute!(["-c", "--according-to", "numeric"], in_w);
+        assert_eq!(
+            buff!(sioe, serr),
+            concat!(
+                program_name!(),
+                ": line 2: (0,1):'x': invalid float literal\n"
+            )
+        );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_more_than_one_input() {
+        let in_w = "1\n2\n";
+        let (r, sioe) = do_execute!(["-c", "-", "-"], in_w);
+        assert!(buff!(sioe, serr).contains("check: can not be used with more than one input"));
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+}
+
+mod test_1_on_bad_key_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    const IN_W: &str = "3 c\nx\n1 a\nfoo bar\n2 b\n";
+    //
+    #[test]
+    fn test_error() {
+        let (r, sioe) = do_execute!(["-e", "^[0-9]+", "--according-to", "numeric"], IN_W);
+        assert_eq!(
+            buff!(sioe, serr),
+            concat!(
+                program_name!(),
+                ": line 2: (0,1):'x': invalid float literal\n"
+            )
+        );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_first() {
+        let (r, sioe) = do_execute!(
+            [
+                "-e",
+                "^[0-9]+",
+                "--according-to",
+                "numeric",
+                "--on-bad-key",
+                "first",
+            ],
+            IN_W
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "x\nfoo bar\n1 a\n2 b\n3 c\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_last() {
+        let (r, sioe) = do_execute!(
+            [
+                "-e",
+                "^[0-9]+",
+                "--according-to",
+                "numeric",
+                "--on-bad-key",
+                "last",
+                "-r",
+            ],
+            IN_W
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "3 c\n2 b\n1 a\nx\nfoo bar\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_skip() {
+        let (r, sioe) = do_execute!(
+            [
+                "-e",
+                "^[0-9]+",
+                "--according-to",
+                "numeric",
+                "--on-bad-key",
+                "skip",
+            ],
+            IN_W
+        );
+        assert_eq!(
+            buff!(sioe, serr),
+            concat!(
+                program_name!(),
+                ": line 2: skipped: 'x': the key is not found\n",
+                program_name!(),
+                ": line 4: skipped: 'foo bar': the key is not found\n",
+            )
+        );
+        assert_eq!(buff!(sioe, sout), "1 a\n2 b\n3 c\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_skip_parse_error() {
+        let (r, sioe) = do_execute!(
+            [
+                "-k",
+                "1,1",
+                "--according-to",
+                "numeric",
+                "--on-bad-key",
+                "skip",
+                "-h",
+                "1",
+            ],
+            IN_W
+        );
+        assert_eq!(
+            buff!(sioe, serr),
+            concat!(
+                program_name!(),
+                ": line 2: skipped: (0,1):'x': invalid float literal\n",
+                program_name!(),
+                ": line 4: skipped: (0,3):'foo bar': invalid float literal\n",
+            )
+        );
+        assert_eq!(buff!(sioe, sout), "3 c\n1 a\n2 b\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_keep_in_place() {
+        let (r, sioe) = do_execute!(
+            [
+                "-e",
+                "^[0-9]+",
+                "--according-to",
+                "numeric",
+                "--on-bad-key",
+                "keep-in-place",
+            ],
+            IN_W
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "1 a\nx\n2 b\nfoo bar\n3 c\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_keep_in_place_buffer_size() {
+        let (r, sioe) = do_execute!(
+            [
+                "-e",
+                "^[0-9]+",
+                "--according-to",
+                "numeric",
+                "--on-bad-key",
+                "keep-in-place",
+                "-S",
+                "4",
+            ],
+            IN_W
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "1 a\nx\n2 b\nfoo bar\n3 c\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_invalid() {
+        let (r, sioe) = do_execute!(["--on-bad-key", "other"], "");
+        assert!(buff!(sioe, serr).contains("on-bad-key: can not parse 'other'"));
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+}
+
+mod test_1_unique_key_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_unique_numeric_key() {
+        let in_w = "1.0 a\n1 b\n2 c\n1.00 d\n";
+        let (r, sioe) = do_execute!(["-u", "-e", "^[^ ]+", "--according-to", "numeric"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "1.0 a\n2 c\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_unique_numeric_key_reverse() {
+        let in_w = "1.0 a\n1 b\n2 c\n1.00 d\n";
+        let (r, sioe) = do_execute!(
+            ["-u", "-r", "-e", "^[^ ]+", "--according-to", "numeric"],
+            in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "2 c\n1.0 a\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_unique_reverse_first_line() {
+        let in_w = "a 1\na 2\nb 3\n";
+        let (r, sioe) = do_execute!(["-k", "1,1", "-r", "-u"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "b 3\na 1\n");
+        assert!(r.is_ok());
+        //
+        let (r, sioe) = do_execute!(["-k", "1,1", "-r", "-u", "-S", "1"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "b 3\na 1\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_unique_multi_keys() {
+        let in_w = "a:1:x\nb:01:x\nc:1:y\nd:2:x\n";
+        let (r, sioe) = do_execute!(
+            [
+                "-u",
+                "--field-separator",
+                ":",
+                "-k",
+                "2,2:numeric",
+                "-k",
+                "3,3",
+            ],
+            in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "a:1:x\nc:1:y\nd:2:x\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_unique_buffer_size() {
+        let in_w = "1.0 a\n1 b\n2 c\n1.00 d\n3 e\n2.0 f\n";
+        let (r, sioe) = do_execute!(
+            ["-u", "-k", "1,1", "--according-to", "numeric", "-S", "5"],
+            in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "1.0 a\n2 c\n3 e\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_unique_line() {
+        let in_w = "1.0 a\n1.0 a\n1 b\n2 c\n";
+        let (r, sioe) = do_execute!(
+            ["--unique-line", "-e", "^[^ ]+", "--according-to", "numeric"],
+            in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "1.0 a\n1 b\n2 c\n");
+        assert!(r.is_ok());
+    }
+}
+
+mod test_1_multi_keys_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    const IN_W: &str = "a:3:Mar\nb:10:Jan\nc:2:Mar\nd:10:Jan\ne:7:Feb\nf:3:Mar\n";
+    //
+    #[test]
+    fn test_month_numeric_r_string() {
+        let (r, sioe) = do_execute!(
+            [
+                "--field-separator",
+                ":",
+                "-k",
+                "3,3:month",
+                "-k",
+                "2,2:numeric:r",
+                "-k",
+                "1,1",
+            ],
+            IN_W
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            "b:10:Jan\nd:10:Jan\ne:7:Feb\na:3:Mar\nf:3:Mar\nc:2:Mar\n"
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_default_according_to() {
+        let (r, sioe) = do_execute!(
+            [
+                "--field-separator",
+                ":",
+                "--according-to",
+                "numeric",
+                "-k",
+                "2,2",
+                "-k",
+                "1,1:string:r",
+            ],
+            IN_W
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            "c:2:Mar\nf:3:Mar\na:3:Mar\ne:7:Feb\nd:10:Jan\nb:10:Jan\n"
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_reverse_all() {
+        let (r, sioe) = do_execute!(
+            [
+                "--field-separator",
+                ":",
+                "-r",
+                "-k",
+                "3,3:month",
+                "-k",
+                "1,1:r",
+            ],
+            IN_W
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            "a:3:Mar\nc:2:Mar\nf:3:Mar\ne:7:Feb\nb:10:Jan\nd:10:Jan\n"
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_color_first_key() {
+        let (r, sioe) = do_execute!(
+            env_1!(),
+            [
+                "--field-separator",
+                ":",
+                "-k",
+                "3,3:month",
+                "-k",
+                "2,2:numeric",
+                "--color",
+                "always",
+            ],
+            "a:3:Mar\nb:10:Jan\nc:2:Mar\n"
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            "b:10:<S>Jan<E>\nc:2:<S>Mar<E>\na:3:<S>Mar<E>\n"
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_buffer_size() {
+        let (r, sioe) = do_execute!(
+            [
+                "--field-separator",
+                ":",
+                "-k",
+                "3,3:month",
+                "-k",
+                "2,2:numeric:r",
+                "-S",
+                "10",
+            ],
+            IN_W
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            "b:10:Jan\nd:10:Jan\ne:7:Feb\na:3:Mar\nf:3:Mar\nc:2:Mar\n"
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_invalid_modifier() {
+        let (r, sioe) = do_execute!(["-k", "1,1:foo"], "");
+        assert!(buff!(sioe, serr).contains("unknown modifier 'foo'"));
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_missing_field() {
+        let in_w = "b x\nzz\na y\n";
+        let (r, sioe) = do_execute!(["-k", "2,2"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "b x\na y\nzz\n");
+        assert!(r.is_ok());
+        //
+        let (r, sioe) = do_execute!(["-k", "2,2:string"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "b x\na y\nzz\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_missing_second_key() {
+        let (r, sioe) = do_execute!(
+            ["--on-bad-key", "last", "-k", "1,1", "-k", "3,3"],
+            "b 1 z\na 2\nc 3 y\n"
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "b 1 z\nc 3 y\na 2\n");
+        assert!(r.is_ok());
+    }
+}
+
+mod test_1_key_options_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_key_version() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(
+            [
+                "-k",
+                "3,3",
+                "--field-separator",
+                ":",
+                "--according-to",
+                "version",
+            ],
+            &in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Orange:222:1.1.2:good:Jan\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Cherry:4:4:good:Oct\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_key_month_color() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(
+            env_1!(),
+            [
+                "-k",
+                "5",
+                "--field-separator",
+                ":",
+                "--according-to",
+                "month",
+                "--color",
+                "always",
+            ],
+            &in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Orange:222:1.1.2:good:<S>Jan<E>\n",
+                "Apple:33:3.3:good:<S>Mar<E>\n",
+                "Kiwi:1111:1.1.11:good:<S>Jun<E>\n",
+                "Cherry:4:4:good:<S>Oct<E>\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_key_blank_separated() {
+        let in_w = "a  10 x\nb 9 y\nc\t 100 z\n";
+        let (r, sioe) = do_execute!(["-k", "2,2", "--according-to", "numeric"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "b 9 y\na  10 x\nc\t 100 z\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_key_char_position() {
+        let in_w = "x:b3\ny:a2\nz:c1\n";
+        let (r, sioe) = do_execute!(["-k", "2.2,2.2", "--field-separator", ":"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "z:c1\ny:a2\nx:b3\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_key_invalid() {
+        let (r, sioe) = do_execute!(["-k", "0,1"], "");
+        assert!(buff!(sioe, serr).contains("key: can not parse '0,1'"));
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_key_with_exp() {
+        let (r, sioe) = do_execute!(["-k", "1", "-e", "a"], "");
+        assert!(buff!(sioe, serr).contains("key: can not be used with --exp"));
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+}
+
+mod test_1_file_args_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_file() {
+        let (r, sioe) = do_execute!([fixture_fruit!()], "");
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Apple:33:3.3:good:Mar\n",
+                "Cherry:4:4:good:Oct\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Orange:222:1.1.2:good:Jan\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_files_and_stdin() {
+        let in_w = "Banana:5:5:good:Feb\n";
+        let (r, sioe) = do_execute!([fixture_fruit!(), "-", fixture_fruit!()], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Apple:33:3.3:good:Mar\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Banana:5:5:good:Feb\n",
+                "Cherry:4:4:good:Oct\n",
+                "Cherry:4:4:good:Oct\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Orange:222:1.1.2:good:Jan\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_stdin_only() {
+        let in_w = "b\na\nc\n";
+        let (r, sioe) = do_execute!(["-"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "a\nb\nc\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_dash_as_option_argument() {
+        let in_w = "b-2\na-3\nc-1\n";
+        let (r, sioe) = do_execute!(["-e", "-(.)", "-"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "c-1\nb-2\na-3\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_file_not_found() {
+        let (r, sioe) = do_execute!([fixture_fruit!(), "fixtures/not_found.txt"], "");
+        assert!(
+            buff!(sioe, serr).starts_with(concat!(program_name!(), ": fixtures/not_found.txt: "))
+        );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_file_invalid_utf8() {
+        let (r, sioe) = do_execute!([fixture_invalid_utf8!()], "");
+        assert_eq!(
+            buff!(sioe, serr),
+            concat!(
+                program_name!(),
+                ": ",
+                fixture_invalid_utf8!(),
+                ": stream did not contain valid UTF-8\n",
+            )
+        );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+}
+
+mod test_2_string_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(&[] as &[&str], &in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Apple:33:3.3:good:Mar\n",
+                "Cherry:4:4:good:Oct\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Orange:222:1.1.2:good:Jan\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t2() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(["-r"], &in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Orange:222:1.1.2:good:Jan\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Cherry:4:4:good:Oct\n",
+                "Apple:33:3.3:good:Mar\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t3() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(["-e", "[0-9]+"], &in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Cherry:4:4:good:Oct\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t4() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(["-e", "[0-9]+", "-r"], &in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Cherry:4:4:good:Oct\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t5() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let in_w = in_w.to_string() + &in_w;
+        let (r, sioe) = do_execute!(["-e", "[0-9]+"], &in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Cherry:4:4:good:Oct\n",
+                "Cherry:4:4:good:Oct\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t6_header() {
+        let in_w = std::fs::read_to_string(fixture_fruit_header!()).unwrap();
+        let (r, sioe) = do_execute!(["-e", "[0-9]+", "-h", "1"], &in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "name:number:version:nice:month\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Cherry:4:4:good:Oct\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t6_footer() {
+        let in_w = std::fs::read_to_string(fixture_fruit_footer!()).unwrap();
+        let (r, sioe) = do_execute!(["-e", "[0-9]+", "-t", "1"], in_w.as_str(),);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Cherry:4:4:good:Oct\n",
+                "This is footer line. 1\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+}
+
+mod test_2_string_ignore_case_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = "cherry\nBanana\napple\nÄpfel\nDate\n";
+        let (r, sioe) = do_execute!(["-f"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "apple\nBanana\ncherry\nDate\nÄpfel\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t2_unique() {
+        let in_w = "Straße\nstrasse\nSTRASSE\nΣΊΣΥΦΟΣ\nσίσυφος\napple\nAPPLE\n";
+        let (r, sioe) = do_execute!(["--ignore-case", "-u"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "apple\nStraße\nΣΊΣΥΦΟΣ\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t3_keydef() {
+        let in_w = "2 b\n1 B\n1 a\n2 A\n";
+        let (r, sioe) = do_execute!(["-f", "-k", "2,2", "-k", "1,1:numeric:r"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "2 A\n1 a\n2 b\n1 B\n");
+        assert!(r.is_ok());
+    }
+}
+
+mod test_2_string_normalize_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_t1_unique() {
+        let in_w = "cafe\u{301}\nzoo\ncaf\u{e9}\n";
+        let (r, sioe) = do_execute!(["--normalize", "nfc", "-u"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "cafe\u{301}\nzoo\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t2_nfd() {
+        let in_w = "caf\u{e9}\ncafe\u{301}\ncafe\n";
+        let (r, sioe) = do_execute!(["--normalize", "nfd"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "cafe\ncaf\u{e9}\ncafe\u{301}\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t3_nfkc_ignore_case() {
+        let in_w = "\u{fb01}le\nFILE\nfile\n";
+        let (r, sioe) = do_execute!(["-f", "--normalize", "nfkc", "-u"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "\u{fb01}le\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t4_keydef() {
+        let in_w = "1 cafe\u{301}\n2 caf\u{e9}\n3 bar\n";
+        let (r, sioe) = do_execute!(["-k", "2,2", "--normalize", "nfc", "-u"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "3 bar\n1 cafe\u{301}\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t5_collate() {
+        let in_w = "cafe\u{301}\ncaf\u{e9}\ncafe\n";
+        let (r, sioe) = do_execute!(
+            ["--according-to", "collate", "--normalize", "nfc", "-u"],
+            in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "cafe\ncafe\u{301}\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t6_invalid() {
+        let in_w = "a\n";
+        let (r, sioe) = do_execute!(["--normalize", "NFC"], in_w);
+        assert!(buff!(sioe, serr).contains("can not parse 'NFC'"));
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_t7_nfc_nfd_ignore_case() {
+        let in_w = "\u{c9}lan\nE\u{301}lan\n\u{e9}LAN\nElan\n";
+        let (r, sioe) = do_execute!(["-f", "--normalize", "nfc", "-u"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "Elan\n\u{c9}lan\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t8_ignore_case_reorder() {
+        let in_w = "\u{391}\u{345}\u{301} a\n\u{3b1}\u{301}\u{345} b\n";
+        let (r, sioe) = do_execute!(["-k", "1,1", "-f", "--normalize", "nfc", "-u"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "\u{391}\u{345}\u{301} a\n");
+        assert!(r.is_ok());
+    }
+}
+
+mod test_2_collate_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = "zebra\néclair\nÉclair\neclair\nApple\napple\n";
+        let (r, sioe) = do_execute!(["--according-to", "collate"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            "apple\nApple\neclair\néclair\nÉclair\nzebra\n"
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t2_reverse() {
+        let in_w = "かき\nカキ\nあめ\nco-op\ncoop\n";
+        let (r, sioe) = do_execute!(["--according-to", "collate", "-r"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "カキ\nかき\nあめ\ncoop\nco-op\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t3_locale() {
+        let in_w = "öl\nzoo\nål\näpple\napple\n";
+        let (r, sioe) = do_execute!(
+            ["--according-to", "collate", "--collate-locale", "sv"],
+            in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "apple\nzoo\nål\näpple\nöl\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t4_keydef() {
+        let in_w = "2 élan\n1 elan\n3 zoo\n";
+        let (r, sioe) = do_execute!(["-k", "2,2:collate:r"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "3 zoo\n2 élan\n1 elan\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t5_invalid_locale() {
+        let in_w = "a\n";
+        let (r, sioe) = do_execute!(
+            ["--according-to", "collate", "--collate-locale", "xx"],
+            in_w
+        );
+        assert!(buff!(sioe, serr).contains("can not parse 'xx'"));
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_t6_canonical_equivalence() {
+        let in_w = "e\u{301}clair\neclair\n\u{e9}clair\n";
+        let (r, sioe) = do_execute!(["--according-to", "collate", "-u"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "eclair\ne\u{301}clair\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t7_locale_nfd() {
+        let in_w = "a\u{30a}l\nzoo\n\u{e5}m\napple\n";
+        let (r, sioe) = do_execute!(
+            ["--according-to", "collate", "--collate-locale", "sv"],
+            in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "apple\nzoo\na\u{30a}l\n\u{e5}m\n");
+        assert!(r.is_ok());
+        //
+        let (r, sioe) = do_execute!(
+            [
+                "--according-to",
+                "collate",
+                "--collate-locale",
+                "sv",
+                "--normalize",
+                "nfd",
+            ],
+            in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "apple\nzoo\na\u{30a}l\n\u{e5}m\n");
+        assert!(r.is_ok());
+    }
+}
+
+mod test_2_string_color_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(env_1!(), ["--color", "always"], &in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "<S>Apple:33:3.3:good:Mar<E>\n",
+                "<S>Cherry:4:4:good:Oct<E>\n",
+                "<S>Kiwi:1111:1.1.11:good:Jun<E>\n",
+                "<S>Orange:222:1.1.2:good:Jan<E>\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t2() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(env_1!(), ["-r", "--color", "always"], &in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "<S>Orange:222:1.1.2:good:Jan<E>\n",
+                "<S>Kiwi:1111:1.1.11:good:Jun<E>\n",
+                "<S>Cherry:4:4:good:Oct<E>\n",
+                "<S>Apple:33:3.3:good:Mar<E>\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t3() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(env_1!(), ["-e", "[0-9]+", "--color", "always"], &in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Cherry:<S>4<E>:4:good:Oct\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t4() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(env_1!(), ["-e", "[0-9]+", "-r", "--color", "always"], &in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Cherry:<S>4<E>:4:good:Oct\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t5() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let in_w = in_w.to_string() + &in_w;
+        let (r, sioe) = do_execute!(
+            env_1!(),
+            ["-e", "[0-9]+", "--color", "always"],
+            in_w.as_str()
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Cherry:<S>4<E>:4:good:Oct\n",
+                "Cherry:<S>4<E>:4:good:Oct\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t6_header() {
+        let in_w = std::fs::read_to_string(fixture_fruit_header!()).unwrap();
+        let (r, sioe) = do_execute!(
+            env_1!(),
+            ["-e", "[0-9]+", "-h", "1", "--color", "always"],
+            &in_w,
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "name:number:version:nice:month\n",
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Cherry:<S>4<E>:4:good:Oct\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t6_footer() {
+        let in_w = std::fs::read_to_string(fixture_fruit_footer!()).unwrap();
+        let (r, sioe) = do_execute!(
+            env_1!(),
+            ["-e", "[0-9]+", "-t", "1", "--color", "always"],
+            &in_w,
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Cherry:<S>4<E>:4:good:Oct\n",
+                "This is footer line. 1\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+}
+
+mod test_2_numeric_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(["--according-to", "numeric"], &in_w);
+        assert_eq!(
+            buff!(sioe, serr),
+            concat!(
+                program_name!(),
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': invalid float literal\n"
+            )
+        );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_t2() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(["--according-to", "numeric", "-r"], &in_w);
+        assert_eq!(
+            buff!(sioe, serr),
+            concat!(
+                program_name!(),
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': invalid float literal\n"
+            )
+        );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_t3() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(["-e", "[0-9]+", "--according-to", "numeric"], &in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Cherry:4:4:good:Oct\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t4() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(["-e", "[0-9]+", "--according-to", "numeric", "-r"], &in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Cherry:4:4:good:Oct\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t5() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let in_w = in_w.to_string() + &in_w;
+        let (r, sioe) = do_execute!(["-e", "[0-9]+", "--according-to", "numeric"], &in_w,);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Cherry:4:4:good:Oct\n",
+                "Cherry:4:4:good:Oct\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t6_header() {
+        let in_w = std::fs::read_to_string(fixture_fruit_header!()).unwrap();
+        let (r, sioe) = do_execute!(
+            ["-e", "[0-9]+", "--according-to", "numeric", "-h", "1"],
+            &in_w,
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "name:number:version:nice:month\n",
+                "Cherry:4:4:good:Oct\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t6_footer() {
+        let in_w = std::fs::read_to_string(fixture_fruit_footer!()).unwrap();
+        let (r, sioe) = do_execute!(
+            ["-e", "[0-9]+", "--according-to", "numeric", "-t", "1"],
+            &in_w,
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Cherry:4:4:good:Oct\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+                "This is footer line. 1\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+}
+
+mod test_2_numeric_precision_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = concat!(
+            "9007199254740993\n",
+            "123456789012345678901234567891\n",
+            "9007199254740992\n",
+            "123456789012345678901234567890\n",
+            "-0.000000000000000000001\n",
+        );
+        let (r, sioe) = do_execute!(["--according-to", "numeric"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "-0.000000000000000000001\n",
+                "9007199254740992\n",
+                "9007199254740993\n",
+                "123456789012345678901234567890\n",
+                "123456789012345678901234567891\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t2_float() {
+        let in_w = "1001\n1e3\n999.5\n1000\n";
+        let (r, sioe) = do_execute!(["--according-to", "numeric", "-r"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "1001\n1e3\n1000\n999.5\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t2_float_unique() {
+        let in_w = "1000 a\n1e3 b\n0.1e4 c\n999 d\n";
+        let (r, sioe) = do_execute!(["-k", "1,1:numeric", "-u"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "999 d\n1000 a\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t3_unique() {
+        let in_w = "007 a\n7.0 b\n18446744073709551617 c\n18446744073709551616 d\n";
+        let (r, sioe) = do_execute!(["-k", "1,1:numeric", "-u"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            "007 a\n18446744073709551616 d\n18446744073709551617 c\n"
+        );
+        assert!(r.is_ok());
+    }
+}
+
+mod test_2_numeric_color_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(
+            env_1!(),
+            ["--according-to", "numeric", "--color", "always"],
+            &in_w,
+        );
+        assert_eq!(
+            buff!(sioe, serr),
+            concat!(
+                program_name!(),
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': invalid float literal\n"
+            )
+        );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_t2() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(
+            env_1!(),
+            ["--according-to", "numeric", "-r", "--color", "always"],
+            &in_w,
+        );
+        assert_eq!(
+            buff!(sioe, serr),
+            concat!(
+                program_name!(),
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': invalid float literal\n"
+            )
+        );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_t3() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(
+            env_1!(),
+            [
+                "-e",
+                "[0-9]+",
+                "--according-to",
+                "numeric",
+                "--color",
+                "always"
+            ],
+            &in_w,
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Cherry:<S>4<E>:4:good:Oct\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t4() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(
+            env_1!(),
+            [
+                "-e",
+                "[0-9]+",
+                "--according-to",
+                "numeric",
+                "-r",
+                "--color",
+                "always"
+            ],
+            &in_w,
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Cherry:<S>4<E>:4:good:Oct\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t5() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let in_w = in_w.to_string() + &in_w;
+        let (r, sioe) = do_execute!(
+            env_1!(),
+            [
+                "-e",
+                "[0-9]+",
+                "--according-to",
+                "numeric",
+                "--color",
+                "always"
+            ],
+            &in_w,
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Cherry:<S>4<E>:4:good:Oct\n",
+                "Cherry:<S>4<E>:4:good:Oct\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t6_header() {
+        let in_w = std::fs::read_to_string(fixture_fruit_header!()).unwrap();
+        let (r, sioe) = do_execute!(
+            env_1!(),
+            [
+                "-e",
+                "[0-9]+",
+                "--according-to",
+                "numeric",
+                "-h",
+                "1",
+                "--color",
+                "always"
+            ],
+            &in_w,
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "name:number:version:nice:month\n",
+                "Cherry:<S>4<E>:4:good:Oct\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t6_footer() {
+        let in_w = std::fs::read_to_string(fixture_fruit_footer!()).unwrap();
+        let (r, sioe) = do_execute!(
+            env_1!(),
+            [
+                "-e",
+                "[0-9]+",
+                "--according-to",
+                "numeric",
+                "-t",
+                "1",
+                "--color",
+                "always"
+            ],
+            &in_w,
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Cherry:<S>4<E>:4:good:Oct\n",
+                "Apple:<S>33<E>:3.3:good:Mar\n",
+                "Orange:<S>222<E>:1.1.2:good:Jan\n",
+                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
+                "This is footer line. 1\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+}
+
+mod test_2_datetime_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = concat!(
+            "a 2024-03-01T12:00:00+09:00\n",
+            "b Fri, 01 Mar 2024 02:00:00 +0000\n",
+            "c 01/Mar/2024:04:00:00 +0000\n",
+            "d 2024-03-01 03:30:00Z\n",
+        );
+        let (r, sioe) = do_execute!(["-e", "^\\S+ (.*)", "--according-to", "datetime"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "b Fri, 01 Mar 2024 02:00:00 +0000\n",
+                "a 2024-03-01T12:00:00+09:00\n",
+                "d 2024-03-01 03:30:00Z\n",
+                "c 01/Mar/2024:04:00:00 +0000\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t2_syslog() {
+        let in_w = "Mar 10 08:00:01 host b\nMar  9 23:59:59 host a\nFeb 29 00:00:00 host c\n";
+        let (r, sioe) = do_execute!(["-e", "^.{15}", "--according-to", "datetime", "-r"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            "Mar 10 08:00:01 host b\nMar  9 23:59:59 host a\nFeb 29 00:00:00 host c\n"
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t3_date_format() {
+        let in_w = "01.03.2024 x\n28.02.2024 y\n";
+        let (r, sioe) = do_execute!(["-k", "1,1:datetime", "--date-format", "%d.%m.%Y"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "28.02.2024 y\n01.03.2024 x\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t4_invalid() {
+        let in_w = "2024-03-01\n2024-13-01\n";
+        let (r, sioe) = do_execute!(["--according-to", "datetime"], in_w);
+        assert_eq!(
+            buff!(sioe, serr),
+            concat!(
+                program_name!(),
+                ": line 2: (0,10):'2024-13-01': unknown format while parsing datetime\n"
+            )
+        );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+}
 
-mod test_2_string_l {
+mod test_2_deb_version_l {
     use libaki_resort::*;
     use runnel::medium::stringio::{StringErr, StringIn, StringOut};
     use runnel::RunnelIoe;
     //
     #[test]
     fn test_t1() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let (r, sioe) = do_execute!(&[] as &[&str], &in_w);
+        let in_w = "2.3-4ubuntu1\n1:1.0\n2.3-4\n2.3~rc1-1\n2.3-10\n";
+        let (r, sioe) = do_execute!(["--according-to", "deb-version"], in_w);
         assert_eq!(buff!(sioe, serr), "");
         assert_eq!(
             buff!(sioe, sout),
-            concat!(
-                "Apple:33:3.3:good:Mar\n",
-                "Cherry:4:4:good:Oct\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Orange:222:1.1.2:good:Jan\n",
-            )
+            "2.3~rc1-1\n2.3-4\n2.3-4ubuntu1\n2.3-10\n1:1.0\n"
         );
         assert!(r.is_ok());
     }
     //
     #[test]
-    fn test_t2() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let (r, sioe) = do_execute!(["-r"], &in_w);
+    fn test_t2_tilde_epoch() {
+        let in_w = "1.0+\n0:1.0\n1.0~\n1.0\n1.0~~\n1.0-0\n";
+        let (r, sioe) = do_execute!(["--according-to", "deb-version", "-u"], in_w);
         assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "1.0~~\n1.0~\n0:1.0\n1.0+\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t3_invalid() {
+        let in_w = "1.0\n1.0_1\n";
+        let (r, sioe) = do_execute!(["--according-to", "deb-version"], in_w);
         assert_eq!(
-            buff!(sioe, sout),
+            buff!(sioe, serr),
             concat!(
-                "Orange:222:1.1.2:good:Jan\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Cherry:4:4:good:Oct\n",
-                "Apple:33:3.3:good:Mar\n",
+                program_name!(),
+                ": line 2: (0,5):'1.0_1': unexpected character '_' while parsing deb-version\n"
             )
         );
-        assert!(r.is_ok());
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
     }
+}
+
+mod test_2_duration_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
     //
     #[test]
-    fn test_t3() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let (r, sioe) = do_execute!(["-e", "[0-9]+"], &in_w);
+    fn test_t1() {
+        let in_w = "test_a 1m2.5s\ntest_b 350\u{b5}s\ntest_c 1h30m\ntest_d 250ms\ntest_e 2d\n";
+        let (r, sioe) = do_execute!(["-k", "2", "--according-to", "duration"], in_w);
         assert_eq!(buff!(sioe, serr), "");
         assert_eq!(
             buff!(sioe, sout),
-            concat!(
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Cherry:4:4:good:Oct\n",
-            )
+            "test_b 350\u{b5}s\ntest_d 250ms\ntest_a 1m2.5s\ntest_c 1h30m\ntest_e 2d\n"
         );
         assert!(r.is_ok());
     }
     //
     #[test]
-    fn test_t4() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let (r, sioe) = do_execute!(["-e", "[0-9]+", "-r"], &in_w);
+    fn test_t2_units_unique() {
+        let in_w = "1d\n24h\n86400s\n1440m\n250ms\n0.25s\n250000us\n";
+        let (r, sioe) = do_execute!(["--according-to", "duration", "-u"], in_w);
         assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "250ms\n1d\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t3_invalid() {
+        let in_w = "1h\n1h5q\n";
+        let (r, sioe) = do_execute!(["--according-to", "duration"], in_w);
         assert_eq!(
-            buff!(sioe, sout),
+            buff!(sioe, serr),
             concat!(
-                "Cherry:4:4:good:Oct\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
+                program_name!(),
+                ": line 2: (0,4):'1h5q': unknown unit 'q' of '5q' while parsing duration\n"
             )
         );
-        assert!(r.is_ok());
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
     }
+}
+
+mod test_2_general_numeric_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
     //
     #[test]
-    fn test_t5() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let in_w = in_w.to_string() + &in_w;
-        let (r, sioe) = do_execute!(["-e", "[0-9]+"], &in_w);
+    fn test_t1() {
+        let in_w = "1,234,567 x\n0x1F hex\n  +42 items\n1.5e+03ms\n0o17 oct\n-inf\n";
+        let (r, sioe) = do_execute!(["--according-to", "general-numeric"], in_w);
         assert_eq!(buff!(sioe, serr), "");
         assert_eq!(
             buff!(sioe, sout),
-            concat!(
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Cherry:4:4:good:Oct\n",
-                "Cherry:4:4:good:Oct\n",
-            )
+            "-inf\n0o17 oct\n0x1F hex\n  +42 items\n1.5e+03ms\n1,234,567 x\n"
         );
         assert!(r.is_ok());
     }
     //
     #[test]
-    fn test_t6_header() {
-        let in_w = std::fs::read_to_string(fixture_fruit_header!()).unwrap();
-        let (r, sioe) = do_execute!(["-e", "[0-9]+", "-h", "1"], &in_w);
+    fn test_t2_radix_unique() {
+        let in_w = "0x10\n16\n1.6e1\n+16\n15\n";
+        let (r, sioe) = do_execute!(["--according-to", "general-numeric", "-u"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "15\n0x10\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t3_decimal_char() {
+        let in_w = "1.234,5\n999,9\n1.000\n";
+        let (r, sioe) = do_execute!(
+            [
+                "--according-to",
+                "general-numeric",
+                "--grouping-char",
+                ".",
+                "--decimal-char",
+                ",",
+            ],
+            in_w
+        );
         assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "999,9\n1.000\n1.234,5\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t4_invalid() {
+        let in_w = "12\nabc\n";
+        let (r, sioe) = do_execute!(["--according-to", "general-numeric"], in_w);
         assert_eq!(
-            buff!(sioe, sout),
+            buff!(sioe, serr),
             concat!(
-                "name:number:version:nice:month\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Cherry:4:4:good:Oct\n",
+                program_name!(),
+                ": line 2: (0,3):'abc': unexpected character 'a' while parsing general numeric\n"
             )
         );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_t5_key() {
+        let in_w = "a 1,000\nb 20\nc 1e3\n";
+        let (r, sioe) = do_execute!(["-k", "2,2:general-numeric:r", "-k", "1,1:r"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "c 1e3\na 1,000\nb 20\n");
         assert!(r.is_ok());
     }
     //
     #[test]
-    fn test_t6_footer() {
-        let in_w = std::fs::read_to_string(fixture_fruit_footer!()).unwrap();
-        let (r, sioe) = do_execute!(["-e", "[0-9]+", "-t", "1"], in_w.as_str(),);
+    fn test_t6_same_chars() {
+        let (r, sioe) = do_execute!(["--grouping-char", ".", "--decimal-char", "."], "");
+        assert!(buff!(sioe, serr).contains("decimal-char: can not be the same as --grouping-char"));
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+}
+
+mod test_2_human_size_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = "10K\tsrc\n2.5M\ttarget\n900\tREADME.md\n1G\t.git\n1.5KiB\tdoc\n";
+        let (r, sioe) = do_execute!(["-e", "^[^\t]+", "--according-to", "human-size"], in_w);
         assert_eq!(buff!(sioe, serr), "");
         assert_eq!(
             buff!(sioe, sout),
+            "900\tREADME.md\n1.5KiB\tdoc\n10K\tsrc\n2.5M\ttarget\n1G\t.git\n"
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t2_si_iec() {
+        let in_w = "1KiB\n1K\n1kB\n1k\n1000\n0.5M\n512K\n1.5Ki\n";
+        let (r, sioe) = do_execute!(["--according-to", "human-size", "-u"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "1kB\n1KiB\n1.5Ki\n0.5M\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t3_invalid() {
+        let in_w = "10K\n3XB\n";
+        let (r, sioe) = do_execute!(["--according-to", "human-size"], in_w);
+        assert_eq!(
+            buff!(sioe, serr),
             concat!(
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Cherry:4:4:good:Oct\n",
-                "This is footer line. 1\n",
+                program_name!(),
+                ": line 2: (0,3):'3XB': unexpected unit 'XB' while parsing size\n"
             )
         );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_t4_key() {
+        let in_w = "b 2G\na 512M\nc 2GiB\n";
+        let (r, sioe) = do_execute!(["-k", "2,2:human-size:r", "-k", "1,1"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "b 2G\nc 2GiB\na 512M\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t5_negative_zero() {
+        let in_w = "0\n-0\n0K\n+0B\n";
+        let (r, sioe) = do_execute!(["--according-to", "human-size", "-u"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "0\n");
         assert!(r.is_ok());
     }
 }
 
-mod test_2_string_color_l {
+mod test_2_ip_l {
     use libaki_resort::*;
     use runnel::medium::stringio::{StringErr, StringIn, StringOut};
     use runnel::RunnelIoe;
     //
     #[test]
     fn test_t1() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let (r, sioe) = do_execute!(env_1!(), ["--color", "always"], &in_w);
+        let in_w = "10.0.0.10\n10.0.0.9\n2001:db8::1\n::ffff:10.0.0.5\n192.168.0.0/24\n192.168.0.0/16\n::1\n";
+        let (r, sioe) = do_execute!(["--according-to", "ip"], in_w);
         assert_eq!(buff!(sioe, serr), "");
         assert_eq!(
             buff!(sioe, sout),
-            concat!(
-                "<S>Apple:33:3.3:good:Mar<E>\n",
-                "<S>Cherry:4:4:good:Oct<E>\n",
-                "<S>Kiwi:1111:1.1.11:good:Jun<E>\n",
-                "<S>Orange:222:1.1.2:good:Jan<E>\n",
-            )
+            "::ffff:10.0.0.5\n10.0.0.9\n10.0.0.10\n192.168.0.0/16\n192.168.0.0/24\n::1\n2001:db8::1\n"
         );
         assert!(r.is_ok());
     }
     //
     #[test]
-    fn test_t2() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let (r, sioe) = do_execute!(env_1!(), ["-r", "--color", "always"], &in_w);
+    fn test_t2_ipv6_first() {
+        let in_w = "10.0.0.10\n10.0.0.9\n2001:db8::1\n::ffff:10.0.0.5\n::1\n";
+        let (r, sioe) = do_execute!(["--according-to", "ip", "--ipv6-first"], in_w);
         assert_eq!(buff!(sioe, serr), "");
         assert_eq!(
             buff!(sioe, sout),
-            concat!(
-                "<S>Orange:222:1.1.2:good:Jan<E>\n",
-                "<S>Kiwi:1111:1.1.11:good:Jun<E>\n",
-                "<S>Cherry:4:4:good:Oct<E>\n",
-                "<S>Apple:33:3.3:good:Mar<E>\n",
-            )
+            "::1\n2001:db8::1\n::ffff:10.0.0.5\n10.0.0.9\n10.0.0.10\n"
         );
         assert!(r.is_ok());
     }
     //
     #[test]
-    fn test_t3() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let (r, sioe) = do_execute!(env_1!(), ["-e", "[0-9]+", "--color", "always"], &in_w);
+    fn test_t2_mapped_prefix() {
+        let in_w = "10.0.0.1\n::ffff:10.0.0.0/104\n10.0.0.0/8\n";
+        let (r, sioe) = do_execute!(["--according-to", "ip", "-u"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "::ffff:10.0.0.0/104\n10.0.0.1\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t3_keydef() {
+        let in_w = "a 10.0.0.2 GET\nb ::1 GET\nc 10.0.0.10 POST\n";
+        let (r, sioe) = do_execute!(["-k", "2,2:ip", "--ipv6-first"], in_w);
         assert_eq!(buff!(sioe, serr), "");
         assert_eq!(
             buff!(sioe, sout),
+            "b ::1 GET\na 10.0.0.2 GET\nc 10.0.0.10 POST\n"
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t4_invalid() {
+        let in_w = "10.0.0.1\n10.0.0.0/33\n";
+        let (r, sioe) = do_execute!(["--according-to", "ip"], in_w);
+        assert_eq!(
+            buff!(sioe, serr),
             concat!(
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Cherry:<S>4<E>:4:good:Oct\n",
+                program_name!(),
+                ": line 2: (0,11):'10.0.0.0/33': invalid prefix '33' while parsing ip\n"
             )
         );
-        assert!(r.is_ok());
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
     }
+}
+
+mod test_2_lenient_version_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
     //
     #[test]
-    fn test_t4() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let (r, sioe) = do_execute!(env_1!(), ["-e", "[0-9]+", "-r", "--color", "always"], &in_w);
+    fn test_t1() {
+        let in_w = "foo-1.10.tar.gz\nfoo-1.2.tar.gz\nv1.10\nv1.9\n1.0-beta10\n1.0-beta2\n1.2.3.4\n";
+        let (r, sioe) = do_execute!(["--according-to", "lenient-version"], in_w);
         assert_eq!(buff!(sioe, serr), "");
         assert_eq!(
             buff!(sioe, sout),
-            concat!(
-                "Cherry:<S>4<E>:4:good:Oct\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-            )
+            "1.0-beta2\n1.0-beta10\n1.2.3.4\nfoo-1.2.tar.gz\nfoo-1.10.tar.gz\nv1.9\nv1.10\n"
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t2_unique() {
+        let in_w = "1.01\n1.1\n1.0~rc1\n";
+        let (r, sioe) = do_execute!(["--according-to", "lenient-version", "-u"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "1.0~rc1\n1.01\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t3_keydef() {
+        let in_w = "b v2.0.0.1\na v2.0.0.10\nb v2.0.0.10\n";
+        let (r, sioe) = do_execute!(["-k", "1,1", "-k", "2:lenient-version:r"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "a v2.0.0.10\nb v2.0.0.10\nb v2.0.0.1\n");
+        assert!(r.is_ok());
+    }
+}
+
+mod test_2_list_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = "high disk\nLow memo\ncritical db\nmedium cpu\nLOW log\n";
+        let (r, sioe) = do_execute!(
+            [
+                "-k",
+                "1,1",
+                "--according-to",
+                "list",
+                "--order",
+                "low,medium,high,critical",
+            ],
+            in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            "Low memo\nLOW log\nmedium cpu\nhigh disk\ncritical db\n"
         );
         assert!(r.is_ok());
     }
     //
     #[test]
-    fn test_t5() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let in_w = in_w.to_string() + &in_w;
+    fn test_t2_order_file() {
+        let in_w = "ERROR x\nInformation y\nwarn z\nTRACE w\nerr v\n";
         let (r, sioe) = do_execute!(
-            env_1!(),
-            ["-e", "[0-9]+", "--color", "always"],
-            in_w.as_str()
+            ["-k", "1,1:list", "--order-file", fixture_order_levels!()],
+            in_w
         );
         assert_eq!(buff!(sioe, serr), "");
         assert_eq!(
             buff!(sioe, sout),
-            concat!(
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Cherry:<S>4<E>:4:good:Oct\n",
-                "Cherry:<S>4<E>:4:good:Oct\n",
-            )
+            "TRACE w\nInformation y\nwarn z\nERROR x\nerr v\n"
         );
         assert!(r.is_ok());
     }
     //
     #[test]
-    fn test_t6_header() {
-        let in_w = std::fs::read_to_string(fixture_fruit_header!()).unwrap();
+    fn test_t3_on_unknown_first() {
+        let in_w = "prod\nqa\ndev\nstaging\n";
         let (r, sioe) = do_execute!(
-            env_1!(),
-            ["-e", "[0-9]+", "-h", "1", "--color", "always"],
-            &in_w,
+            [
+                "--according-to",
+                "list",
+                "--order",
+                "dev,staging,prod",
+                "--on-unknown",
+                "first",
+            ],
+            in_w
         );
         assert_eq!(buff!(sioe, serr), "");
-        assert_eq!(
-            buff!(sioe, sout),
-            concat!(
-                "name:number:version:nice:month\n",
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Cherry:<S>4<E>:4:good:Oct\n",
-            )
-        );
+        assert_eq!(buff!(sioe, sout), "qa\ndev\nstaging\nprod\n");
         assert!(r.is_ok());
     }
     //
     #[test]
-    fn test_t6_footer() {
-        let in_w = std::fs::read_to_string(fixture_fruit_footer!()).unwrap();
+    fn test_t4_on_unknown_last() {
+        let in_w = "prod\nqa\ndev\nstaging\n";
         let (r, sioe) = do_execute!(
-            env_1!(),
-            ["-e", "[0-9]+", "-t", "1", "--color", "always"],
-            &in_w,
+            [
+                "--according-to",
+                "list",
+                "--order",
+                "dev,staging,prod",
+                "--on-unknown",
+                "last",
+            ],
+            in_w
         );
         assert_eq!(buff!(sioe, serr), "");
-        assert_eq!(
-            buff!(sioe, sout),
-            concat!(
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Cherry:<S>4<E>:4:good:Oct\n",
-                "This is footer line. 1\n",
-            )
-        );
+        assert_eq!(buff!(sioe, sout), "dev\nstaging\nprod\nqa\n");
         assert!(r.is_ok());
     }
-}
-
-mod test_2_numeric_l {
-    use libaki_resort::*;
-    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
-    use runnel::RunnelIoe;
     //
     #[test]
-    fn test_t1() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let (r, sioe) = do_execute!(["--according-to", "numeric"], &in_w);
+    fn test_t5_on_unknown_error() {
+        let in_w = "prod\nqa\ndev\n";
+        let (r, sioe) = do_execute!(
+            ["--according-to", "list", "--order", "dev,staging,prod"],
+            in_w
+        );
         assert_eq!(
             buff!(sioe, serr),
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': invalid float literal\n"
+                ": line 2: (0,2):'qa': unknown value 'qa' while parsing list\n"
             )
         );
         assert_eq!(buff!(sioe, sout), "");
@@ -462,311 +2318,251 @@ mod test_2_numeric_l {
     }
     //
     #[test]
-    fn test_t2() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let (r, sioe) = do_execute!(["--according-to", "numeric", "-r"], &in_w);
-        assert_eq!(
-            buff!(sioe, serr),
-            concat!(
-                program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': invalid float literal\n"
-            )
-        );
+    fn test_t6_missing_order() {
+        let in_w = "prod\n";
+        let (r, sioe) = do_execute!(["--according-to", "list"], in_w);
+        assert!(buff!(sioe, serr).contains("Missing option: order"));
         assert_eq!(buff!(sioe, sout), "");
         assert!(r.is_err());
     }
     //
     #[test]
-    fn test_t3() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let (r, sioe) = do_execute!(["-e", "[0-9]+", "--according-to", "numeric"], &in_w);
-        assert_eq!(buff!(sioe, serr), "");
-        assert_eq!(
-            buff!(sioe, sout),
-            concat!(
-                "Cherry:4:4:good:Oct\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-            )
-        );
-        assert!(r.is_ok());
+    fn test_t7_invalid_order() {
+        let in_w = "prod\n";
+        let (r, sioe) = do_execute!(["--according-to", "list", "--order", ","], in_w);
+        assert!(buff!(sioe, serr).contains("no values of the order"));
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+        //
+        let (r, sioe) = do_execute!(["--according-to", "list", "--order", "high,low,high"], in_w);
+        assert!(buff!(sioe, serr).contains("duplicate value 'high' of the order"));
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
     }
+}
+
+mod test_2_natural_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
     //
     #[test]
-    fn test_t4() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let (r, sioe) = do_execute!(["-e", "[0-9]+", "--according-to", "numeric", "-r"], &in_w);
+    fn test_t1() {
+        let in_w = "file10.txt\nfile2.txt\nfile1.txt\nfile02.txt\nfile.txt\n";
+        let (r, sioe) = do_execute!(["--according-to", "natural"], in_w);
         assert_eq!(buff!(sioe, serr), "");
         assert_eq!(
             buff!(sioe, sout),
-            concat!(
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Cherry:4:4:good:Oct\n",
-            )
+            "file.txt\nfile1.txt\nfile2.txt\nfile02.txt\nfile10.txt\n"
         );
         assert!(r.is_ok());
     }
     //
     #[test]
-    fn test_t5() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let in_w = in_w.to_string() + &in_w;
-        let (r, sioe) = do_execute!(["-e", "[0-9]+", "--according-to", "numeric"], &in_w,);
+    fn test_t2_leading_zeros() {
+        let in_w = "a01\na1\na001\na1b\na\n";
+        let (r, sioe) = do_execute!(["--according-to", "natural", "-u"], in_w);
         assert_eq!(buff!(sioe, serr), "");
-        assert_eq!(
-            buff!(sioe, sout),
-            concat!(
-                "Cherry:4:4:good:Oct\n",
-                "Cherry:4:4:good:Oct\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-            )
-        );
+        assert_eq!(buff!(sioe, sout), "a\na1\na01\na001\na1b\n");
         assert!(r.is_ok());
     }
     //
     #[test]
-    fn test_t6_header() {
-        let in_w = std::fs::read_to_string(fixture_fruit_header!()).unwrap();
-        let (r, sioe) = do_execute!(
-            ["-e", "[0-9]+", "--according-to", "numeric", "-h", "1"],
-            &in_w,
-        );
+    fn test_t3() {
+        let in_w = "id 123456789012345678901234567890\nid 99999999999999999999\nid 7\n";
+        let (r, sioe) = do_execute!(["-e", "[0-9]+", "--according-to", "natural"], in_w);
         assert_eq!(buff!(sioe, serr), "");
         assert_eq!(
             buff!(sioe, sout),
-            concat!(
-                "name:number:version:nice:month\n",
-                "Cherry:4:4:good:Oct\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-            )
+            "id 7\nid 99999999999999999999\nid 123456789012345678901234567890\n"
         );
         assert!(r.is_ok());
     }
     //
     #[test]
-    fn test_t6_footer() {
-        let in_w = std::fs::read_to_string(fixture_fruit_footer!()).unwrap();
+    fn test_t4_key() {
+        let in_w = "b:v1.10\na:v1.9\nc:v1.9\n";
         let (r, sioe) = do_execute!(
-            ["-e", "[0-9]+", "--according-to", "numeric", "-t", "1"],
-            &in_w,
+            ["--field-separator", ":", "-k", "2,2:natural", "-k", "1,1:r"],
+            in_w
         );
         assert_eq!(buff!(sioe, serr), "");
-        assert_eq!(
-            buff!(sioe, sout),
-            concat!(
-                "Cherry:4:4:good:Oct\n",
-                "Apple:33:3.3:good:Mar\n",
-                "Orange:222:1.1.2:good:Jan\n",
-                "Kiwi:1111:1.1.11:good:Jun\n",
-                "This is footer line. 1\n",
-            )
-        );
+        assert_eq!(buff!(sioe, sout), "c:v1.9\na:v1.9\nb:v1.10\n");
         assert!(r.is_ok());
     }
 }
 
-mod test_2_numeric_color_l {
+mod test_2_pep440_l {
     use libaki_resort::*;
     use runnel::medium::stringio::{StringErr, StringIn, StringOut};
     use runnel::RunnelIoe;
     //
     #[test]
     fn test_t1() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let (r, sioe) = do_execute!(
-            env_1!(),
-            ["--according-to", "numeric", "--color", "always"],
-            &in_w,
-        );
+        let in_w = "2.0\n1.0.post1\n2.0rc1\n1!3.0\n1.0.dev3\n1.0\n1.0+local.1\n";
+        let (r, sioe) = do_execute!(["--according-to", "pep440"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
         assert_eq!(
-            buff!(sioe, serr),
-            concat!(
-                program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': invalid float literal\n"
-            )
+            buff!(sioe, sout),
+            "1.0.dev3\n1.0\n1.0+local.1\n1.0.post1\n2.0rc1\n2.0\n1!3.0\n"
         );
-        assert_eq!(buff!(sioe, sout), "");
-        assert!(r.is_err());
+        assert!(r.is_ok());
     }
     //
     #[test]
-    fn test_t2() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+    fn test_t2_keydef() {
+        let in_w = "requests=2.31.0\nattrs=23.1.0\nrequests=2.4.post1\nattrs=23.1.0rc1\n";
         let (r, sioe) = do_execute!(
-            env_1!(),
-            ["--according-to", "numeric", "-r", "--color", "always"],
-            &in_w,
+            ["--field-separator", "=", "-k", "1,1", "-k", "2,2:pep440"],
+            in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            "attrs=23.1.0rc1\nattrs=23.1.0\nrequests=2.4.post1\nrequests=2.31.0\n"
         );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t3_invalid() {
+        let in_w = "1.0\n1.0-beta+\n";
+        let (r, sioe) = do_execute!(["--according-to", "pep440"], in_w);
         assert_eq!(
             buff!(sioe, serr),
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': invalid float literal\n"
+                ": line 2: (0,9):'1.0-beta+': invalid version '1.0-beta+' while parsing pep440\n"
             )
         );
         assert_eq!(buff!(sioe, sout), "");
         assert!(r.is_err());
     }
+}
+
+mod test_2_rpm_version_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
     //
     #[test]
-    fn test_t3() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let (r, sioe) = do_execute!(
-            env_1!(),
-            [
-                "-e",
-                "[0-9]+",
-                "--according-to",
-                "numeric",
-                "--color",
-                "always"
-            ],
-            &in_w,
-        );
+    fn test_t1() {
+        let in_w = "pkg 1.0-1.el9\npkg 1.0^git1-1\npkg 1.0~rc1-1\npkg 1:0.9-1\npkg 1.0-1.el10\npkg 1.0-2.el9\n";
+        let (r, sioe) = do_execute!(["-k", "2", "--according-to", "rpm-version"], in_w);
         assert_eq!(buff!(sioe, serr), "");
         assert_eq!(
             buff!(sioe, sout),
-            concat!(
-                "Cherry:<S>4<E>:4:good:Oct\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-            )
+            "pkg 1.0~rc1-1\npkg 1.0-1.el9\npkg 1.0-1.el10\npkg 1.0-2.el9\npkg 1.0^git1-1\npkg 1:0.9-1\n"
         );
         assert!(r.is_ok());
     }
     //
     #[test]
-    fn test_t4() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let (r, sioe) = do_execute!(
-            env_1!(),
-            [
-                "-e",
-                "[0-9]+",
-                "--according-to",
-                "numeric",
-                "-r",
-                "--color",
-                "always"
-            ],
-            &in_w,
-        );
+    fn test_t2_keydef() {
+        let in_w = "b 1.10\na 1.9\nb 1.9\n";
+        let (r, sioe) = do_execute!(["-k", "1,1", "-k", "2:rpm-version:r"], in_w);
         assert_eq!(buff!(sioe, serr), "");
-        assert_eq!(
-            buff!(sioe, sout),
-            concat!(
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Cherry:<S>4<E>:4:good:Oct\n",
-            )
-        );
+        assert_eq!(buff!(sioe, sout), "a 1.9\nb 1.10\nb 1.9\n");
         assert!(r.is_ok());
     }
     //
     #[test]
-    fn test_t5() {
-        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
-        let in_w = in_w.to_string() + &in_w;
-        let (r, sioe) = do_execute!(
-            env_1!(),
-            [
-                "-e",
-                "[0-9]+",
-                "--according-to",
-                "numeric",
-                "--color",
-                "always"
-            ],
-            &in_w,
+    fn test_t3_invalid() {
+        let in_w = "1.0\nx:1.0\n";
+        let (r, sioe) = do_execute!(["--according-to", "rpm-version"], in_w);
+        assert_eq!(
+            buff!(sioe, serr),
+            concat!(
+                program_name!(),
+                ": line 2: (0,5):'x:1.0': epoch 'x' is not a number while parsing rpm-version\n"
+            )
         );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+}
+
+mod test_2_weekday_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = "Sun backup\nwed report\nMonday sync\nFRI deploy\nTue cleanup\n";
+        let (r, sioe) = do_execute!(["-k", "1,1", "--according-to", "weekday"], in_w);
         assert_eq!(buff!(sioe, serr), "");
         assert_eq!(
             buff!(sioe, sout),
-            concat!(
-                "Cherry:<S>4<E>:4:good:Oct\n",
-                "Cherry:<S>4<E>:4:good:Oct\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-            )
+            "Monday sync\nTue cleanup\nwed report\nFRI deploy\nSun backup\n"
         );
         assert!(r.is_ok());
     }
     //
     #[test]
-    fn test_t6_header() {
-        let in_w = std::fs::read_to_string(fixture_fruit_header!()).unwrap();
+    fn test_t2_first_weekday() {
+        let in_w = "Sat\nMon\nSunday\nTue\n";
         let (r, sioe) = do_execute!(
-            env_1!(),
-            [
-                "-e",
-                "[0-9]+",
-                "--according-to",
-                "numeric",
-                "-h",
-                "1",
-                "--color",
-                "always"
-            ],
-            &in_w,
+            ["--according-to", "weekday", "--first-weekday", "sunday"],
+            in_w
         );
         assert_eq!(buff!(sioe, serr), "");
-        assert_eq!(
-            buff!(sioe, sout),
-            concat!(
-                "name:number:version:nice:month\n",
-                "Cherry:<S>4<E>:4:good:Oct\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-            )
-        );
+        assert_eq!(buff!(sioe, sout), "Sunday\nMon\nTue\nSat\n");
         assert!(r.is_ok());
     }
     //
     #[test]
-    fn test_t6_footer() {
-        let in_w = std::fs::read_to_string(fixture_fruit_footer!()).unwrap();
+    fn test_t3_keydef() {
+        let in_w = "0 3 * * Sun\n0 1 * * Mon\n0 2 * * Sun\n";
         let (r, sioe) = do_execute!(
-            env_1!(),
             [
-                "-e",
-                "[0-9]+",
-                "--according-to",
-                "numeric",
-                "-t",
-                "1",
-                "--color",
-                "always"
+                "-k",
+                "5,5:weekday",
+                "-k",
+                "2,2:numeric",
+                "--first-weekday",
+                "sunday"
             ],
-            &in_w,
+            in_w
         );
         assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "0 2 * * Sun\n0 3 * * Sun\n0 1 * * Mon\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t4_invalid() {
+        let in_w = "Mon\nMo\n";
+        let (r, sioe) = do_execute!(["--according-to", "weekday"], in_w);
         assert_eq!(
-            buff!(sioe, sout),
+            buff!(sioe, serr),
             concat!(
-                "Cherry:<S>4<E>:4:good:Oct\n",
-                "Apple:<S>33<E>:3.3:good:Mar\n",
-                "Orange:<S>222<E>:1.1.2:good:Jan\n",
-                "Kiwi:<S>1111<E>:1.1.11:good:Jun\n",
-                "This is footer line. 1\n",
+                program_name!(),
+                ": line 2: (0,2):'Mo': invalid weekday strings\n"
             )
         );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_t5_first_weekday_invalid() {
+        let in_w = "Mon\n";
+        let (r, sioe) = do_execute!(
+            ["--according-to", "weekday", "--first-weekday", "tuesday"],
+            in_w
+        );
+        assert!(buff!(sioe, serr).contains("first-weekday: can not parse 'tuesday'"));
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_t6_normalize() {
+        let in_w = "Wed\nMon.\n Tue\nmonday\n";
+        let (r, sioe) = do_execute!(["--according-to", "weekday", "-u"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "Mon.\n Tue\nWed\n");
         assert!(r.is_ok());
     }
 }
@@ -784,7 +2580,7 @@ mod test_2_version_l {
             buff!(sioe, serr),
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing major version number\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing major version number\n",
             )
         );
         assert_eq!(buff!(sioe, sout), "");
@@ -799,7 +2595,7 @@ mod test_2_version_l {
             buff!(sioe, serr),
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing major version number\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing major version number\n",
             )
         );
         assert_eq!(buff!(sioe, sout), "");
@@ -951,7 +2747,7 @@ mod test_2_version_color_l {
             buff!(sioe, serr),
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing major version number\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing major version number\n",
             )
         );
         assert_eq!(buff!(sioe, sout), "");
@@ -970,7 +2766,7 @@ mod test_2_version_color_l {
             buff!(sioe, serr),
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing major version number\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing major version number\n",
             )
         );
         assert_eq!(buff!(sioe, sout), "");
@@ -1143,7 +2939,7 @@ mod test_2_month_l {
             buff!(sioe, serr),
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': invalid month strings\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': invalid month strings\n",
             )
         );
         assert_eq!(buff!(sioe, sout), "");
@@ -1158,7 +2954,7 @@ mod test_2_month_l {
             buff!(sioe, serr),
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': invalid month strings\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': invalid month strings\n",
             )
         );
         assert_eq!(buff!(sioe, sout), "");
@@ -1267,6 +3063,117 @@ mod test_2_month_l {
     }
 }
 
+mod test_2_month_locale_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_ja() {
+        let in_w = "10月 b\n2月 c\n１月 a\n12月 d\n";
+        let (r, sioe) = do_execute!(
+            [
+                "-k",
+                "1,1",
+                "--according-to",
+                "month",
+                "--month-locale",
+                "ja"
+            ],
+            in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "１月 a\n2月 c\n10月 b\n12月 d\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_de() {
+        let in_w = "Okt\nmärz\nJan\nDez.\nMai\n";
+        let (r, sioe) = do_execute!(["--according-to", "month", "--month-locale", "de"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "Jan\nmärz\nMai\nOkt\nDez.\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_fr_keydef() {
+        let in_w = "3 févr. 2024\n1 janv. 2024\n2 août 2023\n";
+        let (r, sioe) = do_execute!(
+            [
+                "-k",
+                "3,3:numeric",
+                "-k",
+                "2,2:month",
+                "--month-locale",
+                "fr",
+            ],
+            in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            "2 août 2023\n1 janv. 2024\n3 févr. 2024\n"
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_names_file() {
+        let in_w = "Setiembre\nene\nAgosto\nfebrero\n";
+        let (r, sioe) = do_execute!(
+            [
+                "--according-to",
+                "month",
+                "--month-names",
+                fixture_month_names!()
+            ],
+            in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "ene\nfebrero\nAgosto\nSetiembre\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_names_file_not_found() {
+        let in_w = "ene\n";
+        let (r, sioe) = do_execute!(
+            [
+                "--according-to",
+                "month",
+                "--month-names",
+                "fixtures/not_found.txt"
+            ],
+            in_w
+        );
+        assert!(
+            buff!(sioe, serr).starts_with(concat!(program_name!(), ": fixtures/not_found.txt: "))
+        );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_names_file_with_locale() {
+        let in_w = "ene\n";
+        let (r, sioe) = do_execute!(
+            [
+                "--according-to",
+                "month",
+                "--month-locale",
+                "ja",
+                "--month-names",
+                fixture_month_names!(),
+            ],
+            in_w
+        );
+        assert!(buff!(sioe, serr).contains("month-names: can not be used with --month-locale"));
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+}
+
 mod test_2_month_color_l {
     use libaki_resort::*;
     use runnel::medium::stringio::{StringErr, StringIn, StringOut};
@@ -1284,7 +3191,7 @@ mod test_2_month_color_l {
             buff!(sioe, serr),
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': invalid month strings\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': invalid month strings\n",
             )
         );
         assert_eq!(buff!(sioe, sout), "");
@@ -1303,7 +3210,7 @@ mod test_2_month_color_l {
             buff!(sioe, serr),
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': invalid month strings\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': invalid month strings\n",
             )
         );
         assert_eq!(buff!(sioe, sout), "");
@@ -1476,7 +3383,7 @@ mod test_2_time_l {
             buff!(sioe, serr),
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing time\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing time\n",
             )
         );
         assert_eq!(buff!(sioe, sout), "");
@@ -1491,7 +3398,7 @@ mod test_2_time_l {
             buff!(sioe, serr),
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing time\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing time\n",
             )
         );
         assert_eq!(buff!(sioe, sout), "");
@@ -1668,6 +3575,84 @@ mod test_2_time_l {
         );
         assert!(r.is_ok());
     }
+    //
+    #[test]
+    fn test_t8_fraction_truncated() {
+        let in_w = "0.1234567891\n0.1234567899\n0.123456788\n";
+        let (r, sioe) = do_execute!(["--according-to", "time", "-u"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "0.123456788\n0.1234567891\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t9_invalid_fraction() {
+        let in_w = "1:00\n1.5:30\n";
+        let (r, sioe) = do_execute!(["--according-to", "time"], in_w);
+        assert_eq!(
+            buff!(sioe, serr),
+            concat!(
+                program_name!(),
+                ": line 2: (0,6):'1.5:30': can not parse fraction: '.5:30'\n"
+            )
+        );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+}
+
+mod test_2_time_fraction_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_t1() {
+        let in_w = "1:00.5\n1:00.123456\n1:00.05\n1:00.4999999999\n";
+        let (r, sioe) = do_execute!(["--according-to", "time"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            "1:00.05\n1:00.123456\n1:00.4999999999\n1:00.5\n"
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t2_days() {
+        let in_w = "a 2d 03:04:05\nb 1-23:59:59\nc 23:59:59.5\nd -0:01\n";
+        let (r, sioe) = do_execute!(["-e", "^\\S+ (.*)", "--according-to", "time", "-r"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            "a 2d 03:04:05\nb 1-23:59:59\nc 23:59:59.5\nd -0:01\n"
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t3_key() {
+        let in_w = "job1 -1:00\njob2 0:30\njob3 -0:00.5\n";
+        let (r, sioe) = do_execute!(["-k", "2,2:time"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "job1 -1:00\njob3 -0:00.5\njob2 0:30\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_t4_invalid() {
+        let in_w = "1:2:3:4\n";
+        let (r, sioe) = do_execute!(["--according-to", "time"], in_w);
+        assert_eq!(
+            buff!(sioe, serr),
+            concat!(
+                program_name!(),
+                ": line 1: (0,7):'1:2:3:4': too many ':' while parsing time\n"
+            )
+        );
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
 }
 
 mod test_2_time_color_l {
@@ -1687,7 +3672,7 @@ mod test_2_time_color_l {
             buff!(sioe, serr),
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing time\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing time\n",
             )
         );
         assert_eq!(buff!(sioe, sout), "");
@@ -1706,7 +3691,7 @@ mod test_2_time_color_l {
             buff!(sioe, serr),
             concat!(
                 program_name!(),
-                ": (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing time\n",
+                ": line 1: (0,21):\'Apple:33:3.3:good:Mar\': unexpected character \'A\' while parsing time\n",
             )
         );
         assert_eq!(buff!(sioe, sout), "");
@@ -1947,6 +3932,84 @@ mod test_3_l {
     }
 }
 
+mod test_3_buffer_size_l {
+    use libaki_resort::*;
+    use runnel::medium::stringio::{StringErr, StringIn, StringOut};
+    use runnel::RunnelIoe;
+    //
+    #[test]
+    fn test_numeric() {
+        let in_w = std::fs::read_to_string(fixture_fruit!()).unwrap();
+        let (r, sioe) = do_execute!(
+            ["-S", "1", "-e", "[0-9]+", "--according-to", "numeric"],
+            &in_w
+        );
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(
+            buff!(sioe, sout),
+            concat!(
+                "Cherry:4:4:good:Oct\n",
+                "Apple:33:3.3:good:Mar\n",
+                "Orange:222:1.1.2:good:Jan\n",
+                "Kiwi:1111:1.1.11:good:Jun\n",
+            )
+        );
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_stable() {
+        let in_w = "b:1\na:2\nb:3\na:4\nb:5\na:6\n";
+        let (r, sioe) = do_execute!(["-S", "5", "-e", "^(.)"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "a:2\na:4\na:6\nb:1\nb:3\nb:5\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_carriage_return() {
+        // the last line without a newline keeps the '\r' of the end.
+        let (r, sioe) = do_execute!(["-S", "1"], "b\na\nc\r");
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "a\nb\nc\r\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_header_footer() {
+        let in_w = "header\nd\nb\nc\na\nfooter\n";
+        let (r, sioe) = do_execute!(["-S", "1", "-h", "1", "-t", "1", "-r"], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "header\nd\nc\nb\na\nfooter\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_regex_no_match() {
+        let in_w = "b:1\na:2\nc:3\n";
+        let (r, sioe) = do_execute!(["-S", "1", "-e", "d:."], in_w);
+        assert_eq!(buff!(sioe, serr), "");
+        assert_eq!(buff!(sioe, sout), "b:1\na:2\nc:3\n");
+        assert!(r.is_ok());
+    }
+    //
+    #[test]
+    fn test_buffer_size_invalid() {
+        let (r, sioe) = do_execute!(["-S", "a"], "b\na\nc\n");
+        assert!(buff!(sioe, serr).contains("buffer-size: can not parse"));
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+    //
+    #[test]
+    fn test_temp_dir_not_found() {
+        let (r, sioe) = do_execute!(["-S", "1", "-T", "fixtures/not_found"], "b\na\nc\n");
+        assert!(buff!(sioe, serr).contains("aki-resort."));
+        assert_eq!(buff!(sioe, sout), "");
+        assert!(r.is_err());
+    }
+}
+
 mod test_4_combination_options_l {
     use libaki_resort::*;
     use runnel::medium::stringio::{StringErr, StringIn, StringOut};
@@ -0,0 +1,111 @@
+//
+// generate `src/sort/case_fold_table.rs.txt` from `CaseFolding.txt` of
+// the Unicode Character Database.
+//
+// the input is `https://www.unicode.org/Public/<version>/ucd/CaseFolding.txt`
+// of the same version as the collation table, see `gen_collate_table.rs`.
+//
+// the output is the table of the full case folding, the status C and F,
+// except ASCII that is folded by `to_ascii_lowercase()`.
+//
+use std::io::Write;
+
+const DEFAULT_INPUT: &str = "CaseFolding.txt";
+const OUTPUT: &str = "src/sort/case_fold_table.rs.txt";
+
+pub fn do_gen_table(env_args: &[&str]) -> anyhow::Result<()> {
+    let input = env_args.first().copied().unwrap_or(DEFAULT_INPUT);
+    let text = std::fs::read_to_string(input).map_err(|err| anyhow::anyhow!("{input}: {err}"))?;
+    let version = text
+        .lines()
+        .next()
+        .and_then(|s| s.strip_prefix("# CaseFolding-"))
+        .and_then(|s| s.strip_suffix(".txt"))
+        .unwrap_or_default();
+    if version != crate::UNICODE_VERSION {
+        anyhow::bail!(
+            "{input}: the version '{version}' is not {}",
+            crate::UNICODE_VERSION
+        );
+    }
+    let table = parse_case_folding(&text)?;
+    //
+    let mut out = std::io::BufWriter::new(std::fs::File::create(OUTPUT)?);
+    writeln!(
+        out,
+        "// generated by `cargo xtask gen-case-folding`. do not edit."
+    )?;
+    let nums: Vec<&str> = version.split('.').collect();
+    writeln!(
+        out,
+        "/// the Unicode version of CaseFolding.txt, checked by the tests."
+    )?;
+    writeln!(out, "#[cfg(test)]")?;
+    writeln!(
+        out,
+        "pub(crate) const CASE_FOLDING_VERSION: (u8, u8, u8) = ({});",
+        nums.join(", ")
+    )?;
+    writeln!(
+        out,
+        "/// the status C and F of CaseFolding.txt of Unicode {version}, except ASCII."
+    )?;
+    writeln!(out, "#[rustfmt::skip]")?;
+    writeln!(
+        out,
+        "static CASE_FOLDING: [(char, &str); {}] = [",
+        table.len()
+    )?;
+    for (c, folded) in table {
+        let folded: String = folded
+            .iter()
+            .map(|&c| match char::from_u32(c) {
+                Some(c) if c.is_ascii_alphanumeric() => c.to_string(),
+                _ => format!("\\u{{{c:x}}}"),
+            })
+            .collect();
+        writeln!(out, "    ('\\u{{{c:x}}}', \"{folded}\"),")?;
+    }
+    writeln!(out, "];")?;
+    out.flush()?;
+    Ok(())
+}
+
+type Entry = (u32, Vec<u32>);
+
+fn parse_case_folding(text: &str) -> anyhow::Result<Vec<Entry>> {
+    let mut table: Vec<Entry> = Vec::new();
+    for line in text.lines() {
+        let line = match line.find('#') {
+            Some(idx) => line[..idx].trim(),
+            None => line.trim(),
+        };
+        if line.is_empty() {
+            continue;
+        }
+        // <code>; <status>; <mapping>;
+        let fields: Vec<&str> = line.split(';').map(str::trim).collect();
+        if fields.len() < 3 {
+            anyhow::bail!("invalid line: {line}");
+        }
+        if !matches!(fields[1], "C" | "F") {
+            continue;
+        }
+        let c = hex(fields[0])?;
+        if c < 0x80 {
+            continue;
+        }
+        let folded = fields[2]
+            .split_whitespace()
+            .map(hex)
+            .collect::<anyhow::Result<Vec<u32>>>()?;
+        table.push((c, folded));
+    }
+    table.sort();
+    table.dedup_by_key(|(c, _)| *c);
+    Ok(table)
+}
+
+fn hex(s: &str) -> anyhow::Result<u32> {
+    u32::from_str_radix(s, 16).map_err(|err| anyhow::anyhow!("invalid hex '{s}': {err}"))
+}
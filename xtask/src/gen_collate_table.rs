@@ -0,0 +1,145 @@
+//
+// generate `src/sort/collate_table.txt` from `allkeys.txt`, the Default
+// Unicode Collation Element Table (DUCET) of the Unicode Collation Algorithm.
+//
+// the input is `https://www.unicode.org/Public/UCA/<version>/allkeys.txt`,
+// the copy of it is shipped with `Unicode::Collate` of perl too. the copyright
+// lines of the input are kept in the output, see `LICENSE-UNICODE`.
+//
+// the output has a line per collation entry:
+//
+//     <code points>;<level 1 weights>;<level 2 weights>;<level 3 weights>
+//
+// the code points and the weights are lower hex strings separated by a space.
+// the weights of a level are the non-zero weights of the collation elements,
+// and an empty weights field means that the entry is ignorable at the level.
+// the variable collation elements are not ignorable.
+//
+// the ranges of the implicit weights of DUCET are on the lines:
+//
+//     @implicit <first> <last>;<base>
+//
+use std::io::Write;
+
+const DEFAULT_INPUT: &str = "allkeys.txt";
+const OUTPUT: &str = "src/sort/collate_table.txt";
+
+pub fn do_gen_table(env_args: &[&str]) -> anyhow::Result<()> {
+    let input = env_args.first().copied().unwrap_or(DEFAULT_INPUT);
+    let text = std::fs::read_to_string(input).map_err(|err| anyhow::anyhow!("{input}: {err}"))?;
+    let ducet = parse_ducet(&text)?;
+    if ducet.version != crate::UNICODE_VERSION {
+        anyhow::bail!(
+            "{input}: the version {} is not {}",
+            ducet.version,
+            crate::UNICODE_VERSION
+        );
+    }
+    //
+    let mut out = std::io::BufWriter::new(std::fs::File::create(OUTPUT)?);
+    writeln!(
+        out,
+        "# generated by `cargo xtask gen-collate-table`. do not edit."
+    )?;
+    for line in ducet.header {
+        writeln!(out, "{line}")?;
+    }
+    writeln!(out, "@version {}", ducet.version)?;
+    for (first, last, base) in ducet.implicit {
+        writeln!(out, "@implicit {first:x} {last:x};{base:x}")?;
+    }
+    for (cps, levels) in ducet.entries {
+        let cps: Vec<String> = cps.iter().map(|c| format!("{c:x}")).collect();
+        let levels: Vec<String> = levels
+            .iter()
+            .map(|ws| {
+                let ws: Vec<String> = ws.iter().map(|w| format!("{w:x}")).collect();
+                ws.join(" ")
+            })
+            .collect();
+        writeln!(out, "{};{}", cps.join(" "), levels.join(";"))?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+type Entry = (Vec<u32>, [Vec<u32>; 3]);
+type Implicit = (u32, u32, u32);
+
+#[derive(Debug, Default)]
+struct Ducet<'a> {
+    version: &'a str,
+    /// the file name and the copyright lines.
+    header: Vec<&'a str>,
+    implicit: Vec<Implicit>,
+    entries: Vec<Entry>,
+}
+
+fn parse_ducet(text: &str) -> anyhow::Result<Ducet<'_>> {
+    let mut ducet = Ducet::default();
+    for line in text.lines() {
+        let line = line.trim();
+        if line.starts_with("# allkeys")
+            || line.starts_with("# Copyright")
+            || line.starts_with("# For terms of use")
+        {
+            ducet.header.push(line);
+            continue;
+        }
+        let line = match line.find('#') {
+            Some(idx) => line[..idx].trim(),
+            None => line,
+        };
+        if line.is_empty() {
+            continue;
+        }
+        if let Some(s) = line.strip_prefix("@version") {
+            ducet.version = s.trim();
+            continue;
+        }
+        if let Some(s) = line.strip_prefix("@implicitweights") {
+            // @implicitweights 17000..18AFF; FB00
+            let (range, base) = s
+                .split_once(';')
+                .ok_or_else(|| anyhow::anyhow!("invalid line: {line}"))?;
+            let (first, last) = range
+                .trim()
+                .split_once("..")
+                .ok_or_else(|| anyhow::anyhow!("invalid line: {line}"))?;
+            ducet
+                .implicit
+                .push((hex(first)?, hex(last)?, hex(base.trim())?));
+            continue;
+        }
+        // 00E5  ; [.1FA2.0020.0002][.0000.0029.0002]
+        let (cps, ces) = line
+            .split_once(';')
+            .ok_or_else(|| anyhow::anyhow!("invalid line: {line}"))?;
+        let cps = cps
+            .split_whitespace()
+            .map(hex)
+            .collect::<anyhow::Result<Vec<u32>>>()?;
+        let mut levels: [Vec<u32>; 3] = Default::default();
+        for ce in ces.split('[').map(str::trim).filter(|s| !s.is_empty()) {
+            let ce = ce
+                .strip_suffix(']')
+                .and_then(|s| s.strip_prefix(['.', '*']))
+                .ok_or_else(|| anyhow::anyhow!("invalid collation element: {line}"))?;
+            for (lv, w) in ce.split('.').take(3).enumerate() {
+                let w = hex(w)?;
+                if w != 0 {
+                    levels[lv].push(w);
+                }
+            }
+        }
+        ducet.entries.push((cps, levels));
+    }
+    if ducet.version.is_empty() {
+        anyhow::bail!("not found: @version");
+    }
+    Ok(ducet)
+}
+
+fn hex(s: &str) -> anyhow::Result<u32> {
+    u32::from_str_radix(s, 16).map_err(|err| anyhow::anyhow!("invalid hex '{s}': {err}"))
+}
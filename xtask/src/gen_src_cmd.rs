@@ -15,8 +15,19 @@ pub fn do_gen_src() -> anyhow::Result<()> {
                     false,
                     MetaType::Other("opt_according_to_word".into()),
                 ),
+                "grouping-char" => (true, false, MetaType::String),
+                "decimal-char" => (true, false, MetaType::String),
+                "date-format" => (true, false, MetaType::String),
+                "normalize" => (true, false, MetaType::Other("opt_normalize_form".into())),
+                "month-locale" => (true, false, MetaType::Other("opt_month_locale".into())),
+                "first-weekday" => (false, false, MetaType::Other("opt_first_weekday".into())),
+                "on-bad-key" => (false, false, MetaType::Other("opt_on_bad_key".into())),
+                "on-unknown" => (false, false, MetaType::Other("opt_on_unknown".into())),
+                "collate-locale" => (false, false, MetaType::Other("opt_collate_locale".into())),
                 "color" => (false, false, MetaType::Other("opt_color_when".into())),
                 "max-buffer" => (false, false, MetaType::Other("opt_max_buffer_size".into())),
+                "key" => (false, true, MetaType::Other("opt_key_def".into())),
+                "buffer-size" => (true, false, MetaType::Other("opt_max_buffer_size".into())),
                 //
                 "X" => (false, true, MetaType::Other("opt_uc_x_param".into())),
                 _ => return None,
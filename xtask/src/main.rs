@@ -2,8 +2,14 @@
 // ref)
 //   https://github.com/matklad/cargo-xtask
 //
+mod gen_case_folding;
+mod gen_collate_table;
 mod gen_src_cmd;
 
+/// the version of the Unicode data of the generated tables. it must be
+/// the same as `unicode_normalization::UNICODE_VERSION` of the pinned crate.
+pub const UNICODE_VERSION: &str = "13.0.0";
+
 fn main() -> anyhow::Result<()> {
     let mut env_args: Vec<String> = std::env::args().collect();
     let program = env_args.remove(0);
@@ -23,6 +29,8 @@ pub fn run(program: &str, env_args: &[&str]) -> anyhow::Result<()> {
     let cmd = env_args[0];
     match cmd {
         "gen-src-cmd" => gen_src_cmd::do_gen_src()?,
+        "gen-collate-table" => gen_collate_table::do_gen_table(&env_args[1..])?,
+        "gen-case-folding" => gen_case_folding::do_gen_table(&env_args[1..])?,
         "--help" | "-h" | "-H" | "help" => print_help_and_exit(program),
         "--version" | "-V" | "-v" => print_version_and_exit(program),
         _ => {
@@ -43,7 +51,13 @@ fn print_help_and_exit(program: &str) {
     println!(
         "[usage] {} {{{}}}",
         program,
-        concat!("--help|", "--version|", "gen-src-cmd",)
+        concat!(
+            "--help|",
+            "--version|",
+            "gen-src-cmd|",
+            "gen-collate-table|",
+            "gen-case-folding",
+        )
     );
     std::process::exit(0);
 }